@@ -0,0 +1,76 @@
+extern crate aoc_common;
+
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
+
+fn convert_string(input: &str) -> Result<Vec<u8>, ParseError> {
+    Span::new(input)
+        .chars()
+        .map(|(span, c)| c.to_digit(10).map(|d| d as u8).ok_or_else(|| span.error("a digit")))
+        .collect()
+}
+
+fn compute_sum(numbers: &[u8], distance: usize) -> u64 {
+    numbers
+        .iter()
+        .cycle()
+        .skip(distance)
+        .zip(numbers.iter())
+        .filter(|&(&a, &b)| a == b)
+        .map(|(a, _)| u64::from(*a))
+        .sum()
+}
+
+fn compute_solution_part_one(input: &str) -> Result<u64, ParseError> {
+    let numbers = convert_string(input)?;
+    Ok(compute_sum(&numbers, 1))
+}
+
+fn compute_solution_part_two(input: &str) -> Result<u64, ParseError> {
+    let numbers = convert_string(input)?;
+    Ok(compute_sum(&numbers, numbers.len() / 2))
+}
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2017
+    }
+
+    fn day(&self) -> u8 {
+        1
+    }
+
+    fn parse(&self, input: &Input) -> Result<bool, ParseError> {
+        std::hint::black_box(convert_string(input.trimmed())?);
+        Ok(true)
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn examples() {
+        use compute_solution_part_one;
+        use compute_solution_part_two;
+
+        assert_eq!(compute_solution_part_one("1122").unwrap(), 3);
+        assert_eq!(compute_solution_part_one("1111").unwrap(), 4);
+        assert_eq!(compute_solution_part_one("1234").unwrap(), 0);
+        assert_eq!(compute_solution_part_one("91212129").unwrap(), 9);
+
+        assert_eq!(compute_solution_part_two("1212").unwrap(), 6);
+        assert_eq!(compute_solution_part_two("1221").unwrap(), 0);
+        assert_eq!(compute_solution_part_two("123425").unwrap(), 4);
+        assert_eq!(compute_solution_part_two("123123").unwrap(), 12);
+        assert_eq!(compute_solution_part_two("12131415").unwrap(), 4);
+    }
+}
@@ -0,0 +1,95 @@
+extern crate aoc_common;
+
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
+
+fn convert_string(input: &str) -> Result<Vec<Vec<u64>>, ParseError> {
+    Span::new(input)
+        .lines()
+        .map(|s| {
+            s.split_whitespace()
+                .map(|s| s.parse::<u64>("a number"))
+                .collect()
+        })
+        .collect()
+}
+
+fn compute_checksum_one(numbers: &[Vec<u64>]) -> u64 {
+    numbers
+        .iter()
+        .map(|v| v.iter().max().unwrap() - v.iter().min().unwrap())
+        .sum()
+}
+
+fn compute_checksum_two_row(numbers: &[u64]) -> u64 {
+    numbers
+        .iter()
+        .enumerate()
+        .map(|(i, n)| {
+            numbers
+                .iter()
+                .skip(i + 1)
+                .map(|n2| if n % n2 == 0 {
+                    n / n2
+                } else if n2 % n == 0 {
+                    n2 / n
+                } else {
+                    0
+                })
+                .sum::<u64>()
+        })
+        .sum()
+}
+
+fn compute_checksum_two(numbers: &[Vec<u64>]) -> u64 {
+    numbers.iter().map(|v| compute_checksum_two_row(v)).sum()
+}
+
+fn compute_solution_part_one(input: &str) -> Result<u64, ParseError> {
+    let numbers = convert_string(input)?;
+    Ok(compute_checksum_one(&numbers))
+}
+
+fn compute_solution_part_two(input: &str) -> Result<u64, ParseError> {
+    let numbers = convert_string(input)?;
+    Ok(compute_checksum_two(&numbers))
+}
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2017
+    }
+
+    fn day(&self) -> u8 {
+        2
+    }
+
+    fn parse(&self, input: &Input) -> Result<bool, ParseError> {
+        std::hint::black_box(convert_string(input.trimmed())?);
+        Ok(true)
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn examples() {
+        use compute_solution_part_one;
+        use compute_solution_part_two;
+
+        let s = "5 1 9 5\n7 5 3\n2 4 6 8";
+        assert_eq!(compute_solution_part_one(s).unwrap(), 18);
+
+        let s = "5 9 2 8\n9 4 7 3\n3 8 6 5";
+        assert_eq!(compute_solution_part_two(s).unwrap(), 9);
+    }
+}
@@ -1,3 +1,7 @@
+extern crate aoc_common;
+
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
+
 #[derive(Clone)]
 enum Direction {
     Right,
@@ -25,11 +29,11 @@ struct Position {
 
 impl Position {
     fn new(x: i64, y: i64) -> Position {
-        Position { x: x, y: y }
+        Position { x, y }
     }
 
     fn steps_to_origin(&self) -> u64 {
-        self.x.abs() as u64 + self.y.abs() as u64
+        self.x.unsigned_abs() + self.y.unsigned_abs()
     }
 }
 
@@ -94,35 +98,23 @@ impl UlamNumber {
     }
 
     fn compute_layer(&self) -> u64 {
-        let x_abs = self.pos.x.abs() as u64;
-        let y_abs = self.pos.y.abs() as u64;
+        let x_abs = self.pos.x.unsigned_abs();
+        let y_abs = self.pos.y.unsigned_abs();
         if x_abs > y_abs { x_abs } else { y_abs }
     }
 }
 
-fn read_file(filename: &str) -> String {
-    use std::fs::File;
-    use std::io::prelude::*;
-
-    let mut f = File::open(filename).expect("file not found");
-    let mut contents = String::new();
-    f.read_to_string(&mut contents).expect(
-        "something went wrong reading the file",
-    );
-    contents.trim().to_owned()
-}
-
-fn compute_solution_part_one(input: &str) -> u64 {
-    let input_number = input.parse::<u64>().unwrap();
+fn compute_solution_part_one(input: &str) -> Result<u64, ParseError> {
+    let input_number = Span::new(input).parse::<u64>("a number")?;
     let mut ulam_number = UlamNumber::new();
     ulam_number.increase_to(input_number);
-    ulam_number.steps_to_origin()
+    Ok(ulam_number.steps_to_origin())
 }
 
-fn compute_solution_part_two(input: &str) -> u64 {
+fn compute_solution_part_two(input: &str) -> Result<u64, ParseError> {
     use std::collections::VecDeque;
 
-    let input_number = input.parse::<u64>().unwrap();
+    let input_number = Span::new(input).parse::<u64>("a number")?;
     let mut ulam_numbers: VecDeque<UlamNumber> = VecDeque::new();
     let mut sums: VecDeque<u64> = VecDeque::new();
     ulam_numbers.push_back(UlamNumber::new());
@@ -158,7 +150,27 @@ fn compute_solution_part_two(input: &str) -> u64 {
             break;
         }
     }
-    *sums.back().unwrap()
+    Ok(*sums.back().unwrap())
+}
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2017
+    }
+
+    fn day(&self) -> u8 {
+        3
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
+    }
 }
 
 #[cfg(test)]
@@ -168,27 +180,14 @@ mod tests {
         use compute_solution_part_one;
         use compute_solution_part_two;
 
-        assert_eq!(compute_solution_part_one("1"), 0);
-        assert_eq!(compute_solution_part_one("12"), 3);
-        assert_eq!(compute_solution_part_one("23"), 2);
-        assert_eq!(compute_solution_part_one("1024"), 31);
-
-        assert_eq!(compute_solution_part_two("0"), 1);
-        assert_eq!(compute_solution_part_two("55"), 57);
-        assert_eq!(compute_solution_part_two("122"), 133);
-        assert_eq!(compute_solution_part_two("335"), 351);
-    }
-}
-
-fn main() {
-    use std::env;
-    let args: Vec<String> = env::args().collect();
+        assert_eq!(compute_solution_part_one("1").unwrap(), 0);
+        assert_eq!(compute_solution_part_one("12").unwrap(), 3);
+        assert_eq!(compute_solution_part_one("23").unwrap(), 2);
+        assert_eq!(compute_solution_part_one("1024").unwrap(), 31);
 
-    if args.len() != 2 {
-        println!("Usage: d03 <input filename>");
-    } else {
-        let input = read_file(&args[1]);
-        println!("solution 1 = {}", compute_solution_part_one(&input));
-        println!("solution 2 = {}", compute_solution_part_two(&input));
+        assert_eq!(compute_solution_part_two("0").unwrap(), 1);
+        assert_eq!(compute_solution_part_two("55").unwrap(), 57);
+        assert_eq!(compute_solution_part_two("122").unwrap(), 133);
+        assert_eq!(compute_solution_part_two("335").unwrap(), 351);
     }
 }
@@ -1,14 +1,6 @@
-fn read_file(filename: &str) -> String {
-    use std::fs::File;
-    use std::io::prelude::*;
+extern crate aoc_common;
 
-    let mut f = File::open(filename).expect("file not found");
-    let mut contents = String::new();
-    f.read_to_string(&mut contents).expect(
-        "something went wrong reading the file",
-    );
-    contents.trim().to_owned()
-}
+use aoc_common::{Answer, Input, ParseError, Puzzle};
 
 fn check_valid_duplicate(input: &str) -> bool {
     use std::iter::FromIterator;
@@ -46,6 +38,26 @@ fn compute_solution_part_two(input: &str) -> u64 {
     input.split('\n').filter(|l| check_valid_anagram(l)).count() as u64
 }
 
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2017
+    }
+
+    fn day(&self) -> u8 {
+        4
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        Ok(compute_solution_part_one(input.trimmed()).into())
+    }
+
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(Ok(compute_solution_part_two(input.trimmed()).into()))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
@@ -72,16 +84,3 @@ mod tests {
         );
     }
 }
-
-fn main() {
-    use std::env;
-    let args: Vec<String> = env::args().collect();
-
-    if args.len() != 2 {
-        println!("Usage: d04 <input filename>");
-    } else {
-        let input = read_file(&args[1]);
-        println!("solution 1 = {}", compute_solution_part_one(&input));
-        println!("solution 2 = {}", compute_solution_part_two(&input));
-    }
-}
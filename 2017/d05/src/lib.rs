@@ -0,0 +1,97 @@
+extern crate aoc_common;
+
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
+
+fn convert_input(input: &str) -> Result<Vec<i64>, ParseError> {
+    Span::new(input)
+        .split_whitespace()
+        .map(|s| s.parse::<i64>("a number"))
+        .collect()
+}
+
+fn compute_solution_part_one(input: &str) -> Result<u64, ParseError> {
+    let mut instructions = convert_input(input)?;
+    let mut state = 0;
+    let mut steps = 0;
+
+    loop {
+        let jump = instructions[state];
+        let neg = jump < 0;
+        let jump = jump.unsigned_abs() as usize;
+        instructions[state] += 1;
+        steps += 1;
+        if (neg && jump > state) || (!neg && jump + state >= instructions.len()) {
+            break;
+        }
+        if neg {
+            state -= jump;
+        } else {
+            state += jump;
+        }
+    }
+    Ok(steps)
+}
+
+fn compute_solution_part_two(input: &str) -> Result<u64, ParseError> {
+    let mut instructions = convert_input(input)?;
+    let mut state = 0;
+    let mut steps = 0;
+
+    loop {
+        let jump = instructions[state];
+        let neg = jump < 0;
+        let jump = jump.unsigned_abs() as usize;
+        if instructions[state] < 3 {
+            instructions[state] += 1;
+        } else {
+            instructions[state] -= 1;
+        }
+        steps += 1;
+        if (neg && jump > state) || (!neg && jump + state >= instructions.len()) {
+            break;
+        }
+        if neg {
+            state -= jump;
+        } else {
+            state += jump;
+        }
+    }
+    Ok(steps)
+}
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2017
+    }
+
+    fn day(&self) -> u8 {
+        5
+    }
+
+    fn parse(&self, input: &Input) -> Result<bool, ParseError> {
+        std::hint::black_box(convert_input(input.trimmed())?);
+        Ok(true)
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn examples() {
+        use compute_solution_part_one;
+        use compute_solution_part_two;
+
+        assert_eq!(compute_solution_part_one("0\n3\n0\n1\n-3").unwrap(), 5);
+        assert_eq!(compute_solution_part_two("0\n3\n0\n1\n-3").unwrap(), 10);
+    }
+}
@@ -0,0 +1,99 @@
+extern crate aoc_common;
+
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
+
+fn convert_input(input: &str) -> Result<Vec<u64>, ParseError> {
+    Span::new(input)
+        .split_whitespace()
+        .map(|s| s.parse::<u64>("a number"))
+        .collect()
+}
+
+fn step(blocks: &mut [u64]) {
+    use std::cmp::Ordering;
+
+    let (mut ind, mut rest_blocks) = {
+        let tmp = blocks
+            .iter()
+            .enumerate()
+            .max_by(|x, y| match x.1.cmp(y.1) {
+                Ordering::Less => Ordering::Less,
+                Ordering::Greater => Ordering::Greater,
+                Ordering::Equal => y.0.cmp(&x.0),
+            })
+            .unwrap();
+        (tmp.0, *tmp.1)
+    };
+
+    blocks[ind] = 0;
+    while rest_blocks > 0 {
+        ind = (ind + 1) % blocks.len();
+        blocks[ind] += 1;
+        rest_blocks -= 1;
+    }
+}
+
+fn cycle_to_rep(blocks: &mut [u64]) -> (u64, u64) {
+    use std::collections::BTreeMap;
+    let mut btree_set = BTreeMap::new();
+    let mut steps = 0;
+
+    let looplen = loop {
+        let oldval = btree_set.insert(blocks.to_vec(), steps);
+        if let Some(old_step) = oldval {
+            break steps - old_step
+        }
+
+        step(blocks);
+        steps += 1;
+    };
+
+    (steps, looplen)
+}
+
+fn compute_solution_part_one(input: &str) -> Result<u64, ParseError> {
+    let mut blocks = convert_input(input)?;
+    Ok(cycle_to_rep(&mut blocks).0)
+}
+
+fn compute_solution_part_two(input: &str) -> Result<u64, ParseError> {
+    let mut blocks = convert_input(input)?;
+    Ok(cycle_to_rep(&mut blocks).1)
+}
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2017
+    }
+
+    fn day(&self) -> u8 {
+        6
+    }
+
+    fn parse(&self, input: &Input) -> Result<bool, ParseError> {
+        std::hint::black_box(convert_input(input.trimmed())?);
+        Ok(true)
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn examples() {
+        use compute_solution_part_one;
+        use compute_solution_part_two;
+
+        assert_eq!(compute_solution_part_one("0 2 7 0").unwrap(), 5);
+        assert_eq!(compute_solution_part_two("0 2 7 0").unwrap(), 4);
+    }
+}
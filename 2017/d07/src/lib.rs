@@ -1,6 +1,6 @@
-#[macro_use]
-extern crate lazy_static;
-extern crate regex;
+extern crate aoc_common;
+
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
 
 struct Node {
     name: String,
@@ -14,8 +14,8 @@ struct Node {
 impl Node {
     fn new(name: String, weight: i64) -> Node {
         Node {
-            name: name,
-            weight: weight,
+            name,
+            weight,
             total_weight: None,
             balanced_weight: false,
             parent: None,
@@ -62,10 +62,7 @@ impl Tree {
         self.nodes[id]
             .children
             .iter()
-            .filter(|id| match self.nodes[**id].total_weight {
-                Some(_) => false,
-                None => true,
-            })
+            .filter(|id| self.nodes[**id].total_weight.is_none())
             .cloned()
             .nth(0)
     }
@@ -117,66 +114,55 @@ impl Tree {
     }
 }
 
-fn read_file(filename: &str) -> String {
-    use std::fs::File;
-    use std::io::prelude::*;
-
-    let mut f = File::open(filename).expect("file not found");
-    let mut contents = String::new();
-    f.read_to_string(&mut contents).expect(
-        "something went wrong reading the file",
-    );
-    contents.trim().to_owned()
-}
-
-fn convert_input(input: &str) -> Tree {
-    use regex::Regex;
+fn convert_input(input: &str) -> Result<Tree, ParseError> {
     use std::collections::BTreeMap;
 
-    lazy_static! {
-        static ref RE_NODE: Regex = Regex::new(r"([a-z]+) \(([0-9]+)\)(?: -> (.*))?").unwrap();
-        static ref RE_CHILDREN: Regex = Regex::new(r"([a-z]+)").unwrap();
-    }
-
     let mut nodes = Vec::new();
     let mut indices = BTreeMap::new();
+    let mut all_children = Vec::new();
+
+    for (i, line) in Span::new(input).lines().enumerate() {
+        let line = line.trim();
+        let (node, children) = match line.split_once(" -> ") {
+            Ok((node, children)) => (node, Some(children)),
+            Err(_) => (line, None),
+        };
+        let (name, weight) = node.split_once(" (")?;
+        if name.is_empty() || !name.as_str().chars().all(|c| c.is_ascii_lowercase()) {
+            return Err(name.error("a program name"));
+        }
+        let weight = weight.strip_suffix(")")?.parse::<i64>("a weight")?;
+        nodes.push(Node::new(name.as_str().to_owned(), weight));
+        indices.insert(name.as_str(), i);
+        all_children.push(children);
+    }
 
-    for (i, cap) in RE_NODE.captures_iter(input).enumerate() {
-        let name = cap.get(1).unwrap().as_str();
-        let weight = cap.get(2).unwrap().as_str().parse::<i64>().unwrap();
-        nodes.push(Node::new(name.to_owned(), weight));
-        indices.insert(name.to_owned(), i);
-    }
-
-    for (i, cap) in RE_NODE.captures_iter(input).enumerate() {
-        if let Some(children) = cap.get(3) {
-            let children = children.as_str();
-            for cap_children in RE_CHILDREN.captures_iter(children) {
-                cap_children
-                    .iter()
-                    .skip(1)
-                    .map(|c| {
-                        let c = c.unwrap().as_str();
-                        let child_index = indices[c];
-                        nodes[i].children.push(child_index);
-                        nodes[child_index].parent = Some(i);
-                    })
-                    .count();
+    for (i, children) in all_children.into_iter().enumerate() {
+        if let Some(children) = children {
+            for child in children.split(", ") {
+                let child_index = *indices
+                    .get(child.as_str())
+                    .ok_or_else(|| child.error("the name of a listed program"))?;
+                nodes[i].children.push(child_index);
+                nodes[child_index].parent = Some(i);
             }
         }
     }
 
-    Tree::new(nodes)
+    if nodes.is_empty() {
+        return Err(Span::new(input).error("at least one program"));
+    }
+    Ok(Tree::new(nodes))
 }
 
-fn compute_solution_part_one(input: &str) -> String {
-    let tree = convert_input(input);
+fn compute_solution_part_one(input: &str) -> Result<String, ParseError> {
+    let tree = convert_input(input)?;
     let id = tree.root;
-    tree.nodes[id].name.clone()
+    Ok(tree.nodes[id].name.clone())
 }
 
-fn compute_solution_part_two(input: &str) -> i64 {
-    let mut tree = convert_input(input);
+fn compute_solution_part_two(input: &str) -> Result<i64, ParseError> {
+    let mut tree = convert_input(input)?;
     tree.compute_total_weight();
     let id = tree.find_unbalanced_root();
     let nr_c = tree.nodes[id].children.len();
@@ -203,7 +189,32 @@ fn compute_solution_part_two(input: &str) -> i64 {
 
     let id = tree.nodes[id].children[index];
     let diff = correct_weight - tree.nodes[id].total_weight.unwrap();
-    tree.nodes[id].weight + diff
+    Ok(tree.nodes[id].weight + diff)
+}
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2017
+    }
+
+    fn day(&self) -> u8 {
+        7
+    }
+
+    fn parse(&self, input: &Input) -> Result<bool, ParseError> {
+        std::hint::black_box(convert_input(input.trimmed())?);
+        Ok(true)
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
+    }
 }
 
 #[cfg(test)]
@@ -228,22 +239,9 @@ mod tests {
                      cntj (57)"
             .to_owned();
 
-        let solution = compute_solution_part_one(&input);
+        let solution = compute_solution_part_one(&input).unwrap();
         assert_eq!(solution, "tknk".to_owned());
-        let solution = compute_solution_part_two(&input);
+        let solution = compute_solution_part_two(&input).unwrap();
         assert_eq!(solution, 60);
     }
 }
-
-fn main() {
-    use std::env;
-    let args: Vec<String> = env::args().collect();
-
-    if args.len() != 2 {
-        println!("Usage: d07 <input filename>");
-    } else {
-        let input = read_file(&args[1]);
-        println!("solution 1 = {}", compute_solution_part_one(&input));
-        println!("solution 2 = {}", compute_solution_part_two(&input));
-    }
-}
@@ -0,0 +1,207 @@
+extern crate aoc_common;
+
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
+use std::collections::BTreeMap;
+
+enum Comparison {
+    Less,
+    LessEqual,
+    Greater,
+    GreaterEqual,
+    Equal,
+    NotEqual,
+}
+
+enum Direction {
+    Increase,
+    Decrease,
+}
+
+struct Instruction {
+    mod_reg: String,
+    dir: Direction,
+    amount: i64,
+    cond_reg: String,
+    cond_op: Comparison,
+    cond_value: i64,
+}
+
+impl Instruction {
+    fn new(
+        mod_reg: String,
+        dir: Direction,
+        amount: i64,
+        cond_reg: String,
+        cond_op: Comparison,
+        cond_value: i64,
+    ) -> Instruction {
+        Instruction {
+            mod_reg,
+            dir,
+            amount,
+            cond_reg,
+            cond_op,
+            cond_value,
+        }
+    }
+}
+
+fn parse_register(token: Span) -> Result<String, ParseError> {
+    if token.is_empty() || !token.as_str().chars().all(|c| c.is_ascii_lowercase()) {
+        return Err(token.error("a register name"));
+    }
+    Ok(token.as_str().to_owned())
+}
+
+fn parse_instruction(line: Span) -> Result<Instruction, ParseError> {
+    let mut tokens = line.split_whitespace();
+    let mod_reg = parse_register(tokens.expect("a register name")?)?;
+    let dir = {
+        let tmp = tokens.expect("`inc` or `dec`")?;
+        match tmp.as_str() {
+            "inc" => Direction::Increase,
+            "dec" => Direction::Decrease,
+            _ => return Err(tmp.error("`inc` or `dec`")),
+        }
+    };
+    let amount = tokens.expect("an amount")?.parse("an amount")?;
+    let tmp = tokens.expect("`if`")?;
+    if tmp.as_str() != "if" {
+        return Err(tmp.error("`if`"));
+    }
+    let cond_reg = parse_register(tokens.expect("a register name")?)?;
+    let cond_op = {
+        let tmp = tokens.expect("a comparison")?;
+        match tmp.as_str() {
+            "<" => Comparison::Less,
+            "<=" => Comparison::LessEqual,
+            ">" => Comparison::Greater,
+            ">=" => Comparison::GreaterEqual,
+            "==" => Comparison::Equal,
+            "!=" => Comparison::NotEqual,
+            _ => return Err(tmp.error("a comparison")),
+        }
+    };
+    let cond_value = tokens.expect("a number")?.parse("a number")?;
+    tokens.end()?;
+    Ok(Instruction::new(
+        mod_reg,
+        dir,
+        amount,
+        cond_reg,
+        cond_op,
+        cond_value,
+    ))
+}
+
+fn convert_input(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    Span::new(input).lines().map(parse_instruction).collect()
+}
+
+fn run_instruction(inst: &Instruction, regs: &mut BTreeMap<String, i64>) -> i64 {
+    let mut mod_reg_val = match regs.get(&inst.mod_reg) {
+        Some(i) => *i,
+        None => 0,
+    };
+    let cond_reg_val = match regs.get(&inst.cond_reg) {
+        Some(i) => *i,
+        None => 0,
+    };
+
+    let do_operation = match inst.cond_op {
+        Comparison::Less => cond_reg_val < inst.cond_value,
+        Comparison::LessEqual => cond_reg_val <= inst.cond_value,
+        Comparison::Greater => cond_reg_val > inst.cond_value,
+        Comparison::GreaterEqual => cond_reg_val >= inst.cond_value,
+        Comparison::Equal => cond_reg_val == inst.cond_value,
+        Comparison::NotEqual => cond_reg_val != inst.cond_value,
+    };
+
+    if do_operation {
+        match inst.dir {
+            Direction::Increase => mod_reg_val += inst.amount,
+            Direction::Decrease => mod_reg_val -= inst.amount,
+        }
+
+        regs.insert(inst.mod_reg.clone(), mod_reg_val);
+    }
+    mod_reg_val
+}
+
+fn compute_solution_part_one(input: &str) -> Result<i64, ParseError> {
+    let mut regs = BTreeMap::new();
+    let instructions = convert_input(input)?;
+    for inst in &instructions {
+        run_instruction(inst, &mut regs);
+    }
+    Ok(regs.values().copied().max().unwrap_or(0))
+}
+
+fn compute_solution_part_two(input: &str) -> Result<i64, ParseError> {
+    let mut regs = BTreeMap::new();
+    let instructions = convert_input(input)?;
+    let mut max = 0;
+    for inst in &instructions {
+        let new_val = run_instruction(inst, &mut regs);
+        if new_val > max {
+            max = new_val;
+        }
+    }
+    Ok(max)
+}
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2017
+    }
+
+    fn day(&self) -> u8 {
+        8
+    }
+
+    fn parse(&self, input: &Input) -> Result<bool, ParseError> {
+        std::hint::black_box(convert_input(input.trimmed())?);
+        Ok(true)
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn examples() {
+        use compute_solution_part_one;
+        use compute_solution_part_two;
+
+        let input = "b inc 5 if a > 1
+                     a inc 1 if b < 5
+                     c dec -10 if a >= 1
+                     c inc -20 if c == 10"
+            .to_owned();
+
+        let solution = compute_solution_part_one(&input).unwrap();
+        assert_eq!(solution, 1);
+        let solution = compute_solution_part_two(&input).unwrap();
+        assert_eq!(solution, 10);
+    }
+
+    #[test]
+    fn parse_errors() {
+        use convert_input;
+
+        let e = convert_input("a inc 5 if b < 1\nc foo 2 if a > 1").err().unwrap();
+        assert_eq!((e.line, e.column), (2, 3));
+        assert_eq!(e.to_string(), "line 2, column 3: expected `inc` or `dec`, found `foo`");
+        let e = convert_input("a inc 5 if b <").err().unwrap();
+        assert_eq!(e.to_string(), "line 1, column 15: expected a number, found nothing");
+    }
+}
@@ -0,0 +1,142 @@
+extern crate aoc_common;
+
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
+
+struct StreamParser {
+    group_level: u64,
+    score: u64,
+    nr_garbage_chars: u64,
+    ignore_next: bool,
+    garbage_mode: bool,
+}
+
+impl StreamParser {
+    fn new() -> StreamParser {
+        StreamParser {
+            group_level: 0,
+            score: 0,
+            nr_garbage_chars: 0,
+            ignore_next: false,
+            garbage_mode: false,
+        }
+    }
+
+    fn parse_char(&mut self, span: Span, input: &char) -> Result<(), ParseError> {
+        if !self.ignore_next && !self.garbage_mode {
+            match *input {
+                '{' => self.group_level += 1,
+                '}' => {
+                    if self.group_level == 0 {
+                        return Err(span.error("a group to close"));
+                    }
+                    self.score += self.group_level;
+                    self.group_level -= 1;
+                }
+                '<' => self.garbage_mode = true,
+                _ => (),
+            }
+        } else if !self.ignore_next && self.garbage_mode {
+            match *input {
+                '!' => self.ignore_next = true,
+                '>' => self.garbage_mode = true,
+                _ => self.nr_garbage_chars += 1,
+            }
+            if *input == '>' {
+                self.garbage_mode = false;
+            }
+        } else {
+            self.ignore_next = false;
+        }
+        Ok(())
+    }
+
+    fn parse_str(&mut self, input: &str) -> Result<(), ParseError> {
+        let input = Span::new(input);
+        for (span, c) in input.chars() {
+            self.parse_char(span, &c)?;
+        }
+        if self.garbage_mode {
+            Err(input.end().error("`>`"))
+        } else if self.group_level > 0 {
+            Err(input.end().error("`}`"))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+fn compute_solution_part_one(input: &str) -> Result<u64, ParseError> {
+    let mut parser = StreamParser::new();
+    parser.parse_str(input)?;
+    Ok(parser.score)
+}
+
+fn compute_solution_part_two(input: &str) -> Result<u64, ParseError> {
+    let mut parser = StreamParser::new();
+    parser.parse_str(input)?;
+    Ok(parser.nr_garbage_chars)
+}
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2017
+    }
+
+    fn day(&self) -> u8 {
+        9
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn examples() {
+        use compute_solution_part_one;
+        use compute_solution_part_two;
+
+        let examples = [
+            ("{}".to_owned(), 1),
+            ("{{{}}}".to_owned(), 6),
+            ("{{},{}}".to_owned(), 5),
+            ("{{{},{},{{}}}}".to_owned(), 16),
+            ("{<a>,<a>,<a>,<a>}".to_owned(), 1),
+            ("{{<ab>},{<ab>},{<ab>},{<ab>}}".to_owned(), 9),
+            ("{{<!!>},{<!!>},{<!!>},{<!!>}}".to_owned(), 9),
+            ("{{<a!>},{<a!>},{<a!>},{<ab>}}".to_owned(), 3),
+        ];
+        examples
+            .iter()
+            .map(|&(ref input, value)| {
+                let solution = compute_solution_part_one(input).unwrap();
+                assert_eq!(solution, value);
+            })
+            .count();
+
+        let examples = [
+            ("<>".to_owned(), 0),
+            ("<random characters>".to_owned(), 17),
+            ("<<<<>".to_owned(), 3),
+            ("<{!>}>".to_owned(), 2),
+            ("<!!>".to_owned(), 0),
+            ("<!!!>>".to_owned(), 0),
+            ("<{o\"i!a,<{i<a>".to_owned(), 10),
+        ];
+        examples
+            .iter()
+            .map(|&(ref input, value)| {
+                let solution = compute_solution_part_two(input).unwrap();
+                assert_eq!(solution, value);
+            })
+            .count();
+    }
+}
@@ -1,3 +1,7 @@
+extern crate aoc_common;
+
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
+
 struct Hasher {
     position: usize,
     skip: usize,
@@ -10,14 +14,14 @@ impl Hasher {
         Hasher {
             position: 0,
             skip: 0,
-            vals: vals,
+            vals,
         }
     }
 
     fn step(&mut self, length: usize) {
         let n = self.vals.len();
         let mut i = self.position;
-        let mut j = (self.position + length - 1) % n;
+        let mut j = (self.position + length + n - 1) % n;
 
         for _ in 0..length / 2 {
             self.vals.swap(i, j);
@@ -30,14 +34,17 @@ impl Hasher {
         self.position %= n;
     }
 
-    fn parse_str_as_numbers(&mut self, input: &str) {
-        input
-            .split(',')
-            .map(|sr| {
-                let length = sr.trim().parse().unwrap();
-                self.step(length);
-            })
-            .count();
+    fn parse_str_as_numbers(&mut self, input: &str) -> Result<(), ParseError> {
+        let n = self.vals.len();
+        for sr in Span::new(input).split(",") {
+            let sr = sr.trim();
+            let length = sr.parse("a length")?;
+            if length > n {
+                return Err(sr.error(format!("a length of at most {}", n)));
+            }
+            self.step(length);
+        }
+        Ok(())
     }
 
     fn parse_str_as_ascii(&mut self, input: &str) {
@@ -64,33 +71,43 @@ impl Hasher {
     }
 }
 
-fn read_file(filename: &str) -> String {
-    use std::fs::File;
-    use std::io::prelude::*;
-
-    let mut f = File::open(filename).expect("file not found");
-    let mut contents = String::new();
-    f.read_to_string(&mut contents).expect(
-        "something went wrong reading the file",
-    );
-    contents.trim().to_owned()
-}
-
-fn compute_solution_part_one(input: &str, length: usize) -> usize {
+fn compute_solution_part_one(input: &str, length: usize) -> Result<usize, ParseError> {
     let mut hasher = Hasher::new(length);
-    hasher.parse_str_as_numbers(input);
-    hasher.vals[0] * hasher.vals[1]
+    hasher.parse_str_as_numbers(input)?;
+    Ok(hasher.vals[0] * hasher.vals[1])
 }
 
 fn compute_solution_part_two(input: &str) -> String {
     let mut hasher = Hasher::new(256);
     for _ in 0..64 {
         hasher.parse_str_as_ascii(input);
-        hasher.parse_str_as_numbers("17,31,73,47,23");
+        for &length in &[17, 31, 73, 47, 23] {
+            hasher.step(length);
+        }
     }
     hasher.to_hex()
 }
 
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2017
+    }
+
+    fn day(&self) -> u8 {
+        10
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed(), 256).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(Ok(compute_solution_part_two(input.trimmed()).into()))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
@@ -98,16 +115,14 @@ mod tests {
         use compute_solution_part_one;
         use compute_solution_part_two;
 
-        let solution = compute_solution_part_one("3, 4, 1, 5", 5);
+        let solution = compute_solution_part_one("3, 4, 1, 5", 5).unwrap();
         assert_eq!(solution, 12);
 
-        let examples = vec!["", "AoC 2017", "1,2,3", "1,2,4"];
-        let solutions = vec![
-            "a2582a3a0e66e6e86e3812dcb672a272",
+        let examples = ["", "AoC 2017", "1,2,3", "1,2,4"];
+        let solutions = ["a2582a3a0e66e6e86e3812dcb672a272",
             "33efeb34ea91902bb2f59c9920caa6cd",
             "3efbe78a8d82f29979031a4aa0b16a9d",
-            "63960835bcdc130f0b66d7ff4f6a5a8e",
-        ];
+            "63960835bcdc130f0b66d7ff4f6a5a8e"];
         examples
             .iter()
             .zip(solutions.iter())
@@ -115,16 +130,3 @@ mod tests {
             .count();
     }
 }
-
-fn main() {
-    use std::env;
-    let args: Vec<String> = env::args().collect();
-
-    if args.len() != 2 {
-        println!("Usage: d10 <input filename>");
-    } else {
-        let input = read_file(&args[1]);
-        println!("solution 1 = {}", compute_solution_part_one(&input, 256));
-        println!("solution 2 = {}", compute_solution_part_two(&input));
-    }
-}
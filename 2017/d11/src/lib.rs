@@ -1,3 +1,7 @@
+extern crate aoc_common;
+
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
+
 enum HexDirection {
     North,
     NorthWest,
@@ -53,9 +57,9 @@ impl HexPosition {
         different_sign = different_sign || (self.north_west < 0 && self.north_east > 0);
 
         if different_sign {
-            self.north_west.abs() as u64 + self.north_west.abs() as u64
+            self.north_west.unsigned_abs() + self.north_west.unsigned_abs()
         } else {
-            cmp::max(self.north_west.abs() as u64, self.north_east.abs() as u64)
+            cmp::max(self.north_west.unsigned_abs(), self.north_east.unsigned_abs())
         }
     }
 }
@@ -73,23 +77,25 @@ impl HexWalker {
         self.pos = self.pos.neighbor(dir);
     }
 
-    fn parse_str(&mut self, input: &str) -> u64 {
+    fn parse_str(&mut self, input: &str) -> Result<u64, ParseError> {
         use std::cmp;
 
-        input.split(',').fold(0, |max, dir_str| {
-            let dir = match dir_str {
+        let mut max = 0;
+        for dir_str in Span::new(input).split(",") {
+            let dir = match dir_str.as_str() {
                 "n" => HexDirection::North,
                 "nw" => HexDirection::NorthWest,
                 "sw" => HexDirection::SouthWest,
                 "s" => HexDirection::South,
                 "se" => HexDirection::SouthEast,
                 "ne" => HexDirection::NorthEast,
-                _ => unreachable!(),
+                _ => return Err(dir_str.error("one of `n`, `nw`, `sw`, `s`, `se` or `ne`")),
             };
             self.walk_to_dir(&dir);
             let dist = self.pos.steps_to_origin();
-            cmp::max(max, dist)
-        })
+            max = cmp::max(max, dist);
+        }
+        Ok(max)
     }
 
     fn steps_to_origin(&self) -> u64 {
@@ -97,29 +103,37 @@ impl HexWalker {
     }
 }
 
-fn read_file(filename: &str) -> String {
-    use std::fs::File;
-    use std::io::prelude::*;
-
-    let mut f = File::open(filename).expect("file not found");
-    let mut contents = String::new();
-    f.read_to_string(&mut contents).expect(
-        "something went wrong reading the file",
-    );
-    contents.trim().to_owned()
-}
-
-fn compute_solution_part_one(input: &str) -> u64 {
+fn compute_solution_part_one(input: &str) -> Result<u64, ParseError> {
     let mut walker = HexWalker::new();
-    walker.parse_str(input);
-    walker.steps_to_origin()
+    walker.parse_str(input)?;
+    Ok(walker.steps_to_origin())
 }
 
-fn compute_solution_part_two(input: &str) -> u64 {
+fn compute_solution_part_two(input: &str) -> Result<u64, ParseError> {
     let mut walker = HexWalker::new();
     walker.parse_str(input)
 }
 
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2017
+    }
+
+    fn day(&self) -> u8 {
+        11
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
@@ -127,33 +141,20 @@ mod tests {
         use compute_solution_part_one;
         use compute_solution_part_two;
 
-        let examples = vec!["ne,ne,ne", "ne,ne,sw,sw", "ne,ne,s,s", "se,sw,se,sw,sw"];
-        let solutions = vec![3, 0, 2, 3];
+        let examples = ["ne,ne,ne", "ne,ne,sw,sw", "ne,ne,s,s", "se,sw,se,sw,sw"];
+        let solutions = [3, 0, 2, 3];
         examples
             .iter()
             .zip(solutions.iter())
-            .map(|(e, s)| assert_eq!(compute_solution_part_one(e), *s))
+            .map(|(e, s)| assert_eq!(compute_solution_part_one(e).unwrap(), *s))
             .count();
 
-        let examples = vec!["ne,ne,ne", "ne,ne,sw,sw", "ne,ne,s,s", "se,sw,se,sw,sw"];
-        let solutions = vec![3, 2, 2, 3];
+        let examples = ["ne,ne,ne", "ne,ne,sw,sw", "ne,ne,s,s", "se,sw,se,sw,sw"];
+        let solutions = [3, 2, 2, 3];
         examples
             .iter()
             .zip(solutions.iter())
-            .map(|(e, s)| assert_eq!(compute_solution_part_two(e), *s))
+            .map(|(e, s)| assert_eq!(compute_solution_part_two(e).unwrap(), *s))
             .count();
     }
 }
-
-fn main() {
-    use std::env;
-    let args: Vec<String> = env::args().collect();
-
-    if args.len() != 2 {
-        println!("Usage: d11 <input filename>");
-    } else {
-        let input = read_file(&args[1]);
-        println!("solution 1 = {}", compute_solution_part_one(&input));
-        println!("solution 2 = {}", compute_solution_part_two(&input));
-    }
-}
@@ -1,7 +1,6 @@
-#[macro_use]
-extern crate lazy_static;
-extern crate regex;
+extern crate aoc_common;
 
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
 use std::collections::{BTreeMap, BTreeSet};
 
 struct GroupInfo {
@@ -13,8 +12,8 @@ impl GroupInfo {
     fn new(connections: BTreeMap<usize, Vec<usize>>) -> GroupInfo {
         let groups = connections.keys().map(|k| (*k, None)).collect();
         GroupInfo {
-            connections: connections,
-            groups: groups,
+            connections,
+            groups,
         }
     }
 
@@ -44,7 +43,7 @@ impl GroupInfo {
     fn compute_all_groups(&mut self) {
         loop {
             let nr = self.groups.iter().map(|(a, b)| (*a, *b)).find(
-                |x| x.1 == None,
+                |x| x.1.is_none(),
             );
 
             match nr {
@@ -73,56 +72,73 @@ impl GroupInfo {
     }
 }
 
-fn read_file(filename: &str) -> String {
-    use std::fs::File;
-    use std::io::prelude::*;
-
-    let mut f = File::open(filename).expect("file not found");
-    let mut contents = String::new();
-    f.read_to_string(&mut contents).expect(
-        "something went wrong reading the file",
-    );
-    contents.trim().to_owned()
-}
-
-fn convert_input(input: &str) -> BTreeMap<usize, Vec<usize>> {
-    use regex::Regex;
-
-    lazy_static! {
-        static ref RE_LINE: Regex = Regex::new("([0-9]+) <-> ([0-9, ]+)").unwrap();
-        static ref RE_NEIGHBORS: Regex = Regex::new("([0-9]+)").unwrap();
-    }
-
+fn convert_input(input: &str) -> Result<BTreeMap<usize, Vec<usize>>, ParseError> {
     let mut result = BTreeMap::new();
-
-    for cap in RE_LINE.captures_iter(input) {
-        let program = cap.get(1).unwrap().as_str().to_owned().parse().unwrap();
-        let neighbors_str = cap.get(2).unwrap().as_str();
-
-        let neighbors = RE_NEIGHBORS
-            .captures_iter(neighbors_str)
-            .map(|cap| {
-                cap.get(1).unwrap().as_str().to_owned().parse().unwrap()
-            })
-            .collect();
+    let mut all_neighbors = Vec::new();
+
+    for line in Span::new(input).lines() {
+        let (program, neighbors_str) = line.trim().split_once(" <-> ")?;
+        let program = program.parse("a program id")?;
+        let neighbors = neighbors_str
+            .split(", ")
+            .map(|s| s.parse("a program id"))
+            .collect::<Result<_, _>>()?;
         result.insert(program, neighbors);
+        all_neighbors.push(neighbors_str);
     }
 
-    result
+    // Every neighbor needs a line of its own, otherwise its group can not be computed
+    for neighbors_str in all_neighbors {
+        for s in neighbors_str.split(", ") {
+            if !result.contains_key(&s.parse("a program id")?) {
+                return Err(s.error("a program that has a line of its own"));
+            }
+        }
+    }
+
+    Ok(result)
 }
 
-fn compute_solution_part_one(input: &str) -> usize {
-    let connections = convert_input(input);
+fn compute_solution_part_one(input: &str) -> Result<usize, ParseError> {
+    let connections = convert_input(input)?;
+    if !connections.contains_key(&0) {
+        return Err(Span::new(input).error("a line for program 0"));
+    }
     let mut group_info = GroupInfo::new(connections);
     group_info.compute_group(0);
-    group_info.group_size(0)
+    Ok(group_info.group_size(0))
 }
 
-fn compute_solution_part_two(input: &str) -> usize {
-    let connections = convert_input(input);
+fn compute_solution_part_two(input: &str) -> Result<usize, ParseError> {
+    let connections = convert_input(input)?;
     let mut group_info = GroupInfo::new(connections);
     group_info.compute_all_groups();
-    group_info.total_groups()
+    Ok(group_info.total_groups())
+}
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2017
+    }
+
+    fn day(&self) -> u8 {
+        12
+    }
+
+    fn parse(&self, input: &Input) -> Result<bool, ParseError> {
+        std::hint::black_box(convert_input(input.trimmed())?);
+        Ok(true)
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
+    }
 }
 
 #[cfg(test)]
@@ -140,23 +156,10 @@ mod tests {
                      5 <-> 6
                      6 <-> 4, 5";
 
-        let solution = compute_solution_part_one(input);
+        let solution = compute_solution_part_one(input).unwrap();
         assert_eq!(solution, 6);
 
-        let solution = compute_solution_part_two(input);
+        let solution = compute_solution_part_two(input).unwrap();
         assert_eq!(solution, 2);
     }
 }
-
-fn main() {
-    use std::env;
-    let args: Vec<String> = env::args().collect();
-
-    if args.len() != 2 {
-        println!("Usage: d12 <input filename>");
-    } else {
-        let input = read_file(&args[1]);
-        println!("solution 1 = {}", compute_solution_part_one(&input));
-        println!("solution 2 = {}", compute_solution_part_two(&input));
-    }
-}
@@ -0,0 +1,114 @@
+extern crate aoc_common;
+
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
+
+struct Scanner {
+    depth: usize,
+    range: usize,
+}
+
+impl Scanner {
+    fn new(depth: usize, range: usize) -> Scanner {
+        Scanner {
+            depth,
+            range,
+        }
+    }
+
+    fn from_str(input: Span) -> Result<Scanner, ParseError> {
+        let (depth, range) = input.split_once(": ")?;
+        let depth = depth.trim().parse("a depth")?;
+        let range = range.trim();
+        match range.parse("a range")? {
+            range if range >= 2 => Ok(Scanner::new(depth, range)),
+            _ => Err(range.error("a range of at least 2")),
+        }
+    }
+
+    fn catch(&self, delay: usize) -> bool {
+        let state = (delay + self.depth) % (2 * (self.range - 1));
+        state == 0
+    }
+
+    fn severity(&self, delay: usize) -> usize {
+        if self.catch(delay) {
+            self.depth * self.range
+        } else {
+            0
+        }
+    }
+}
+
+fn convert_input(input: &str) -> Result<Vec<Scanner>, ParseError> {
+    Span::new(input)
+        .lines()
+        .map(|s| Scanner::from_str(s.trim()))
+        .collect()
+}
+
+fn compute_solution_part_one(input: &str) -> Result<usize, ParseError> {
+    let scanners = convert_input(input)?;
+    Ok(scanners.iter().map(|s| s.severity(0)).sum())
+}
+
+fn compute_solution_part_two(input: &str) -> Result<usize, ParseError> {
+    let scanners = convert_input(input)?;
+    for delay in 0.. {
+        let mut passed = true;
+        for scanner in &scanners {
+            if scanner.catch(delay) {
+                passed = false;
+                break;
+            }
+        }
+        if passed {
+            return Ok(delay);
+        }
+    }
+    unreachable!();
+}
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2017
+    }
+
+    fn day(&self) -> u8 {
+        13
+    }
+
+    fn parse(&self, input: &Input) -> Result<bool, ParseError> {
+        std::hint::black_box(convert_input(input.trimmed())?);
+        Ok(true)
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn examples() {
+        use compute_solution_part_one;
+        use compute_solution_part_two;
+
+        let input = "0: 3
+                     1: 2
+                     4: 4
+                     6: 4";
+
+        let solution = compute_solution_part_one(input).unwrap();
+        assert_eq!(solution, 24);
+
+        let solution = compute_solution_part_two(input).unwrap();
+        assert_eq!(solution, 10);
+    }
+}
@@ -1,3 +1,6 @@
+extern crate aoc_common;
+
+use aoc_common::{Answer, Input, ParseError, Puzzle};
 use std::collections::{BTreeMap, BTreeSet};
 
 struct XorIter<'a> {
@@ -7,7 +10,7 @@ struct XorIter<'a> {
 
 impl<'a> XorIter<'a> {
     fn new(vals: &'a [usize]) -> XorIter<'a> {
-        XorIter { vals: vals, i: 0 }
+        XorIter { vals, i: 0 }
     }
 }
 
@@ -36,13 +39,13 @@ struct BitIter<'a> {
 }
 
 impl<'a> BitIter<'a> {
-    fn new(vals: &'a [usize], from_bit: usize) -> BitIter {
+    fn new(vals: &'a [usize], from_bit: usize) -> BitIter<'a> {
         let mut xor_iter = XorIter::new(vals);
         let xor = xor_iter.next();
         BitIter {
-            xor_iter: xor_iter,
-            xor: xor,
-            from_bit: from_bit,
+            xor_iter,
+            xor,
+            from_bit,
             bit: 0,
         }
     }
@@ -79,7 +82,7 @@ impl Hasher {
         Hasher {
             position: 0,
             skip: 0,
-            vals: vals,
+            vals,
         }
     }
 
@@ -120,11 +123,11 @@ impl Hasher {
             .count();
     }
 
-    fn xor_iter(&self) -> XorIter {
+    fn xor_iter(&self) -> XorIter<'_> {
         XorIter::new(&self.vals)
     }
 
-    fn bit_iter(&self) -> BitIter {
+    fn bit_iter(&self) -> BitIter<'_> {
         BitIter::new(&self.vals, 7)
     }
 
@@ -178,10 +181,7 @@ impl GroupCounter {
     }
 
     fn insert_top(&mut self, column: usize) {
-        let gn = match self.map.get(&(0, column - 1)) {
-            Some(gn) => Some(*gn),
-            None => None,
-        };
+        let gn = self.map.get(&(0, column - 1)).copied();
 
         match gn {
             Some(gn) => {
@@ -192,10 +192,7 @@ impl GroupCounter {
     }
 
     fn insert_left(&mut self, row: usize) {
-        let gn = match self.map.get(&(row - 1, 0)) {
-            Some(gn) => Some(*gn),
-            None => None,
-        };
+        let gn = self.map.get(&(row - 1, 0)).copied();
 
         match gn {
             Some(gn) => {
@@ -282,18 +279,6 @@ impl GroupCounter {
     }
 }
 
-fn read_file(filename: &str) -> String {
-    use std::fs::File;
-    use std::io::prelude::*;
-
-    let mut f = File::open(filename).expect("file not found");
-    let mut contents = String::new();
-    f.read_to_string(&mut contents).expect(
-        "something went wrong reading the file",
-    );
-    contents.trim().to_owned()
-}
-
 fn compute_solution_part_one(input: &str) -> usize {
     let mut hasher = Hasher::new(256);
 
@@ -330,6 +315,26 @@ fn compute_solution_part_two(input: &str) -> usize {
     group_counter.number_of_groups()
 }
 
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2017
+    }
+
+    fn day(&self) -> u8 {
+        14
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        Ok(compute_solution_part_one(input.trimmed()).into())
+    }
+
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(Ok(compute_solution_part_two(input.trimmed()).into()))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
@@ -346,16 +351,3 @@ mod tests {
 
     }
 }
-
-fn main() {
-    use std::env;
-    let args: Vec<String> = env::args().collect();
-
-    if args.len() != 2 {
-        println!("Usage: d14 <input filename>");
-    } else {
-        let input = read_file(&args[1]);
-        println!("solution 1 = {}", compute_solution_part_one(&input));
-        println!("solution 2 = {}", compute_solution_part_two(&input));
-    }
-}
@@ -0,0 +1,127 @@
+extern crate aoc_common;
+
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
+
+struct Generator {
+    state: u64,
+    multiplier: u64,
+    divider: u64,
+}
+
+impl Generator {
+    fn new(state: u64, multiplier: u64, divider: u64) -> Generator {
+        Generator {
+            state,
+            multiplier,
+            divider,
+        }
+    }
+
+    fn new_a(state: u64) -> Generator {
+        Generator::new(state, 16_807, 2_147_483_647)
+    }
+
+    fn new_b(state: u64) -> Generator {
+        Generator::new(state, 48_271, 2_147_483_647)
+    }
+
+    fn step(&mut self) -> u64 {
+        self.state = (self.state * self.multiplier) % self.divider;
+        self.state
+    }
+
+    fn step_until_divisible_by(&mut self, nr: u64) -> u64 {
+        loop {
+            let result = self.step();
+            if result.is_multiple_of(nr) {
+                break result;
+            }
+        }
+    }
+}
+
+fn convert_input(input: &str) -> Result<[u64; 2], ParseError> {
+    let mut lines = Span::new(input).lines();
+    let mut seed = |name: &str| -> Result<u64, ParseError> {
+        let line = lines.next().unwrap_or_else(|| Span::new(input).end());
+        let prefix = format!("Generator {} starts with ", name);
+        line.trim().strip_prefix(&prefix)?.parse("a number")
+    };
+    let result = [seed("A")?, seed("B")?];
+    match lines.next() {
+        Some(line) => Err(line.error("nothing more")),
+        None => Ok(result),
+    }
+}
+
+fn compute_solution_part_one(input: &str) -> Result<usize, ParseError> {
+    let seeds = convert_input(input)?;
+    let mut gen_a = Generator::new_a(seeds[0]);
+    let mut gen_b = Generator::new_b(seeds[1]);
+
+    Ok((0..40_000_000)
+        .map(|_| {
+            let a = gen_a.step() & 0b1111_1111_1111_1111;
+            let b = gen_b.step() & 0b1111_1111_1111_1111;
+            a == b
+        })
+        .filter(|b| *b)
+        .count())
+}
+
+fn compute_solution_part_two(input: &str) -> Result<usize, ParseError> {
+    let seeds = convert_input(input)?;
+    let mut gen_a = Generator::new_a(seeds[0]);
+    let mut gen_b = Generator::new_b(seeds[1]);
+
+    Ok((0..5_000_000)
+        .map(|_| {
+            let a = gen_a.step_until_divisible_by(4) & 0b1111_1111_1111_1111;
+            let b = gen_b.step_until_divisible_by(8) & 0b1111_1111_1111_1111;
+            a == b
+        })
+        .filter(|b| *b)
+        .count())
+}
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2017
+    }
+
+    fn day(&self) -> u8 {
+        15
+    }
+
+    fn parse(&self, input: &Input) -> Result<bool, ParseError> {
+        std::hint::black_box(convert_input(input.trimmed())?);
+        Ok(true)
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn examples() {
+        use compute_solution_part_one;
+        use compute_solution_part_two;
+
+        let input = "Generator A starts with 65
+                     Generator B starts with 8921";
+        let solution = compute_solution_part_one(input).unwrap();
+        assert_eq!(solution, 588);
+
+        let solution = compute_solution_part_two(input).unwrap();
+        assert_eq!(solution, 309);
+    }
+}
@@ -1,6 +1,6 @@
-#[macro_use]
-extern crate lazy_static;
-extern crate regex;
+extern crate aoc_common;
+
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
 
 #[derive(Debug)]
 enum Instruction {
@@ -18,7 +18,7 @@ struct Programs {
 impl Programs {
     fn new(n: u8) -> Programs {
         let vals = (0..n).collect();
-        Programs { vals: vals }
+        Programs { vals }
     }
 
     fn spin(&mut self, n: usize) {
@@ -70,9 +70,12 @@ impl Programs {
             Instruction::Table(ref vals) => self.table(vals),
         }
     }
+}
 
-    fn to_string(&self) -> String {
-        self.vals.iter().map(|i| Programs::u8_to_char(*i)).collect()
+impl std::fmt::Display for Programs {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let s: String = self.vals.iter().map(|i| Programs::u8_to_char(*i)).collect();
+        write!(f, "{}", s)
     }
 }
 
@@ -99,62 +102,57 @@ fn compress_instructions(len: u8, instructions: &[Instruction]) -> Vec<Instructi
     result
 }
 
-fn read_file(filename: &str) -> String {
-    use std::fs::File;
-    use std::io::prelude::*;
-
-    let mut f = File::open(filename).expect("file not found");
-    let mut contents = String::new();
-    f.read_to_string(&mut contents).expect(
-        "something went wrong reading the file",
-    );
-    contents.trim().to_owned()
-}
-
-fn convert_input(input: &str) -> Vec<Instruction> {
-    use regex::Regex;
-
-    lazy_static! {
-        static ref RE_SP: Regex = Regex::new(r"s([0-9]+)").unwrap();
-        static ref RE_EX: Regex = Regex::new(r"x([0-9]+)/([0-9]+)").unwrap();
-        static ref RE_PA: Regex = Regex::new(r"p([a-z])/([a-z])").unwrap();
-    }
+fn convert_input(input: &str, len: u8) -> Result<Vec<Instruction>, ParseError> {
+    let position = |s: Span| -> Result<usize, ParseError> {
+        match s.parse("a position")? {
+            p if p < len as usize => Ok(p),
+            _ => Err(s.error(format!("a position below {}", len))),
+        }
+    };
+    let program = |s: Span| -> Result<char, ParseError> {
+        let mut chars = s.as_str().chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) if ('a'..(b'a' + len) as char).contains(&c) => Ok(c),
+            _ => Err(s.error(format!("a program from `a` to `{}`", (b'a' + len - 1) as char))),
+        }
+    };
 
     let mut result = Vec::new();
 
-    for s in input.split(',') {
-        if let Some(cap) = RE_SP.captures(s) {
-            let tmp = cap[1].parse().unwrap();
+    for s in Span::new(input).split(",") {
+        if let Ok(rest) = s.strip_prefix("s") {
+            let tmp = match rest.parse("a spin size")? {
+                n if n <= len as usize => n,
+                _ => return Err(rest.error(format!("a spin size of at most {}", len))),
+            };
             result.push(Instruction::Spin(tmp));
-        } else if let Some(cap) = RE_EX.captures(s) {
-            let tmp = cap[1].parse().unwrap();
-            let tmp2 = cap[2].parse().unwrap();
-            result.push(Instruction::Exchange((tmp, tmp2)));
-        } else if let Some(cap) = RE_PA.captures(s) {
-            let tmp = cap[1].chars().nth(0).unwrap();
-            let tmp2 = cap[2].chars().nth(0).unwrap();
-            result.push(Instruction::Partner((tmp, tmp2)));
+        } else if let Ok(rest) = s.strip_prefix("x") {
+            let (a, b) = rest.split_once("/")?;
+            result.push(Instruction::Exchange((position(a)?, position(b)?)));
+        } else if let Ok(rest) = s.strip_prefix("p") {
+            let (a, b) = rest.split_once("/")?;
+            result.push(Instruction::Partner((program(a)?, program(b)?)));
         } else {
-            unreachable!();
+            return Err(s.error("a dance move (`sN`, `xA/B` or `pA/B`)"));
         }
     }
 
-    result
+    Ok(result)
 }
 
-fn compute_solution_part_one(input: &str, len: u8) -> String {
-    let instructions = convert_input(input);
+fn compute_solution_part_one(input: &str, len: u8) -> Result<String, ParseError> {
+    let instructions = convert_input(input, len)?;
     let mut programs = Programs::new(len);
 
     for instruction in &instructions {
         programs.run_instruction(instruction);
     }
 
-    programs.to_string()
+    Ok(programs.to_string())
 }
 
-fn compute_solution_part_two(input: &str, len: u8) -> String {
-    let instructions = convert_input(input);
+fn compute_solution_part_two(input: &str, len: u8) -> Result<String, ParseError> {
+    let instructions = convert_input(input, len)?;
     let instructions = compress_instructions(len, &instructions);
     let mut programs = Programs::new(len);
 
@@ -178,7 +176,32 @@ fn compute_solution_part_two(input: &str, len: u8) -> String {
         }
     }
 
-    programs.to_string()
+    Ok(programs.to_string())
+}
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2017
+    }
+
+    fn day(&self) -> u8 {
+        16
+    }
+
+    fn parse(&self, input: &Input) -> Result<bool, ParseError> {
+        std::hint::black_box(convert_input(input.trimmed(), 16)?);
+        Ok(true)
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed(), 16).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed(), 16).map(Answer::from))
+    }
 }
 
 #[cfg(test)]
@@ -189,24 +212,11 @@ mod tests {
         use compute_solution_part_two;
 
         let input = "s1,x3/4,pe/b";
-        let solution = compute_solution_part_one(input, 5);
+        let solution = compute_solution_part_one(input, 5).unwrap();
         assert_eq!(solution, "baedc");
 
-        let solution = compute_solution_part_two(input, 5);
+        let solution = compute_solution_part_two(input, 5).unwrap();
         assert_eq!(solution, "abcde");
 
     }
 }
-
-fn main() {
-    use std::env;
-    let args: Vec<String> = env::args().collect();
-
-    if args.len() != 2 {
-        println!("Usage: d16 <input filename>");
-    } else {
-        let input = read_file(&args[1]);
-        println!("solution 1 = {}", compute_solution_part_one(&input, 16));
-        println!("solution 2 = {}", compute_solution_part_two(&input, 16));
-    }
-}
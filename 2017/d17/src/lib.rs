@@ -0,0 +1,72 @@
+extern crate aoc_common;
+
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
+
+fn compute_solution_part_one(input: &str) -> Result<usize, ParseError> {
+    let input_number = Span::new(input).parse::<usize>("a number of steps")?;
+
+    let mut state = vec![0];
+    let mut pos = 0;
+
+    for i in 1..2018 {
+        pos = (pos + input_number) % state.len();
+        state.insert(pos + 1, i);
+        pos += 1;
+    }
+
+    Ok(state[(pos + 1) % state.len()])
+}
+
+fn compute_solution_part_two(input: &str) -> Result<usize, ParseError> {
+    let input_number = Span::new(input).parse::<usize>("a number of steps")?;
+
+    let mut pos = 0;
+    let mut at_pos_one = 0;
+
+    // the buffer length equals i before inserting the i-th value
+    for i in 1..50_000_000 {
+        pos = (pos + input_number) % i;
+        if pos == 0 {
+            at_pos_one = i;
+        }
+        pos += 1;
+    }
+
+    Ok(at_pos_one)
+}
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2017
+    }
+
+    fn day(&self) -> u8 {
+        17
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn examples() {
+        use compute_solution_part_one;
+        use compute_solution_part_two;
+
+        let input = "3";
+        let solution = compute_solution_part_one(input).unwrap();
+        assert_eq!(solution, 638);
+
+        let solution = compute_solution_part_two(input).unwrap();
+        assert_eq!(solution, 1222153);
+    }
+}
@@ -1,9 +1,16 @@
-#[macro_use]
-extern crate lazy_static;
-extern crate regex;
+extern crate aoc_common;
 
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span, Tokens};
 use std::collections::{BTreeMap, VecDeque};
 
+fn parse_register(tokens: &mut Tokens) -> Result<String, ParseError> {
+    let s = tokens.expect("a register")?;
+    if s.is_empty() || !s.as_str().chars().all(|c| c.is_ascii_lowercase()) {
+        return Err(s.error("a register"));
+    }
+    Ok(s.as_str().to_owned())
+}
+
 #[derive(Clone, Debug)]
 enum InstructionValue {
     Number(i64),
@@ -11,11 +18,14 @@ enum InstructionValue {
 }
 
 impl InstructionValue {
-    fn parse(s: &str) -> InstructionValue {
-        let v = s.parse::<i64>();
-        match v {
-            Ok(x) => InstructionValue::Number(x),
-            _ => InstructionValue::Name(s.to_owned()),
+    fn parse(tokens: &mut Tokens) -> Result<InstructionValue, ParseError> {
+        let s = tokens.expect("a number or a register")?;
+        match s.parse::<i64>("a number") {
+            Ok(x) => Ok(InstructionValue::Number(x)),
+            _ if s.as_str().chars().all(|c| c.is_ascii_lowercase()) => {
+                Ok(InstructionValue::Name(s.as_str().to_owned()))
+            }
+            _ => Err(s.error("a number or a register")),
         }
     }
 }
@@ -46,10 +56,10 @@ impl Machine {
     fn new(instructions: Vec<Instruction>, default_p: i64) -> Machine {
         Machine {
             registers: BTreeMap::new(),
-            instructions: instructions,
+            instructions,
             next_inst: Some(0),
             signal_queue: VecDeque::new(),
-            default_p: default_p,
+            default_p,
             wait: false,
             output: None,
             sent_values: 0,
@@ -126,7 +136,7 @@ impl Machine {
         if vx > 0 {
             let vy = self.parse_instruction_value(y);
             let neg = vy < 0;
-            let vy = vy.abs() as usize;
+            let vy = vy.unsigned_abs() as usize;
             let next_inst = self.next_inst.unwrap();
             self.next_inst = if neg && vy < next_inst {
                 Some(next_inst - vy)
@@ -150,10 +160,7 @@ impl Machine {
 
     fn run_instruction(&mut self) {
         let next_inst = self.next_inst.unwrap();
-        let increase = match self.instructions[next_inst] {
-            Instruction::Jgz((_, _)) => false,
-            _ => true,
-        };
+        let increase = !matches!(self.instructions[next_inst], Instruction::Jgz((_, _)));
 
         match self.instructions[next_inst].clone() {
             Instruction::Snd(x) => self.snd(&x),
@@ -171,71 +178,33 @@ impl Machine {
     }
 }
 
-fn read_file(filename: &str) -> String {
-    use std::fs::File;
-    use std::io::prelude::*;
-
-    let mut f = File::open(filename).expect("file not found");
-    let mut contents = String::new();
-    f.read_to_string(&mut contents).expect(
-        "something went wrong reading the file",
-    );
-    contents.trim().to_owned()
-}
-
-fn convert_input(input: &str) -> Vec<Instruction> {
-    use regex::Regex;
-
-    lazy_static! {
-        static ref RE_SND: Regex = Regex::new("snd (-?[0-9]+|[a-z]+)").unwrap();
-        static ref RE_SET: Regex = Regex::new("set ([a-z]+) (-?[0-9]+|[a-z]+)").unwrap();
-        static ref RE_ADD: Regex = Regex::new("add ([a-z]+) (-?[0-9]+|[a-z]+)").unwrap();
-        static ref RE_MUL: Regex = Regex::new("mul ([a-z]+) (-?[0-9]+|[a-z]+)").unwrap();
-        static ref RE_MOD: Regex = Regex::new("mod ([a-z]+) (-?[0-9]+|[a-z]+)").unwrap();
-        static ref RE_RCV: Regex = Regex::new("rcv ([a-z]+)").unwrap();
-        static ref RE_JGZ: Regex = Regex::new("jgz (-?[0-9]+|[a-z]+) (-?[0-9]+|[a-z]+)").unwrap();
-    }
-
+fn convert_input(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    let reg = parse_register;
+    let val = InstructionValue::parse;
     let mut result = Vec::new();
 
-    for s in input.split('\n') {
-        let s = s.trim();
-        if let Some(cap) = RE_SND.captures(s) {
-            let x = InstructionValue::parse(&cap[1]);
-            result.push(Instruction::Snd(x));
-        } else if let Some(cap) = RE_SET.captures(s) {
-            let x = cap[1].to_owned();
-            let y = InstructionValue::parse(&cap[2]);
-            result.push(Instruction::Set((x, y)));
-        } else if let Some(cap) = RE_ADD.captures(s) {
-            let x = cap[1].to_owned();
-            let y = InstructionValue::parse(&cap[2]);
-            result.push(Instruction::Add((x, y)));
-        } else if let Some(cap) = RE_MUL.captures(s) {
-            let x = cap[1].to_owned();
-            let y = InstructionValue::parse(&cap[2]);
-            result.push(Instruction::Mul((x, y)));
-        } else if let Some(cap) = RE_MOD.captures(s) {
-            let x = cap[1].to_owned();
-            let y = InstructionValue::parse(&cap[2]);
-            result.push(Instruction::Mod((x, y)));
-        } else if let Some(cap) = RE_RCV.captures(s) {
-            let x = cap[1].to_owned();
-            result.push(Instruction::Rcv(x));
-        } else if let Some(cap) = RE_JGZ.captures(s) {
-            let x = InstructionValue::parse(&cap[1]);
-            let y = InstructionValue::parse(&cap[2]);
-            result.push(Instruction::Jgz((x, y)));
-        } else {
-            unreachable!();
-        }
+    for s in Span::new(input).lines() {
+        let mut tokens = s.split_whitespace();
+        let name = tokens.expect("an instruction")?;
+        let instruction = match name.as_str() {
+            "snd" => Instruction::Snd(val(&mut tokens)?),
+            "set" => Instruction::Set((reg(&mut tokens)?, val(&mut tokens)?)),
+            "add" => Instruction::Add((reg(&mut tokens)?, val(&mut tokens)?)),
+            "mul" => Instruction::Mul((reg(&mut tokens)?, val(&mut tokens)?)),
+            "mod" => Instruction::Mod((reg(&mut tokens)?, val(&mut tokens)?)),
+            "rcv" => Instruction::Rcv(reg(&mut tokens)?),
+            "jgz" => Instruction::Jgz((val(&mut tokens)?, val(&mut tokens)?)),
+            _ => return Err(name.error("one of `snd`, `set`, `add`, `mul`, `mod`, `rcv` or `jgz`")),
+        };
+        tokens.end()?;
+        result.push(instruction);
     }
 
-    result
+    Ok(result)
 }
 
-fn compute_solution_part_one(input: &str) -> i64 {
-    let instructions = convert_input(input);
+fn compute_solution_part_one(input: &str) -> Result<i64, ParseError> {
+    let instructions = convert_input(input)?;
     let mut machine = Machine::new(instructions, 0);
     machine.signal_queue.push_back(0);
     loop {
@@ -244,14 +213,14 @@ fn compute_solution_part_one(input: &str) -> i64 {
         }
         let next_inst = machine.next_inst.unwrap();
         if let Instruction::Rcv(_) = machine.instructions[next_inst].clone() {
-            break machine.signal_queue.pop_back().unwrap();
+            break Ok(machine.signal_queue.pop_back().unwrap());
         }
         machine.run_instruction();
     }
 }
 
-fn compute_solution_part_two(input: &str) -> usize {
-    let instructions = convert_input(input);
+fn compute_solution_part_two(input: &str) -> Result<usize, ParseError> {
+    let instructions = convert_input(input)?;
     let mut machine_0 = Machine::new(instructions.clone(), 0);
     let mut machine_1 = Machine::new(instructions.clone(), 1);
 
@@ -262,11 +231,36 @@ fn compute_solution_part_two(input: &str) -> usize {
         machine_1.run_instruction();
 
         if machine_0.wait && machine_1.wait {
-            break machine_1.sent_values;
+            break Ok(machine_1.sent_values);
         }
     }
 }
 
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2017
+    }
+
+    fn day(&self) -> u8 {
+        18
+    }
+
+    fn parse(&self, input: &Input) -> Result<bool, ParseError> {
+        std::hint::black_box(convert_input(input.trimmed())?);
+        Ok(true)
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
@@ -285,23 +279,10 @@ mod tests {
                      set a 1
                      jgz a -2";
 
-        let solution = compute_solution_part_one(input);
+        let solution = compute_solution_part_one(input).unwrap();
         assert_eq!(solution, 4);
 
-        let solution = compute_solution_part_two(input);
+        let solution = compute_solution_part_two(input).unwrap();
         assert_eq!(solution, 1);
     }
 }
-
-fn main() {
-    use std::env;
-    let args: Vec<String> = env::args().collect();
-
-    if args.len() != 2 {
-        println!("Usage: d18 <input filename>");
-    } else {
-        let input = read_file(&args[1]);
-        println!("solution 1 = {}", compute_solution_part_one(&input));
-        println!("solution 2 = {}", compute_solution_part_two(&input));
-    }
-}
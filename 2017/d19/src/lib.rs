@@ -1,3 +1,6 @@
+extern crate aoc_common;
+
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
 use std::collections::BTreeMap;
 
 enum Direction {
@@ -30,7 +33,7 @@ struct Maze {
 impl Maze {
     fn new(fields: BTreeMap<(usize, usize), Field>) -> Maze {
         let mut result = Maze {
-            fields: fields,
+            fields,
             i: 0,
             j: 0,
             width: 0,
@@ -56,11 +59,9 @@ impl Maze {
         self.n_steps = 0;
 
         for j in 0..width {
-            if let Some(field) = self.fields.get(&(0, j)) {
-                if let Field::Vertical = *field {
-                    self.j = j;
-                    break;
-                }
+            if let Some(Field::Vertical) = self.fields.get(&(0, j)) {
+                self.j = j;
+                break;
             }
         }
     }
@@ -190,29 +191,18 @@ impl Maze {
     }
 }
 
-fn read_file(filename: &str) -> String {
-    use std::fs::File;
-    use std::io::prelude::*;
-
-    let mut f = File::open(filename).expect("file not found");
-    let mut contents = String::new();
-    f.read_to_string(&mut contents).expect(
-        "something went wrong reading the file",
-    );
-    contents.to_owned()
-}
-
-fn convert_input(input: &str) -> BTreeMap<(usize, usize), Field> {
+fn convert_input(input: &str) -> Result<BTreeMap<(usize, usize), Field>, ParseError> {
     let mut result = BTreeMap::new();
 
-    for (i, line) in input.split('\n').enumerate() {
-        for (j, c) in line.chars().enumerate() {
+    for (i, line) in Span::new(input).lines().enumerate() {
+        for (j, (span, c)) in line.chars().enumerate() {
             let field = match c {
                 '|' => Field::Vertical,
                 '-' => Field::Horizontal,
                 '+' => Field::Curve,
                 ' ' => Field::Empty,
-                _ => Field::Letter(c),
+                'A'..='Z' => Field::Letter(c),
+                _ => return Err(span.error("`|`, `-`, `+`, a space or a letter")),
             };
 
             match field {
@@ -222,29 +212,64 @@ fn convert_input(input: &str) -> BTreeMap<(usize, usize), Field> {
         }
     }
 
-    result
+    let starts = result
+        .iter()
+        .any(|(&(i, _), field)| i == 0 && matches!(field, Field::Vertical));
+    if !starts {
+        let first_line = Span::new(input).lines().next();
+        return Err(first_line
+            .unwrap_or_else(|| Span::new(input))
+            .error("a `|` in the first line where the path starts"));
+    }
+
+    Ok(result)
 }
 
-fn compute_solution_part_one(input: &str) -> String {
-    let fields = convert_input(input);
+fn compute_solution_part_one(input: &str) -> Result<String, ParseError> {
+    let fields = convert_input(input)?;
     let mut maze = Maze::new(fields);
 
     while !maze.finished {
         maze.move_pos();
     }
 
-    maze.path_chars.iter().collect()
+    Ok(maze.path_chars.iter().collect())
 }
 
-fn compute_solution_part_two(input: &str) -> usize {
-    let fields = convert_input(input);
+fn compute_solution_part_two(input: &str) -> Result<usize, ParseError> {
+    let fields = convert_input(input)?;
     let mut maze = Maze::new(fields);
 
     while !maze.finished {
         maze.move_pos();
     }
 
-    maze.n_steps
+    Ok(maze.n_steps)
+}
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2017
+    }
+
+    fn day(&self) -> u8 {
+        19
+    }
+
+    fn parse(&self, input: &Input) -> Result<bool, ParseError> {
+        std::hint::black_box(convert_input(input.untrimmed())?);
+        Ok(true)
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.untrimmed()).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.untrimmed()).map(Answer::from))
+    }
 }
 
 #[cfg(test)]
@@ -264,23 +289,10 @@ mod tests {
 
         let input = input.split('\n').skip(1).collect::<Vec<&str>>().join("\n");
 
-        let solution = compute_solution_part_one(&input);
+        let solution = compute_solution_part_one(&input).unwrap();
         assert_eq!(solution, "ABCDEF");
 
-        let solution = compute_solution_part_two(&input);
+        let solution = compute_solution_part_two(&input).unwrap();
         assert_eq!(solution, 38);
     }
 }
-
-fn main() {
-    use std::env;
-    let args: Vec<String> = env::args().collect();
-
-    if args.len() != 2 {
-        println!("Usage: d19 <input filename>");
-    } else {
-        let input = read_file(&args[1]);
-        println!("solution 1 = {}", compute_solution_part_one(&input));
-        println!("solution 2 = {}", compute_solution_part_two(&input));
-    }
-}
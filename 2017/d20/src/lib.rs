@@ -1,5 +1,6 @@
-extern crate regex;
+extern crate aoc_common;
 
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
 use std::cmp::Ordering;
 use std::ops;
 
@@ -12,7 +13,7 @@ struct Vector {
 
 impl Vector {
     fn new(x: i64, y: i64, z: i64) -> Vector {
-        Vector { x: x, y: y, z: z }
+        Vector { x, y, z }
     }
 
     fn from_slice(vals: &[i64]) -> Vector {
@@ -20,11 +21,11 @@ impl Vector {
     }
 
     fn manhattan_abs(&self) -> u64 {
-        self.x.abs() as u64 + self.y.abs() as u64 + self.z.abs() as u64
+        self.x.unsigned_abs() + self.y.unsigned_abs() + self.z.unsigned_abs()
     }
 }
 
-impl<'a> ops::AddAssign<&'a Vector> for Vector {
+impl ops::AddAssign<&Vector> for Vector {
     fn add_assign(&mut self, other: &Vector) {
         *self = Vector {
             x: self.x + other.x,
@@ -36,7 +37,7 @@ impl<'a> ops::AddAssign<&'a Vector> for Vector {
 
 impl PartialEq for Vector {
     fn eq(&self, other: &Vector) -> bool {
-        self.x == other.x && self.y == other.y && self.y == other.y
+        self.x == other.x && self.y == other.y && self.z == other.z
     }
 }
 
@@ -54,10 +55,10 @@ impl Particle {
         let v = Vector::from_slice(&vals[3..6]);
         let a = Vector::from_slice(&vals[6..9]);
         Particle {
-            p: p,
-            v: v,
-            a: a,
-            id: id,
+            p,
+            v,
+            a,
+            id,
         }
     }
 
@@ -83,69 +84,53 @@ impl Particle {
     }
 }
 
-fn read_file(filename: &str) -> String {
-    use std::fs::File;
-    use std::io::prelude::*;
-
-    let mut f = File::open(filename).expect("file not found");
-    let mut contents = String::new();
-    f.read_to_string(&mut contents).expect(
-        "something went wrong reading the file",
-    );
-    contents.trim().to_owned()
+fn parse_vector(s: Span, name: &str) -> Result<Vec<i64>, ParseError> {
+    let s = s.trim().strip_prefix(&format!("{}=<", name))?.strip_suffix(">")?;
+    let mut coordinates = s.split(",");
+    let mut vals = Vec::new();
+    for _ in 0..3 {
+        vals.push(coordinates.expect("a coordinate")?.trim().parse("a coordinate")?);
+    }
+    coordinates.end()?;
+    Ok(vals)
 }
 
-fn convert_input(input: &str) -> Vec<Particle> {
-    use regex::Regex;
-
-    let co = "(-?[0-9]+)";
-    let s = format!(
-        "p=<{},{},{}>, v=<{},{},{}>, a=<{},{},{}>",
-        co,
-        co,
-        co,
-        co,
-        co,
-        co,
-        co,
-        co,
-        co
-    );
-    let re: Regex = Regex::new(&s).unwrap();
-
+fn convert_input(input: &str) -> Result<Vec<Particle>, ParseError> {
     let mut result = Vec::new();
 
-    for (id, cap) in re.captures_iter(input).enumerate() {
-        let vals = cap.iter()
-            .skip(1)
-            .map(|c| c.unwrap().as_str().parse().unwrap())
-            .collect::<Vec<i64>>();
-        assert_eq!(vals.len(), 9);
+    for (id, line) in Span::new(input).lines().enumerate() {
+        let mut parts = line.trim().split(", ");
+        let mut vals = Vec::new();
+        for name in &["p", "v", "a"] {
+            let part = parts.expect(&format!("`{}=<x,y,z>`", name))?;
+            vals.extend(parse_vector(part, name)?);
+        }
+        parts.end()?;
         let particle = Particle::from_slice(&vals, id);
         result.push(particle);
     }
 
-    result
+    Ok(result)
 }
 
-fn compute_solution_part_one(input: &str) -> usize {
-    let particles = convert_input(input);
+fn compute_solution_part_one(input: &str) -> Result<usize, ParseError> {
+    let particles = convert_input(input)?;
     let min = particles.iter().map(|p| p.a.manhattan_abs()).min().unwrap();
     let min_particles: Vec<_> = particles
         .iter()
         .filter(|&p| p.a.manhattan_abs() == min)
         .collect();
     if min_particles.len() == 1 {
-        min_particles[0].id
+        Ok(min_particles[0].id)
     } else {
         unimplemented!();
     }
 }
 
-fn compute_solution_part_two(input: &str) -> usize {
+fn compute_solution_part_two(input: &str) -> Result<usize, ParseError> {
     use std::collections::BTreeSet;
 
-    let mut particles = convert_input(input);
+    let mut particles = convert_input(input)?;
     let mut result = 0;
 
     while !particles.is_empty() {
@@ -208,7 +193,32 @@ fn compute_solution_part_two(input: &str) -> usize {
         particles.iter_mut().map(|p| p.step()).count();
     }
 
-    result
+    Ok(result)
+}
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2017
+    }
+
+    fn day(&self) -> u8 {
+        20
+    }
+
+    fn parse(&self, input: &Input) -> Result<bool, ParseError> {
+        std::hint::black_box(convert_input(input.trimmed())?);
+        Ok(true)
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
+    }
 }
 
 #[cfg(test)]
@@ -220,27 +230,14 @@ mod tests {
 
         let input = "p=<3,0,0>, v=<2,0,0>, a=<-1,0,0>
                      p=<4,0,0>, v=<0,0,0>, a=<-2,0,0>";
-        let solution = compute_solution_part_one(input);
+        let solution = compute_solution_part_one(input).unwrap();
         assert_eq!(solution, 0);
 
         let input = "p=<-6,0,0>, v=<3,0,0>, a=<0,0,0>
                      p=<-4,0,0>, v=<2,0,0>, a=<0,0,0>
                      p=<-2,0,0>, v=<1,0,0>, a=<0,0,0>
                      p=<3,0,0>, v=<-1,0,0>, a=<0,0,0>";
-        let solution = compute_solution_part_two(input);
+        let solution = compute_solution_part_two(input).unwrap();
         assert_eq!(solution, 1);
     }
 }
-
-fn main() {
-    use std::env;
-    let args: Vec<String> = env::args().collect();
-
-    if args.len() != 2 {
-        println!("Usage: d20 <input filename>");
-    } else {
-        let input = read_file(&args[1]);
-        println!("solution 1 = {}", compute_solution_part_one(&input));
-        println!("solution 2 = {}", compute_solution_part_two(&input));
-    }
-}
@@ -1,6 +1,8 @@
-extern crate regex;
+extern crate aoc_common;
 
+use aoc_common::{Answer, Input, ParseError, Picture, PictureExport, Puzzle, Span};
 use std::collections::BTreeMap;
+use std::io;
 
 #[derive(Clone, Debug, PartialEq, Eq, Ord, PartialOrd)]
 enum Pixel {
@@ -16,10 +18,10 @@ struct Pattern {
 
 impl Pattern {
     fn new(pixels: Vec<Pixel>) -> Pattern {
-        assert!([4, 9, 16].iter().any(|&l| pixels.len() == l));
+        assert!([4, 9, 16].contains(&pixels.len()));
         let size = [2, 3, 4].iter().find(|&s| s * s == pixels.len()).unwrap();
         Pattern {
-            pixels: pixels,
+            pixels,
             size: *size,
         }
     }
@@ -92,8 +94,8 @@ impl Image {
     fn new(pixels: Vec<Pixel>, size: usize) -> Image {
         assert_eq!(pixels.len(), size * size);
         Image {
-            pixels: pixels,
-            size: size,
+            pixels,
+            size,
         }
     }
 
@@ -148,18 +150,22 @@ impl Image {
         Pattern::new(res_p)
     }
 
-    fn patern_iter(&self) -> SubImageIterator {
+    fn patern_iter(&self) -> SubImageIterator<'_> {
         SubImageIterator::new(self)
     }
 
-    fn increase_size(&mut self, rules: &BTreeMap<Pattern, Pattern>) {
-        let newsize = if self.size % 2 == 0 {
-            let npats = self.size / 2;
+    fn next_size(size: usize) -> usize {
+        if size.is_multiple_of(2) {
+            let npats = size / 2;
             npats * 3
         } else {
-            let npats = self.size / 3;
+            let npats = size / 3;
             npats * 4
-        };
+        }
+    }
+
+    fn increase_size(&mut self, rules: &BTreeMap<Pattern, Pattern>) {
+        let newsize = Image::next_size(self.size);
         let image = {
             let iter = self.patern_iter().map(|p| rules.get(&p).unwrap().clone());
             Image::from_patern_iter(iter, newsize)
@@ -176,13 +182,13 @@ struct SubImageIterator<'a> {
 }
 
 impl<'a> SubImageIterator<'a> {
-    fn new(image: &Image) -> SubImageIterator {
-        let patternsize = if image.size % 2 == 0 { 2 } else { 3 };
+    fn new(image: &Image) -> SubImageIterator<'_> {
+        let patternsize = if image.size.is_multiple_of(2) { 2 } else { 3 };
         SubImageIterator {
-            image: image,
+            image,
             i: 0,
             j: 0,
-            patternsize: patternsize,
+            patternsize,
         }
     }
 
@@ -214,109 +220,136 @@ impl<'a> Iterator for SubImageIterator<'a> {
     }
 }
 
-fn read_file(filename: &str) -> String {
-    use std::fs::File;
-    use std::io::prelude::*;
-
-    let mut f = File::open(filename).expect("file not found");
-    let mut contents = String::new();
-    f.read_to_string(&mut contents).expect(
-        "something went wrong reading the file",
-    );
-    contents.trim().to_owned()
+fn parse_pattern(s: Span, size: usize) -> Result<Pattern, ParseError> {
+    let mut rows = s.split("/");
+    let mut pixels = Vec::new();
+    for _ in 0..size {
+        let row = rows.expect(&format!("a row of {} pixels", size))?;
+        if row.as_str().chars().count() != size {
+            return Err(row.error(format!("a row of {} pixels", size)));
+        }
+        for (span, c) in row.chars() {
+            pixels.push(match c {
+                '.' => Pixel::Off,
+                '#' => Pixel::On,
+                _ => return Err(span.error("`.` or `#`")),
+            });
+        }
+    }
+    rows.end()?;
+    Ok(Pattern::new(pixels))
 }
 
-fn convert_input(input: &str) -> BTreeMap<Pattern, Pattern> {
-    use regex::Regex;
-
-    let str2 = r"(\.|#)(\.|#)".to_string();
-    let str3 = r"(\.|#)(\.|#)(\.|#)".to_string();
-    let str4 = r"(\.|#)(\.|#)(\.|#)(\.|#)".to_string();
-
-    let pat2 = str2.clone() + "/" + &str2;
-    let pat3 = str3.clone() + "/" + &str3 + "/" + &str3;
-    let pat4 = str4.clone() + "/" + &str4 + "/" + &str4 + "/" + &str4;
-
-    let restr2 = pat2 + " => " + &pat3;
-    let restr3 = pat3 + " => " + &pat4;
-
-    let re2: Regex = Regex::new(&restr2).unwrap();
-    let re3: Regex = Regex::new(&restr3).unwrap();
-
+fn convert_input(input: &str) -> Result<BTreeMap<Pattern, Pattern>, ParseError> {
     let mut result = BTreeMap::new();
 
-    macro_rules! insert {
-        ( $cap:expr, $i:expr, $j:expr ) => {
-            {
-                $cap.iter()
-                    .skip($i)
-                    .take($j)
-                    .map(|c| c.unwrap().as_str())
-                    .map(|s| match s {
-                        "." => Pixel::Off,
-                        "#" => Pixel::On,
-                        _ => unreachable!(),
-                    })
-                    .collect::<Vec<Pixel>>()
-            }
+    for line in Span::new(input).lines() {
+        let (inp, outp) = line.trim().split_once(" => ")?;
+        let size = match inp.as_str().split('/').count() {
+            size @ 2..=3 => size,
+            _ => return Err(inp.error("a pattern of 2x2 or 3x3 pixels")),
         };
-    }
-
-    for cap in re2.captures_iter(input) {
-        let inpxl = insert!(cap, 1, 4);
-        let outpxl = insert!(cap, 5, 9);
-        let inptn = Pattern::new(inpxl);
-        let outptn = Pattern::new(outpxl);
-        for p in inptn.iter_permutations() {
-            result.insert(p, outptn.clone());
-        }
-    }
-    for cap in re3.captures_iter(input) {
-        let inpxl = insert!(cap, 1, 9);
-        let outpxl = insert!(cap, 10, 16);
-        let inptn = Pattern::new(inpxl);
-        let outptn = Pattern::new(outpxl);
+        let inptn = parse_pattern(inp, size)?;
+        let outptn = parse_pattern(outp, size + 1)?;
         for p in inptn.iter_permutations() {
             result.insert(p, outptn.clone());
         }
     }
 
-    result
+    Ok(result)
 }
 
-fn count_ones(input: &str, iterations: usize) -> usize {
-    let rules = convert_input(input);
-    let mut image = {
-        let mut init_pixels = vec![Pixel::Off; 9];
-        [1, 5, 6, 7, 8]
-            .iter()
-            .map(|&i| init_pixels[i] = Pixel::On)
-            .count();
-        Image::new(init_pixels, 3)
-    };
+fn initial_image() -> Image {
+    let mut init_pixels = vec![Pixel::Off; 9];
+    [1, 5, 6, 7, 8]
+        .iter()
+        .map(|&i| init_pixels[i] = Pixel::On)
+        .count();
+    Image::new(init_pixels, 3)
+}
+
+fn count_ones(input: &str, iterations: usize) -> Result<usize, ParseError> {
+    let rules = convert_input(input)?;
+    let mut image = initial_image();
 
     for _ in 0..iterations {
         image.increase_size(&rules);
     }
 
-    image
+    Ok(image
         .pixels
         .iter()
         .filter(|&p| match *p {
             Pixel::On => true,
             Pixel::Off => false,
         })
-        .count()
+        .count())
 }
 
-fn compute_solution_part_one(input: &str) -> usize {
+// Every frame is scaled up to the size of the final image, so that they can be animated
+fn export_image(input: &str, iterations: usize, export: &mut PictureExport) -> io::Result<bool> {
+    let rules =
+        convert_input(input).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    let mut image = initial_image();
+    let mut final_size = image.size;
+    for _ in 0..iterations {
+        final_size = Image::next_size(final_size);
+    }
+
+    let picture = |image: &Image| {
+        Picture::bitmap(final_size, final_size, |i, j| {
+            let i = i * image.size / final_size;
+            let j = j * image.size / final_size;
+            image.pixels[i * image.size + j] == Pixel::On
+        })
+    };
+
+    export.frame(|| picture(&image))?;
+    for _ in 0..iterations {
+        image.increase_size(&rules);
+        export.frame(|| picture(&image))?;
+    }
+    export.finish(&picture(&image))?;
+    Ok(true)
+}
+
+fn compute_solution_part_one(input: &str) -> Result<usize, ParseError> {
     count_ones(input, 5)
 }
 
-fn compute_solution_part_two(input: &str) -> usize {
+fn compute_solution_part_two(input: &str) -> Result<usize, ParseError> {
     count_ones(input, 18)
 }
 
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2017
+    }
+
+    fn day(&self) -> u8 {
+        21
+    }
+
+    fn parse(&self, input: &Input) -> Result<bool, ParseError> {
+        std::hint::black_box(convert_input(input.trimmed())?);
+        Ok(true)
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
+    }
+
+    fn export_image(&self, input: &Input, export: &mut PictureExport) -> io::Result<bool> {
+        export_image(input.trimmed(), 18, export)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
@@ -325,20 +358,7 @@ mod tests {
 
         let input = "../.# => ##./#../...
                      .#./..#/### => #..#/..../..../#..#";
-        let solution = count_ones(input, 2);
+        let solution = count_ones(input, 2).unwrap();
         assert_eq!(solution, 12);
     }
 }
-
-fn main() {
-    use std::env;
-    let args: Vec<String> = env::args().collect();
-
-    if args.len() != 2 {
-        println!("Usage: d21 <input filename>");
-    } else {
-        let input = read_file(&args[1]);
-        println!("solution 1 = {}", compute_solution_part_one(&input));
-        println!("solution 2 = {}", compute_solution_part_two(&input));
-    }
-}
@@ -1,3 +1,9 @@
+extern crate aoc_common;
+
+use aoc_common::{Answer, Input, ParseError, Picture, PictureExport, Puzzle, Span};
+use std::io;
+
+#[derive(Clone)]
 enum Direction {
     Up,
     Down,
@@ -83,10 +89,10 @@ struct Coordinate {
 impl Coordinate {
     fn new(i: usize, height: usize, j: usize, width: usize) -> Coordinate {
         Coordinate {
-            i: i,
-            height: height,
-            j: j,
-            width: width,
+            i,
+            height,
+            j,
+            width,
         }
     }
 
@@ -112,6 +118,7 @@ impl Coordinate {
     }
 }
 
+#[derive(Clone)]
 struct VirusCarrier {
     pos: Coordinate,
     dir: Direction,
@@ -125,9 +132,9 @@ impl VirusCarrier {
         let j = width / 2;
         let pos = Coordinate::new(i, height, j, width);
         VirusCarrier {
-            pos: pos,
+            pos,
             dir: Direction::Up,
-            fields: fields,
+            fields,
             caused_infections: 0,
         }
     }
@@ -205,67 +212,132 @@ impl VirusCarrier {
         self.pos = large_pos;
         self.fields = new_fields;
     }
-}
-
-fn read_file(filename: &str) -> String {
-    use std::fs::File;
-    use std::io::prelude::*;
 
-    let mut f = File::open(filename).expect("file not found");
-    let mut contents = String::new();
-    f.read_to_string(&mut contents).expect(
-        "something went wrong reading the file",
-    );
-    contents.trim().to_owned()
+    // The grid only ever grows by one field on both sides, so a smaller grid is drawn centered
+    fn picture(&self, height: usize, width: usize) -> Picture {
+        let di = (height - self.pos.height) / 2;
+        let dj = (width - self.pos.width) / 2;
+        Picture::color(width, height, |i, j| {
+            if i < di || i >= di + self.pos.height || j < dj || j >= dj + self.pos.width {
+                return [0, 0, 0];
+            }
+            let pos = Coordinate::new(i - di, self.pos.height, j - dj, self.pos.width);
+            if pos.i == self.pos.i && pos.j == self.pos.j {
+                return [255, 255, 255];
+            }
+            match self.fields[pos.as_ind()] {
+                Field::Clean => [0, 0, 0],
+                Field::Weakened => [230, 200, 40],
+                Field::Infected => [200, 30, 30],
+                Field::Flagged => [40, 90, 220],
+            }
+        })
+    }
 }
 
-fn convert_input(input: &str) -> VirusCarrier {
+fn convert_input(input: &str) -> Result<VirusCarrier, ParseError> {
     let mut fields = Vec::new();
-    let mut i_max = 0;
-    let mut j_max = 0;
-    for (i, line) in input.split('\n').enumerate() {
+    let mut height = 0;
+    let mut width = None;
+    for line in Span::new(input).lines() {
         let line = line.trim();
-        if i > i_max {
-            i_max = i;
-        }
-        for (j, c) in line.chars().enumerate() {
-            if j > j_max {
-                j_max = j;
-            }
+        height += 1;
+        for (span, c) in line.chars() {
             let newfield = match c {
                 '.' => Field::Clean,
                 '#' => Field::Infected,
-                _ => unreachable!(),
+                _ => return Err(span.error("`.` or `#`")),
             };
             fields.push(newfield);
         }
+        let expected = *width.get_or_insert(line.as_str().len());
+        if line.as_str().len() != expected || expected == 0 {
+            return Err(line.error(format!("a row of {} nodes", expected.max(1))));
+        }
+    }
+    match width {
+        Some(width) => Ok(VirusCarrier::new_from_fields(height, width, fields)),
+        None => Err(Span::new(input).error("a grid of nodes")),
     }
-    let width = j_max + 1;
-    let height = i_max + 1;
-    assert_eq!(fields.len(), height * width);
-    VirusCarrier::new_from_fields(height, width, fields)
 }
 
-fn compute_solution_part_one(input: &str) -> usize {
+fn compute_solution_part_one(input: &str) -> Result<usize, ParseError> {
     let mode = Mode::Basic;
-    let mut virus_carrier = convert_input(input);
+    let mut virus_carrier = convert_input(input)?;
 
     for _ in 0..10_000 {
         virus_carrier.step(&mode);
     }
 
-    virus_carrier.caused_infections
+    Ok(virus_carrier.caused_infections)
 }
 
-fn compute_solution_part_two(input: &str) -> usize {
+fn compute_solution_part_two(input: &str) -> Result<usize, ParseError> {
     let mode = Mode::Advanced;
-    let mut virus_carrier = convert_input(input);
+    let mut virus_carrier = convert_input(input)?;
 
     for _ in 0..10_000_000 {
         virus_carrier.step(&mode);
     }
 
-    virus_carrier.caused_infections
+    Ok(virus_carrier.caused_infections)
+}
+
+// Draws the grid after the bursts of part two, with a frame every 100 000 bursts
+fn export_image(input: &str, export: &mut PictureExport) -> io::Result<bool> {
+    let mode = Mode::Advanced;
+    let bursts = 10_000_000;
+    let frame_every = 100_000;
+
+    let mut virus_carrier =
+        convert_input(input).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    let initial = virus_carrier.clone();
+    for _ in 0..bursts {
+        virus_carrier.step(&mode);
+    }
+    let height = virus_carrier.pos.height;
+    let width = virus_carrier.pos.width;
+
+    if export.wants_frames() {
+        let mut virus_carrier = initial;
+        for burst in 0..bursts {
+            if burst % frame_every == 0 {
+                export.frame(|| virus_carrier.picture(height, width))?;
+            }
+            virus_carrier.step(&mode);
+        }
+    }
+    export.finish(&virus_carrier.picture(height, width))?;
+    Ok(true)
+}
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2017
+    }
+
+    fn day(&self) -> u8 {
+        22
+    }
+
+    fn parse(&self, input: &Input) -> Result<bool, ParseError> {
+        std::hint::black_box(convert_input(input.trimmed())?);
+        Ok(true)
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
+    }
+
+    fn export_image(&self, input: &Input, export: &mut PictureExport) -> io::Result<bool> {
+        export_image(input.trimmed(), export)
+    }
 }
 
 #[cfg(test)]
@@ -278,23 +350,10 @@ mod tests {
         let input = "..#
                      #..
                      ...";
-        let solution = compute_solution_part_one(input);
+        let solution = compute_solution_part_one(input).unwrap();
         assert_eq!(solution, 5_587);
 
-        let solution = compute_solution_part_two(input);
+        let solution = compute_solution_part_two(input).unwrap();
         assert_eq!(solution, 2_511_944);
     }
 }
-
-fn main() {
-    use std::env;
-    let args: Vec<String> = env::args().collect();
-
-    if args.len() != 2 {
-        println!("Usage: d22 <input filename>");
-    } else {
-        let input = read_file(&args[1]);
-        println!("solution 1 = {}", compute_solution_part_one(&input));
-        println!("solution 2 = {}", compute_solution_part_two(&input));
-    }
-}
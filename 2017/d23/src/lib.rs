@@ -1,9 +1,16 @@
-#[macro_use]
-extern crate lazy_static;
-extern crate regex;
+extern crate aoc_common;
 
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span, Tokens};
 use std::collections::BTreeMap;
 
+fn parse_register(tokens: &mut Tokens) -> Result<String, ParseError> {
+    let s = tokens.expect("a register")?;
+    if s.is_empty() || !s.as_str().chars().all(|c| c.is_ascii_lowercase()) {
+        return Err(s.error("a register"));
+    }
+    Ok(s.as_str().to_owned())
+}
+
 #[derive(Clone, Debug)]
 enum InstructionValue {
     Number(i64),
@@ -11,11 +18,14 @@ enum InstructionValue {
 }
 
 impl InstructionValue {
-    fn parse(s: &str) -> InstructionValue {
-        let v = s.parse::<i64>();
-        match v {
-            Ok(x) => InstructionValue::Number(x),
-            _ => InstructionValue::Name(s.to_owned()),
+    fn parse(tokens: &mut Tokens) -> Result<InstructionValue, ParseError> {
+        let s = tokens.expect("a number or a register")?;
+        match s.parse::<i64>("a number") {
+            Ok(x) => Ok(InstructionValue::Number(x)),
+            _ if s.as_str().chars().all(|c| c.is_ascii_lowercase()) => {
+                Ok(InstructionValue::Name(s.as_str().to_owned()))
+            }
+            _ => Err(s.error("a number or a register")),
         }
     }
 }
@@ -101,7 +111,7 @@ impl Machine {
         if vx != 0 {
             let vy = self.parse_instruction_value(y);
             let neg = vy < 0;
-            let vy = vy.abs() as usize;
+            let vy = vy.unsigned_abs() as usize;
             let next_inst = self.next_inst.unwrap();
             self.next_inst = if neg && vy < next_inst {
                 Some(next_inst - vy)
@@ -118,10 +128,7 @@ impl Machine {
 
     fn run_instruction(&mut self) {
         let next_inst = self.next_inst.unwrap();
-        let increase = match self.instructions[next_inst] {
-            Instruction::Jnz((_, _)) => false,
-            _ => true,
-        };
+        let increase = !matches!(self.instructions[next_inst], Instruction::Jnz((_, _)));
 
         match self.instructions[next_inst].clone() {
             Instruction::Set((x, y)) => self.set(x, &y),
@@ -136,60 +143,32 @@ impl Machine {
     }
 }
 
-fn read_file(filename: &str) -> String {
-    use std::fs::File;
-    use std::io::prelude::*;
-
-    let mut f = File::open(filename).expect("file not found");
-    let mut contents = String::new();
-    f.read_to_string(&mut contents).expect(
-        "something went wrong reading the file",
-    );
-    contents.trim().to_owned()
-}
-
-fn convert_input(input: &str) -> Vec<Instruction> {
-    use regex::Regex;
-
-    lazy_static! {
-        static ref RE_SET: Regex = Regex::new("set ([a-z]+) (-?[0-9]+|[a-z]+)").unwrap();
-        static ref RE_SUB: Regex = Regex::new("sub ([a-z]+) (-?[0-9]+|[a-z]+)").unwrap();
-        static ref RE_MUL: Regex = Regex::new("mul ([a-z]+) (-?[0-9]+|[a-z]+)").unwrap();
-        static ref RE_JNZ: Regex = Regex::new("jnz (-?[0-9]+|[a-z]+) (-?[0-9]+|[a-z]+)").unwrap();
-    }
-
+fn convert_input(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    let reg = parse_register;
+    let val = InstructionValue::parse;
     let mut result = Vec::new();
 
-    for s in input.split('\n') {
-        let s = s.trim();
-        if let Some(cap) = RE_SET.captures(s) {
-            let x = cap[1].to_owned();
-            let y = InstructionValue::parse(&cap[2]);
-            result.push(Instruction::Set((x, y)));
-        } else if let Some(cap) = RE_SUB.captures(s) {
-            let x = cap[1].to_owned();
-            let y = InstructionValue::parse(&cap[2]);
-            result.push(Instruction::Sub((x, y)));
-        } else if let Some(cap) = RE_MUL.captures(s) {
-            let x = cap[1].to_owned();
-            let y = InstructionValue::parse(&cap[2]);
-            result.push(Instruction::Mul((x, y)));
-        } else if let Some(cap) = RE_JNZ.captures(s) {
-            let x = InstructionValue::parse(&cap[1]);
-            let y = InstructionValue::parse(&cap[2]);
-            result.push(Instruction::Jnz((x, y)));
-        } else {
-            unreachable!();
-        }
+    for s in Span::new(input).lines() {
+        let mut tokens = s.split_whitespace();
+        let name = tokens.expect("an instruction")?;
+        let instruction = match name.as_str() {
+            "set" => Instruction::Set((reg(&mut tokens)?, val(&mut tokens)?)),
+            "sub" => Instruction::Sub((reg(&mut tokens)?, val(&mut tokens)?)),
+            "mul" => Instruction::Mul((reg(&mut tokens)?, val(&mut tokens)?)),
+            "jnz" => Instruction::Jnz((val(&mut tokens)?, val(&mut tokens)?)),
+            _ => return Err(name.error("one of `set`, `sub`, `mul` or `jnz`")),
+        };
+        tokens.end()?;
+        result.push(instruction);
     }
 
-    result
+    Ok(result)
 }
 
 fn is_prime(number: u64) -> bool {
     let mut d = 2;
     while d * d <= number {
-        if number % d == 0 {
+        if number.is_multiple_of(d) {
             return false;
         }
         d += 1;
@@ -197,13 +176,13 @@ fn is_prime(number: u64) -> bool {
     true
 }
 
-fn compute_solution_part_one(input: &str) -> usize {
-    let instructions = convert_input(input);
+fn compute_solution_part_one(input: &str) -> Result<usize, ParseError> {
+    let instructions = convert_input(input)?;
     let mut machine = Machine::new(instructions, 0);
     while !machine.finished {
         machine.run_instruction();
     }
-    machine.muls_called
+    Ok(machine.muls_called)
 }
 
 fn compute_solution_part_two() -> u64 {
@@ -219,15 +198,27 @@ fn compute_solution_part_two() -> u64 {
     h
 }
 
-fn main() {
-    use std::env;
-    let args: Vec<String> = env::args().collect();
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2017
+    }
+
+    fn day(&self) -> u8 {
+        23
+    }
+
+    fn parse(&self, input: &Input) -> Result<bool, ParseError> {
+        std::hint::black_box(convert_input(input.trimmed())?);
+        Ok(true)
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
 
-    if args.len() != 2 {
-        println!("Usage: d23 <input filename>");
-    } else {
-        let input = read_file(&args[1]);
-        println!("solution 1 = {}", compute_solution_part_one(&input));
-        println!("solution 2 = {}", compute_solution_part_two());
+    fn part_two(&self, _input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(Ok(compute_solution_part_two().into()))
     }
 }
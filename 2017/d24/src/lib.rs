@@ -1,4 +1,6 @@
-extern crate regex;
+extern crate aoc_common;
+
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
 
 enum SearchStatus {
     Complete,
@@ -129,53 +131,63 @@ impl Walker {
     }
 }
 
-fn read_file(filename: &str) -> String {
-    use std::fs::File;
-    use std::io::prelude::*;
-
-    let mut f = File::open(filename).expect("file not found");
-    let mut contents = String::new();
-    f.read_to_string(&mut contents)
-        .expect("something went wrong reading the file");
-    contents.trim().to_owned()
-}
-
-fn convert_input(input: &str) -> Vec<Component> {
-    use regex::Regex;
-
-    let re: Regex = Regex::new(r"(\d+)/(\d+)").unwrap();
+fn convert_input(input: &str) -> Result<Vec<Component>, ParseError> {
     let mut result = Vec::new();
 
-    for cap in re.captures_iter(input) {
-        let a: usize = cap[1].parse().unwrap();
-        let b: usize = cap[2].parse().unwrap();
-        let component = Component::new(a, b);
+    for line in Span::new(input).lines() {
+        let (a, b) = line.trim().split_once("/")?;
+        let component = Component::new(a.parse("a number of pins")?, b.parse("a number of pins")?);
         result.push(component);
     }
 
-    result
+    Ok(result)
 }
 
-fn compute_solution_part_one(input: &str) -> usize {
-    let components = convert_input(input);
+fn compute_solution_part_one(input: &str) -> Result<usize, ParseError> {
+    let components = convert_input(input)?;
     let mut walker = Walker::new(components);
     loop {
         if let SearchStatus::Complete = walker.step() {
             break;
         }
     }
-    walker.max
+    Ok(walker.max)
 }
 
-fn compute_solution_part_two(input: &str) -> usize {
-    let components = convert_input(input);
+fn compute_solution_part_two(input: &str) -> Result<usize, ParseError> {
+    let components = convert_input(input)?;
     let mut walker = Walker::new(components);
     loop {
         if let SearchStatus::Complete = walker.step() {
             break;
         }
     }
-    walker.max_at_maxlevel
+    Ok(walker.max_at_maxlevel)
+}
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2017
+    }
+
+    fn day(&self) -> u8 {
+        24
+    }
+
+    fn parse(&self, input: &Input) -> Result<bool, ParseError> {
+        std::hint::black_box(convert_input(input.trimmed())?);
+        Ok(true)
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
+    }
 }
 
 #[cfg(test)]
@@ -193,23 +205,10 @@ mod tests {
                      0/1
                      10/1
                      9/10";
-        let solution = compute_solution_part_one(input);
+        let solution = compute_solution_part_one(input).unwrap();
         assert_eq!(solution, 31);
 
-        let solution = compute_solution_part_two(input);
+        let solution = compute_solution_part_two(input).unwrap();
         assert_eq!(solution, 19);
     }
 }
-
-fn main() {
-    use std::env;
-    let args: Vec<String> = env::args().collect();
-
-    if args.len() != 2 {
-        println!("Usage: d24 <input filename>");
-    } else {
-        let input = read_file(&args[1]);
-        println!("solution 1 = {}", compute_solution_part_one(&input));
-        println!("solution 2 = {}", compute_solution_part_two(&input));
-    }
-}
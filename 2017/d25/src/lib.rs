@@ -0,0 +1,258 @@
+extern crate aoc_common;
+
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
+use std::collections::{BTreeMap, VecDeque};
+
+#[derive(Debug, Clone, Copy)]
+enum Direction {
+    Left,
+    Right,
+}
+
+struct Instruction {
+    write_value: bool,
+    dir: Direction,
+    next_state: u8,
+}
+
+impl Instruction {
+    fn new(write_value: bool, dir: Direction, next_state: u8) -> Self {
+        Instruction {
+            write_value,
+            dir,
+            next_state,
+        }
+    }
+}
+
+struct TuringMachine {
+    state: u8,
+    position: usize,
+    tape: VecDeque<bool>,
+    instructions: BTreeMap<(u8, bool), Instruction>,
+}
+
+impl TuringMachine {
+    fn new(state: u8, instructions: BTreeMap<(u8, bool), Instruction>) -> Self {
+        let mut tape = VecDeque::new();
+        tape.push_back(false);
+        let position = 0;
+        TuringMachine {
+            state,
+            position,
+            tape,
+            instructions,
+        }
+    }
+
+    fn step(&mut self) {
+        let instructions = std::mem::take(&mut self.instructions);
+        {
+            let instruction = &instructions[&(self.state, self.tape[self.position])];
+            self.parse_instruction(instruction);
+            self.trim();
+        }
+        self.instructions = instructions;
+    }
+
+    fn parse_instruction(&mut self, instruction: &Instruction) {
+        self.tape[self.position] = instruction.write_value;
+        self.state = instruction.next_state;
+        match instruction.dir {
+            Direction::Left => {
+                if self.position == 0 {
+                    self.tape.push_front(false);
+                } else {
+                    self.position -= 1;
+                }
+            }
+            Direction::Right => {
+                if self.position == self.tape.len() - 1 {
+                    self.tape.push_back(false);
+                }
+                self.position += 1;
+            }
+        }
+    }
+
+    fn trim(&mut self) {
+        while self.tape.len() > 1 {
+            if !self.tape[0] && self.position != 0 {
+                self.tape.pop_front();
+                self.position -= 1;
+            } else if !self.tape[self.tape.len() - 1] && self.position != self.tape.len() - 1 {
+                self.tape.pop_back();
+            } else {
+                break;
+            }
+        }
+        if self.tape.capacity() > self.tape.len() * 2 {
+            self.tape.shrink_to_fit();
+        }
+    }
+
+    fn count_ones(&self) -> usize {
+        self.tape.iter().filter(|&&s| s).count()
+    }
+}
+
+// The next non-empty line, which has to be `prefix`, something, `suffix`. Returns the something.
+fn expect_line<'a, I: Iterator<Item = Span<'a>>>(
+    lines: &mut I,
+    end: Span<'a>,
+    prefix: &str,
+    suffix: &str,
+) -> Result<Span<'a>, ParseError> {
+    let line = lines
+        .next()
+        .ok_or_else(|| end.error(format!("`{}...{}`", prefix, suffix)))?;
+    line.strip_prefix(prefix)?.strip_suffix(suffix)
+}
+
+fn parse_value(s: Span) -> Result<bool, ParseError> {
+    match s.as_str() {
+        "0" => Ok(false),
+        "1" => Ok(true),
+        _ => Err(s.error("`0` or `1`")),
+    }
+}
+
+fn convert_input(input: &str) -> Result<(TuringMachine, usize), ParseError> {
+    let end = Span::new(input).end();
+    let mut lines = Span::new(input)
+        .lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty())
+        .peekable();
+
+    let mut states = BTreeMap::new();
+    let mut state_id = |name: Span| {
+        let id = states.len() as u8;
+        *states.entry(name.as_str().to_owned()).or_insert(id)
+    };
+    let mut defined = Vec::new();
+    let mut continued = Vec::new();
+
+    let begin_state = expect_line(&mut lines, end, "Begin in state ", ".")?;
+    let begin_state = state_id(begin_state);
+    let checksum_after = expect_line(
+        &mut lines,
+        end,
+        "Perform a diagnostic checksum after ",
+        " steps.",
+    )?
+    .parse("a number of steps")?;
+
+    let mut instructions = BTreeMap::new();
+
+    while lines.peek().is_some() {
+        let current_state = state_id(expect_line(&mut lines, end, "In state ", ":")?);
+        defined.push(current_state);
+        for _ in 0..2 {
+            let current_value = parse_value(expect_line(
+                &mut lines,
+                end,
+                "If the current value is ",
+                ":",
+            )?)?;
+            let next_value = parse_value(expect_line(&mut lines, end, "- Write the value ", ".")?)?;
+            let dir = expect_line(&mut lines, end, "- Move one slot to the ", ".")?;
+            let dir = match dir.as_str() {
+                "left" => Direction::Left,
+                "right" => Direction::Right,
+                _ => return Err(dir.error("`left` or `right`")),
+            };
+            let next_state = expect_line(&mut lines, end, "- Continue with state ", ".")?;
+            continued.push(next_state);
+            let instruction = Instruction::new(next_value, dir, state_id(next_state));
+            instructions.insert((current_state, current_value), instruction);
+        }
+        if !instructions.contains_key(&(current_state, false))
+            || !instructions.contains_key(&(current_state, true))
+        {
+            return Err(lines
+                .peek()
+                .unwrap_or(&end)
+                .error("the instructions for both values of the state"));
+        }
+    }
+
+    for next_state in continued {
+        if !defined.contains(&state_id(next_state)) {
+            return Err(next_state.error("a state that is described"));
+        }
+    }
+    if !defined.contains(&begin_state) {
+        return Err(Span::new(input).error("a begin state that is described"));
+    }
+
+    Ok((
+        TuringMachine::new(begin_state, instructions),
+        checksum_after,
+    ))
+}
+
+fn compute_solution_part_one(input: &str) -> Result<usize, ParseError> {
+    let (mut machine, check_after) = convert_input(input)?;
+    for _ in 0..check_after {
+        machine.step();
+    }
+    Ok(machine.count_ones())
+}
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2017
+    }
+
+    fn day(&self) -> u8 {
+        25
+    }
+
+    fn parse(&self, input: &Input) -> Result<bool, ParseError> {
+        std::hint::black_box(convert_input(input.trimmed())?);
+        Ok(true)
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
+
+    fn has_part_two(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn examples() {
+        use compute_solution_part_one;
+        let input = "Begin in state A.
+                     Perform a diagnostic checksum after 6 steps.
+
+                     In state A:
+                     If the current value is 0:
+                         - Write the value 1.
+                         - Move one slot to the right.
+                         - Continue with state B.
+                     If the current value is 1:
+                         - Write the value 0.
+                         - Move one slot to the left.
+                         - Continue with state B.
+
+                     In state B:
+                     If the current value is 0:
+                         - Write the value 1.
+                         - Move one slot to the left.
+                         - Continue with state A.
+                     If the current value is 1:
+                         - Write the value 1.
+                         - Move one slot to the right.
+                         - Continue with state A.";
+        let solution = compute_solution_part_one(input).unwrap();
+        assert_eq!(solution, 3);
+    }
+}
@@ -0,0 +1,51 @@
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
+
+fn naive_fuel(mass: u64) -> u64 {
+    let fuel = mass / 3;
+    fuel.saturating_sub(2)
+}
+
+fn recursive_fuel(mass: u64) -> u64 {
+    let mut fuel = naive_fuel(mass);
+    let mut unaccounted_fuel = fuel;
+    while unaccounted_fuel > 0 {
+        unaccounted_fuel = naive_fuel(unaccounted_fuel);
+        fuel += unaccounted_fuel;
+    }
+    fuel
+}
+
+fn parse_input(input: &str) -> Result<Vec<u64>, ParseError> {
+    Span::new(input)
+        .lines()
+        .map(|s| s.parse::<u64>("a mass"))
+        .collect()
+}
+
+fn compute_solution_part_one(input: &str) -> Result<u64, ParseError> {
+    Ok(parse_input(input)?.into_iter().map(naive_fuel).sum())
+}
+
+fn compute_solution_part_two(input: &str) -> Result<u64, ParseError> {
+    Ok(parse_input(input)?.into_iter().map(recursive_fuel).sum())
+}
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2019
+    }
+
+    fn day(&self) -> u8 {
+        1
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
+    }
+}
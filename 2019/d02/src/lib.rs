@@ -0,0 +1,154 @@
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
+
+fn parse_input(input: &str) -> Result<Vec<usize>, ParseError> {
+    Span::new(input)
+        .split(",")
+        .map(|s| s.parse("a non-negative integer"))
+        .collect()
+}
+
+// A program that cannot run: the position of the offending value and what was expected there
+type Fault = (usize, String);
+
+fn compute_output(noun: usize, verb: usize, data: &mut [usize]) -> Result<usize, Fault> {
+    if data.len() < 3 {
+        return Err((data.len(), "a noun and a verb".to_owned()));
+    }
+    data[1] = noun;
+    data[2] = verb;
+    let mut position = 0;
+    loop {
+        let opcode = *data
+            .get(position)
+            .ok_or_else(|| (position, "the opcode 99".to_owned()))?;
+        if opcode == 99 {
+            return Ok(data[0]);
+        }
+        if opcode != 1 && opcode != 2 {
+            return Err((position, "the opcode 1, 2 or 99".to_owned()));
+        }
+        let address = |offset| {
+            let i = position + offset;
+            match data.get(i) {
+                Some(&address) if address < data.len() => Ok(address),
+                // The noun and the verb replace values of the input
+                Some(&address) => Err((
+                    i,
+                    format!("an address below {} instead of {}", data.len(), address),
+                )),
+                None => Err((i, "an address".to_owned())),
+            }
+        };
+        let (i1, i2, ir) = (address(1)?, address(2)?, address(3)?);
+        let result = match opcode {
+            1 => data[i1].checked_add(data[i2]),
+            _ => data[i1].checked_mul(data[i2]),
+        };
+        data[ir] = result.ok_or_else(|| (position, "a result without overflow".to_owned()))?;
+        position += 4;
+    }
+}
+
+// Points a fault at the value of `input` it is about
+fn fault_error(input: &str, (position, expected): Fault) -> ParseError {
+    let source = Span::new(input);
+    source
+        .split(",")
+        .nth(position)
+        .unwrap_or_else(|| source.end())
+        .error(expected)
+}
+
+fn compute_solution_part_one(input: &str) -> Result<usize, ParseError> {
+    let mut data = parse_input(input)?;
+    compute_output(12, 2, &mut data).map_err(|fault| fault_error(input, fault))
+}
+
+fn compute_solution_part_two(input: &str) -> Result<usize, ParseError> {
+    let data = parse_input(input)?;
+    for noun in 0..100 {
+        for verb in 0..100 {
+            let mut d = data.clone();
+            let output =
+                compute_output(noun, verb, &mut d).map_err(|fault| fault_error(input, fault))?;
+            if output == 19690720 {
+                return Ok(100 * noun + verb);
+            }
+        }
+    }
+    Err(Span::new(input)
+        .end()
+        .error("a program that outputs 19690720 for some noun and verb"))
+}
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2019
+    }
+
+    fn day(&self) -> u8 {
+        2
+    }
+
+    fn parse(&self, input: &Input) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input.trimmed())?);
+        Ok(true)
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn runtime_errors() {
+        let error = |input| {
+            let mut data = parse_input(input).unwrap();
+            let fault = compute_output(0, 0, &mut data).unwrap_err();
+            fault_error(input, fault).to_string()
+        };
+        assert_eq!(
+            error("1,0,0,0,42"),
+            "line 1, column 9: expected the opcode 1, 2 or 99, found `42`"
+        );
+        assert_eq!(
+            error("1,0,0,7,99"),
+            "line 1, column 7: expected an address below 5 instead of 7, found `7`"
+        );
+        assert_eq!(
+            error("1,0,0,0,1,0"),
+            "line 1, column 12: expected an address, found nothing"
+        );
+        assert_eq!(
+            error("1,0,0,0"),
+            "line 1, column 8: expected the opcode 99, found nothing"
+        );
+        assert_eq!(
+            error("1,0"),
+            "line 1, column 4: expected a noun and a verb, found nothing"
+        );
+        assert_eq!(
+            compute_solution_part_one("1,0,0,0,99")
+                .unwrap_err()
+                .to_string(),
+            "line 1, column 3: expected an address below 5 instead of 12, found `0`"
+        );
+        // Adds noun and verb
+        let input = format!("1,0,0,0,99{}", ",0".repeat(95));
+        assert_eq!(
+            compute_solution_part_two(&input).unwrap_err().to_string(),
+            "line 1, column 201: expected a program that outputs 19690720 for some noun and \
+             verb, found nothing"
+        );
+    }
+}
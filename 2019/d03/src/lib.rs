@@ -0,0 +1,112 @@
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
+
+use std::collections::{HashMap, HashSet};
+
+#[derive(Debug)]
+enum Direction {
+    Right(u64),
+    Up(u64),
+    Left(u64),
+    Down(u64),
+}
+
+fn parse_line(line: Span) -> Result<Vec<Direction>, ParseError> {
+    line.split(",")
+        .map(|s| {
+            let (d, v) = match s.as_str().chars().next() {
+                Some(d) => (d, s.slice(1, s.as_str().len())),
+                None => return Err(s.error("a direction")),
+            };
+            let v = v.parse("a distance")?;
+            match d {
+                'R' => Ok(Direction::Right(v)),
+                'U' => Ok(Direction::Up(v)),
+                'L' => Ok(Direction::Left(v)),
+                'D' => Ok(Direction::Down(v)),
+                _ => Err(s.slice(0, d.len_utf8()).error("`R`, `U`, `L` or `D`")),
+            }
+        })
+        .collect()
+}
+
+fn parse_input(input: &str) -> Result<Vec<Vec<Direction>>, ParseError> {
+    let mut lines = Span::new(input).lines();
+    let mut wires = Vec::new();
+    for _ in 0..2 {
+        let line = lines.next().unwrap_or_else(|| Span::new(input).end());
+        wires.push(parse_line(line)?);
+    }
+    match lines.next() {
+        Some(line) => Err(line.error("only two wires")),
+        None => Ok(wires),
+    }
+}
+
+fn directions_to_points(directions: &[Direction]) -> HashMap<(i64, i64), u64> {
+    let mut x = 0;
+    let mut y = 0;
+    let mut steps = 0;
+    let mut points = HashMap::new();
+    for direction in directions {
+        let (delta_x, delta_y, v) = match direction {
+            Direction::Right(v) => (1, 0, v),
+            Direction::Left(v) => (-1, 0, v),
+            Direction::Up(v) => (0, 1, v),
+            Direction::Down(v) => (0, -1, v),
+        };
+        for _ in 0..*v {
+            x += delta_x;
+            y += delta_y;
+            steps += 1;
+            points.entry((x, y)).or_insert(steps);
+        }
+    }
+    points
+}
+
+fn compute_solution_part_one(input: &str) -> Result<u64, ParseError> {
+    let directions = parse_input(input)?;
+    let lines: Vec<_> = directions.iter().map(|d| directions_to_points(d)).collect();
+    let points: Vec<HashSet<_>> = lines.iter().map(|p| p.keys().collect()).collect();
+    Ok(points[0]
+        .intersection(&points[1])
+        .map(|(x, y)| (x.abs() + y.abs()) as u64)
+        .min()
+        .unwrap())
+}
+
+fn compute_solution_part_two(input: &str) -> Result<u64, ParseError> {
+    let directions = parse_input(input)?;
+    let lines: Vec<_> = directions.iter().map(|d| directions_to_points(d)).collect();
+    let points: Vec<HashSet<_>> = lines.iter().map(|p| p.keys().collect()).collect();
+    Ok(points[0]
+        .intersection(&points[1])
+        .map(|c| lines[0][c] + lines[1][c])
+        .min()
+        .unwrap())
+}
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2019
+    }
+
+    fn day(&self) -> u8 {
+        3
+    }
+
+    fn parse(&self, input: &Input) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input.trimmed())?);
+        Ok(true)
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
+    }
+}
@@ -0,0 +1,89 @@
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
+
+fn parse_input(input: &str) -> Result<(u32, u32), ParseError> {
+    let (lower, upper) = Span::new(input).split_once("-")?;
+    Ok((lower.parse("a number")?, upper.parse("a number")?))
+}
+
+fn password_to_vec(password: u32) -> Vec<u32> {
+    password
+        .to_string()
+        .chars()
+        .map(|c| c.to_digit(10).unwrap())
+        .collect()
+}
+
+fn is_increasing(password: &[u32]) -> bool {
+    for d in password.windows(2) {
+        if d[0] > d[1] {
+            return false;
+        }
+    }
+    true
+}
+
+fn has_double(password: &[u32]) -> bool {
+    for d in password.windows(2) {
+        if d[0] == d[1] {
+            return true;
+        }
+    }
+    false
+}
+
+fn has_isolated_double(password: &[u32]) -> bool {
+    let mut count = 0;
+    for d in password.windows(2) {
+        if d[0] == d[1] {
+            count += 1;
+        } else if count == 1 {
+            return true;
+        } else {
+            count = 0;
+        }
+    }
+    count == 1
+}
+
+fn compute_solution_part_one(input: &str) -> Result<usize, ParseError> {
+    let (lower, upper) = parse_input(input)?;
+    Ok((lower..=upper)
+        .map(password_to_vec)
+        .filter(|p| is_increasing(p))
+        .filter(|p| has_double(p))
+        .count())
+}
+
+fn compute_solution_part_two(input: &str) -> Result<usize, ParseError> {
+    let (lower, upper) = parse_input(input)?;
+    Ok((lower..=upper)
+        .map(password_to_vec)
+        .filter(|p| is_increasing(p))
+        .filter(|p| has_isolated_double(p))
+        .count())
+}
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2019
+    }
+
+    fn day(&self) -> u8 {
+        4
+    }
+
+    fn parse(&self, input: &Input) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input.trimmed())?);
+        Ok(true)
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
+    }
+}
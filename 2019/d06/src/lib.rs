@@ -1,16 +1,6 @@
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
 use std::collections::BTreeMap;
 
-fn read_file(filename: &str) -> String {
-    use std::fs::File;
-    use std::io::prelude::*;
-
-    let mut f = File::open(filename).expect("file not found");
-    let mut contents = String::new();
-    f.read_to_string(&mut contents)
-        .expect("something went wrong reading the file");
-    contents.trim().to_owned()
-}
-
 struct OrbitalBody {
     name: String,
     children: Vec<OrbitalBody>,
@@ -68,18 +58,19 @@ impl OrbitalBody {
     }
 }
 
-fn parse_input(input: &str) -> OrbitalBody {
+fn parse_input(input: &str) -> Result<OrbitalBody, ParseError> {
     let mut orbits = BTreeMap::new();
-    for line in input.lines() {
-        let b: Vec<_> = line.split(')').collect();
-        let children = orbits.entry(b[0].to_owned()).or_insert_with(Vec::new);
-        children.push(b[1].to_owned());
+    for line in Span::new(input).lines() {
+        let (center, body) = line.split_once(")")?;
+        let children = orbits
+            .entry(center.as_str().to_owned())
+            .or_insert_with(Vec::new);
+        children.push(body.as_str().to_owned());
     }
     let mut root = OrbitalBody::new("COM");
     let mut leaves = vec!["COM".to_owned()];
 
-    while !leaves.is_empty() {
-        let parent_name = leaves.pop().unwrap();
+    while let Some(parent_name) = leaves.pop() {
         let parent = root.get(&parent_name).unwrap();
         let child_names = orbits.entry(parent_name).or_insert_with(Vec::new);
         for child_name in child_names {
@@ -89,31 +80,43 @@ fn parse_input(input: &str) -> OrbitalBody {
         }
     }
 
-    root
+    Ok(root)
 }
 
-fn compute_solution_part_one(input: &str) -> u64 {
-    let root = parse_input(input);
-    root.count_orbits(0)
+fn compute_solution_part_one(input: &str) -> Result<u64, ParseError> {
+    let root = parse_input(input)?;
+    Ok(root.count_orbits(0))
 }
 
-fn compute_solution_part_two(input: &str) -> u64 {
-    let root = parse_input(input);
+fn compute_solution_part_two(input: &str) -> Result<u64, ParseError> {
+    let root = parse_input(input)?;
     let (a, b) = root.measure_distance("YOU", "SAN");
     let a = a.unwrap();
     let b = b.unwrap();
-    a + b - 2
+    Ok(a + b - 2)
 }
 
-fn main() {
-    use std::env;
-    let args: Vec<String> = env::args().collect();
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2019
+    }
+
+    fn day(&self) -> u8 {
+        6
+    }
+
+    fn parse(&self, input: &Input) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input.trimmed())?);
+        Ok(true)
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
 
-    if args.len() != 2 {
-        println!("Usage: d06 <input filename>");
-    } else {
-        let input = read_file(&args[1]);
-        println!("solution 1 = {}", compute_solution_part_one(&input));
-        println!("solution 2 = {}", compute_solution_part_two(&input));
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
     }
 }
@@ -0,0 +1,81 @@
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
+use int_code_machine::{IntCodeError, IntCodeMachine, Network, NetworkEvent, Topology};
+use itertools::Itertools;
+
+// One amplifier per phase setting, each already given its setting as the first input
+fn amplifiers(machine: &IntCodeMachine, phase_settings: &[i64]) -> Vec<IntCodeMachine> {
+    phase_settings
+        .iter()
+        .map(|&phase_setting| {
+            let mut amplifier = machine.clone();
+            amplifier.add_input_signal(phase_setting);
+            amplifier
+        })
+        .collect()
+}
+
+// Runs the amplifiers until they all halt and returns the last output of the last one, if it
+// output anything. Amplifiers that wait for input nobody sends are starved.
+fn run_amplifiers(
+    machine: &IntCodeMachine,
+    phase_settings: Vec<i64>,
+    topology: Topology,
+) -> Result<Option<i64>, IntCodeError> {
+    let mut network = Network::new(amplifiers(machine, &phase_settings), topology);
+    network.send(0, &[0]);
+    match network.run()? {
+        NetworkEvent::Halted => Ok(network.machines.last().and_then(|m| m.last_output_signal())),
+        _ => {
+            let waiting = network.machines.iter().find(|m| !m.halted);
+            Err(IntCodeError::InputStarvation {
+                ip: waiting.map_or(0, |m| m.instruction_pointer),
+            })
+        }
+    }
+}
+
+fn max_thrust(
+    input: &str,
+    phase_settings: std::ops::RangeInclusive<i64>,
+    topology: Topology,
+) -> Result<i64, ParseError> {
+    let machine = IntCodeMachine::from_string(input).map_err(|e| e.to_parse_error(input))?;
+    let thrusts = phase_settings
+        .permutations(5)
+        .map(|c| run_amplifiers(&machine, c, topology))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| e.to_parse_error(input))?;
+    thrusts.into_iter().flatten().max().ok_or_else(|| {
+        Span::new(input)
+            .end()
+            .error("amplifiers that output a thrust")
+    })
+}
+
+fn compute_solution_part_one(input: &str) -> Result<i64, ParseError> {
+    max_thrust(input, 0..=4, Topology::Chain)
+}
+
+fn compute_solution_part_two(input: &str) -> Result<i64, ParseError> {
+    max_thrust(input, 5..=9, Topology::Ring)
+}
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2019
+    }
+
+    fn day(&self) -> u8 {
+        7
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
+    }
+}
@@ -0,0 +1,129 @@
+use aoc_common::{Answer, Input, ParseError, Picture, PictureExport, Puzzle, Span};
+use aoc_grid::{ocr, Grid};
+use std::io;
+
+const N_COLUMNS: usize = 25;
+const N_ROWS: usize = 6;
+
+fn parse_input(input: &str) -> Result<Vec<u32>, ParseError> {
+    let input = Span::new(input);
+    let digits: Vec<u32> = input
+        .chars()
+        .map(|(span, c)| match c.to_digit(10) {
+            Some(d) if d <= 2 => Ok(d),
+            _ => Err(span.error("`0`, `1` or `2`")),
+        })
+        .collect::<Result<_, _>>()?;
+    if digits.is_empty() || !digits.len().is_multiple_of(N_COLUMNS * N_ROWS) {
+        let expected = format!("a multiple of {} digits", N_COLUMNS * N_ROWS);
+        return Err(input.end().error(expected));
+    }
+    Ok(digits)
+}
+
+fn compute_solution_part_one(input: &str) -> Result<usize, ParseError> {
+    let digits = parse_input(input)?;
+    let n_columns = N_COLUMNS;
+    let n_rows = N_ROWS;
+
+    let mut counts = 0;
+    let mut product = 0;
+
+    for (i, layer) in digits.chunks(n_columns * n_rows).enumerate() {
+        let counter = |n| layer.iter().filter(|c| **c == n).count();
+        let c = counter(0);
+        let p = counter(1) * counter(2);
+
+        if c < counts || i == 0 {
+            counts = c;
+            product = p;
+        }
+    }
+
+    Ok(product)
+}
+
+fn mix(upper: u32, lower: u32) -> u32 {
+    match (upper, lower) {
+        (0, _) => 0,
+        (1, _) => 1,
+        (2, d) => d,
+        _ => panic!("unknown colors"),
+    }
+}
+
+fn compute_solution_part_two(input: &str) -> Result<String, ParseError> {
+    let digits = parse_input(input)?;
+    let n_columns = N_COLUMNS;
+    let n_rows = N_ROWS;
+    let mut image = vec![2; n_columns * n_rows];
+
+    for layer in digits.chunks(n_columns * n_rows) {
+        for (i, l) in image.iter_mut().zip(layer.iter()) {
+            *i = mix(*i, *l);
+        }
+    }
+
+    let image = image
+        .into_iter()
+        .map(|i| match i {
+            0 => false,
+            1 => true,
+            _ => panic!("not every pixel has a color"),
+        })
+        .collect();
+    let image = Grid::new(n_columns, n_rows, image);
+    ocr::recognize_at(&image, Span::new(input).end())
+}
+
+// Draws the decoded image, with one frame per layer showing the layers stacked so far.
+// Transparent pixels are gray.
+fn export_image(input: &str, export: &mut PictureExport) -> io::Result<bool> {
+    let digits = parse_input(input).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    let n_columns = N_COLUMNS;
+    let n_rows = N_ROWS;
+    let mut image = vec![2; n_columns * n_rows];
+
+    let picture = |image: &[u32]| {
+        Picture::gray(n_columns, n_rows, |row, col| {
+            match image[row * n_columns + col] {
+                0 => 0,
+                1 => 255,
+                _ => 128,
+            }
+        })
+    };
+
+    for layer in digits.chunks(n_columns * n_rows) {
+        for (i, l) in image.iter_mut().zip(layer.iter()) {
+            *i = mix(*i, *l);
+        }
+        export.frame(|| picture(&image))?;
+    }
+    export.finish(&picture(&image))?;
+    Ok(true)
+}
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2019
+    }
+
+    fn day(&self) -> u8 {
+        8
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
+    }
+
+    fn export_image(&self, input: &Input, export: &mut PictureExport) -> io::Result<bool> {
+        export_image(input.trimmed(), export)
+    }
+}
@@ -0,0 +1,34 @@
+use aoc_common::{Answer, Input, ParseError, Puzzle};
+use int_code_machine::IntCodeMachine;
+
+fn compute_solution_part_one(input: &str) -> Result<i64, ParseError> {
+    let mut machine = IntCodeMachine::from_string(input).map_err(|e| e.to_parse_error(input))?;
+    machine.add_input_signal(1);
+    machine.final_output().map_err(|e| e.to_parse_error(input))
+}
+
+fn compute_solution_part_two(input: &str) -> Result<i64, ParseError> {
+    let mut machine = IntCodeMachine::from_string(input).map_err(|e| e.to_parse_error(input))?;
+    machine.add_input_signal(2);
+    machine.final_output().map_err(|e| e.to_parse_error(input))
+}
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2019
+    }
+
+    fn day(&self) -> u8 {
+        9
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
+    }
+}
@@ -1,17 +1,7 @@
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
 use std::cmp::Ordering;
 use std::collections::BTreeSet;
 
-fn read_file(filename: &str) -> String {
-    use std::fs::File;
-    use std::io::prelude::*;
-
-    let mut f = File::open(filename).expect("file not found");
-    let mut contents = String::new();
-    f.read_to_string(&mut contents)
-        .expect("something went wrong reading the file");
-    contents.trim().to_owned()
-}
-
 fn gcd(a: i64, b: i64) -> i64 {
     let mut a = a;
     let mut b = b;
@@ -130,17 +120,23 @@ fn compare_angles(a: &Point, b: &Point, anchor: &Point) -> Ordering {
     }
 }
 
-fn parse_input(input: &str) -> BTreeSet<Point> {
-    input
-        .lines()
-        .enumerate()
-        .flat_map(|(y, line)| {
-            line.chars()
-                .enumerate()
-                .filter(|(_, c)| *c == '#')
-                .map(move |(x, _)| Point::new(x as i64, y as i64))
-        })
-        .collect()
+fn parse_input(input: &str) -> Result<BTreeSet<Point>, ParseError> {
+    let mut asteroids = BTreeSet::new();
+    for (y, line) in Span::new(input).lines().enumerate() {
+        for (x, (span, c)) in line.chars().enumerate() {
+            match c {
+                '#' => {
+                    asteroids.insert(Point::new(x as i64, y as i64));
+                }
+                '.' => (),
+                _ => return Err(span.error("`#` or `.`")),
+            }
+        }
+    }
+    if asteroids.is_empty() {
+        return Err(Span::new(input).error("at least one asteroid"));
+    }
+    Ok(asteroids)
 }
 
 fn find_best_location(asteroids: &BTreeSet<Point>) -> (Point, usize) {
@@ -166,14 +162,14 @@ fn find_best_location(asteroids: &BTreeSet<Point>) -> (Point, usize) {
     (arg_max.unwrap(), max.unwrap())
 }
 
-fn compute_solution_part_one(input: &str) -> usize {
-    let asteroids = parse_input(input);
+fn compute_solution_part_one(input: &str) -> Result<usize, ParseError> {
+    let asteroids = parse_input(input)?;
     let (_, m) = find_best_location(&asteroids);
-    m
+    Ok(m)
 }
 
-fn compute_solution_part_two(input: &str) -> i64 {
-    let asteroids = parse_input(input);
+fn compute_solution_part_two(input: &str) -> Result<i64, ParseError> {
+    let asteroids = parse_input(input)?;
     let (anchor, _) = find_best_location(&asteroids);
     let mut others: Vec<_> = asteroids.iter().filter(|a| **a != anchor).collect();
     others.sort_by(|a, b| compare_angles(a, b, &anchor));
@@ -197,18 +193,30 @@ fn compute_solution_part_two(input: &str) -> i64 {
             last_direction = Point::new(0, 0);
         }
     }
-    last_destroyed.unwrap().x * 100 + last_destroyed.unwrap().y
+    Ok(last_destroyed.unwrap().x * 100 + last_destroyed.unwrap().y)
 }
 
-fn main() {
-    use std::env;
-    let args: Vec<String> = env::args().collect();
+pub struct Solution;
 
-    if args.len() != 2 {
-        println!("Usage: d10 <input filename>");
-    } else {
-        let input = read_file(&args[1]);
-        println!("solution 1 = {}", compute_solution_part_one(&input));
-        println!("solution 2 = {}", compute_solution_part_two(&input));
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2019
+    }
+
+    fn day(&self) -> u8 {
+        10
+    }
+
+    fn parse(&self, input: &Input) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input.trimmed())?);
+        Ok(true)
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
     }
 }
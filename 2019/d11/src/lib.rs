@@ -0,0 +1,149 @@
+use aoc_common::{Answer, Input, ParseError, Picture, PictureExport, Puzzle, Span};
+use aoc_grid::{ocr, Grid};
+use int_code_machine::IntCodeMachine;
+use std::collections::BTreeMap;
+use std::io;
+
+type Hull = BTreeMap<(i64, i64), i64>;
+
+#[derive(Clone, Copy)]
+enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    fn turn(&mut self, turn_code: i64) {
+        match (*self, turn_code) {
+            (Direction::Up, 0) => *self = Direction::Left,
+            (Direction::Up, 1) => *self = Direction::Right,
+            (Direction::Down, 0) => *self = Direction::Right,
+            (Direction::Down, 1) => *self = Direction::Left,
+            (Direction::Left, 0) => *self = Direction::Down,
+            (Direction::Left, 1) => *self = Direction::Up,
+            (Direction::Right, 0) => *self = Direction::Up,
+            (Direction::Right, 1) => *self = Direction::Down,
+            _ => unreachable!(),
+        }
+    }
+}
+
+// Calls `on_paint` with the hull after every panel that was painted
+fn paint_hull<F: FnMut(&Hull)>(
+    input: &str,
+    initial_color: i64,
+    mut on_paint: F,
+) -> Result<Hull, ParseError> {
+    let mut brain = IntCodeMachine::from_string(input).map_err(|e| e.to_parse_error(input))?;
+    let mut hull = BTreeMap::new();
+    let mut x = 0;
+    let mut y = 0;
+    hull.insert((x, y), initial_color);
+    let mut direction = Direction::Up;
+
+    loop {
+        let color = hull.entry((x, y)).or_insert(0);
+        brain.add_input_signal(*color);
+        brain
+            .run_until_output_or_halt()
+            .map_err(|e| e.to_parse_error(input))?;
+        let new_color = match brain.last_output_signal() {
+            Some(color) if !brain.halted => color,
+            _ => break,
+        };
+        hull.insert((x, y), new_color);
+        on_paint(&hull);
+        let turn_code = brain.next_output().map_err(|e| e.to_parse_error(input))?;
+        direction.turn(turn_code);
+        match direction {
+            Direction::Up => y += 1,
+            Direction::Down => y -= 1,
+            Direction::Left => x -= 1,
+            Direction::Right => x += 1,
+        }
+    }
+    Ok(hull)
+}
+
+fn compute_solution_part_one(input: &str) -> Result<usize, ParseError> {
+    let hull = paint_hull(input, 0, |_| ())?;
+    Ok(hull.len())
+}
+
+// Returns the top, bottom, left and right coordinate of the visited panels
+fn bounds(hull: &Hull) -> (i64, i64, i64, i64) {
+    let top = *hull.keys().map(|(_, y)| y).max().unwrap();
+    let bottom = *hull.keys().map(|(_, y)| y).min().unwrap();
+    let left = *hull.keys().map(|(x, _)| x).min().unwrap();
+    let right = *hull.keys().map(|(x, _)| x).max().unwrap();
+    (top, bottom, left, right)
+}
+
+// Every panel is true if it is painted white
+fn hull_image(hull: &Hull, (top, bottom, left, right): (i64, i64, i64, i64)) -> Grid<bool> {
+    let width = (right - left + 1) as usize;
+    let height = (top - bottom + 1) as usize;
+    Grid::from_fn(width, height, |row, col| {
+        let position = (left + col as i64, top - row as i64);
+        match hull.get(&position).unwrap_or(&0) {
+            0 => false,
+            1 => true,
+            _ => panic!("unknown color"),
+        }
+    })
+}
+
+fn compute_solution_part_two(input: &str) -> Result<String, ParseError> {
+    let hull = paint_hull(input, 1, |_| ())?;
+    let image = hull_image(&hull, bounds(&hull));
+    ocr::recognize_at(&image, Span::new(input).end())
+}
+
+// Draws the registration identifier of part two, with one frame per painted panel
+fn export_image(input: &str, export: &mut PictureExport) -> io::Result<bool> {
+    let picture = |image: Grid<bool>| {
+        Picture::bitmap(image.width(), image.height(), |row, col| !image[(row, col)])
+    };
+
+    let invalid_data = |e| io::Error::new(io::ErrorKind::InvalidData, e);
+    let hull = paint_hull(input, 1, |_| ()).map_err(invalid_data)?;
+    let bounds = bounds(&hull);
+    if export.wants_frames() {
+        let mut result = Ok(());
+        paint_hull(input, 1, |hull| {
+            if result.is_ok() {
+                result = export.frame(|| picture(hull_image(hull, bounds)));
+            }
+        })
+        .map_err(invalid_data)?;
+        result?;
+    }
+    export.finish(&picture(hull_image(&hull, bounds)))?;
+    Ok(true)
+}
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2019
+    }
+
+    fn day(&self) -> u8 {
+        11
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
+    }
+
+    fn export_image(&self, input: &Input, export: &mut PictureExport) -> io::Result<bool> {
+        export_image(input.trimmed(), export)
+    }
+}
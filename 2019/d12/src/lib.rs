@@ -1,19 +1,6 @@
-extern crate regex;
-
-use regex::Regex;
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
 use std::cmp::Ordering;
 
-fn read_file(filename: &str) -> String {
-    use std::fs::File;
-    use std::io::prelude::*;
-
-    let mut f = File::open(filename).expect("file not found");
-    let mut contents = String::new();
-    f.read_to_string(&mut contents)
-        .expect("something went wrong reading the file");
-    contents.trim().to_owned()
-}
-
 #[derive(PartialEq, Eq, PartialOrd, Ord, Clone)]
 struct PhaseSpace {
     x: [i64; 4],
@@ -65,21 +52,34 @@ impl PhaseSpace {
     }
 }
 
-fn parse_input(input: &str) -> [PhaseSpace; 3] {
-    let re = Regex::new(r"x=(-?\d*), y=(-?\d*), z=(-?\d*)").unwrap();
+fn parse_input(input: &str) -> Result<[PhaseSpace; 3], ParseError> {
     let mut x = Vec::new();
     let mut y = Vec::new();
     let mut z = Vec::new();
-    for c in re.captures_iter(input) {
-        x.push(c[1].parse().unwrap());
-        y.push(c[2].parse().unwrap());
-        z.push(c[3].parse().unwrap());
+    let mut lines = Span::new(input).lines();
+    for _ in 0..4 {
+        let line = lines
+            .next()
+            .ok_or_else(|| Span::new(input).end().error("the position of four moons"))?;
+        let mut coordinates = line
+            .trim()
+            .strip_prefix("<")?
+            .strip_suffix(">")?
+            .split(", ");
+        for (name, values) in [("x=", &mut x), ("y=", &mut y), ("z=", &mut z)] {
+            let coordinate = coordinates.expect(&format!("`{}`", name))?;
+            values.push(coordinate.strip_prefix(name)?.parse("a coordinate")?);
+        }
+        coordinates.end()?;
+    }
+    if let Some(line) = lines.next() {
+        return Err(line.error("only four moons"));
     }
-    [
+    Ok([
         PhaseSpace::from_vec(x),
         PhaseSpace::from_vec(y),
         PhaseSpace::from_vec(z),
-    ]
+    ])
 }
 
 fn gcd(a: u64, b: u64) -> u64 {
@@ -97,8 +97,8 @@ fn lcm(a: u64, b: u64) -> u64 {
     a * b / gcd(a, b)
 }
 
-fn compute_solution_part_one(input: &str) -> i64 {
-    let mut phase_spaces = parse_input(input);
+fn compute_solution_part_one(input: &str) -> Result<i64, ParseError> {
+    let mut phase_spaces = parse_input(input)?;
     for _ in 0..1000 {
         for phase_space in &mut phase_spaces {
             phase_space.update_v();
@@ -118,28 +118,40 @@ fn compute_solution_part_one(input: &str) -> i64 {
         total_energy += kinetic_energy * potential_energy;
     }
 
-    total_energy
+    Ok(total_energy)
 }
 
-fn compute_solution_part_two(input: &str) -> u64 {
-    let mut phase_spaces = parse_input(input);
+fn compute_solution_part_two(input: &str) -> Result<u64, ParseError> {
+    let mut phase_spaces = parse_input(input)?;
     let mut periods = [0; 3];
     for i in 0..3 {
         let period = phase_spaces[i].find_period();
         periods[i] = period;
     }
-    periods.iter().fold(1, |t, p| lcm(t, *p))
+    Ok(periods.iter().fold(1, |t, p| lcm(t, *p)))
 }
 
-fn main() {
-    use std::env;
-    let args: Vec<String> = env::args().collect();
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2019
+    }
+
+    fn day(&self) -> u8 {
+        12
+    }
+
+    fn parse(&self, input: &Input) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input.trimmed())?);
+        Ok(true)
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
 
-    if args.len() != 2 {
-        println!("Usage: d12 <input filename>");
-    } else {
-        let input = read_file(&args[1]);
-        println!("solution 1 = {}", compute_solution_part_one(&input));
-        println!("solution 2 = {}", compute_solution_part_two(&input));
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
     }
 }
@@ -0,0 +1,86 @@
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
+use int_code_machine::{input_fn, output_fn, IntCodeMachine};
+use std::cell::Cell;
+
+// Collects the outputs of the game into tiles of x, y and tile id
+#[derive(Default)]
+struct Screen {
+    pending: Vec<i64>,
+}
+
+impl Screen {
+    fn draw(&mut self, value: i64) -> Option<(i64, i64, i64)> {
+        self.pending.push(value);
+        if self.pending.len() < 3 {
+            return None;
+        }
+        let tile = (self.pending[0], self.pending[1], self.pending[2]);
+        self.pending.clear();
+        Some(tile)
+    }
+}
+
+fn compute_solution_part_one(input: &str) -> Result<u64, ParseError> {
+    let mut screen = Screen::default();
+    let mut blocks = 0;
+    let mut machine = IntCodeMachine::from_string(input)
+        .map_err(|e| e.to_parse_error(input))?
+        .with_output(output_fn(|value| {
+            if let Some((_, _, 2)) = screen.draw(value) {
+                blocks += 1;
+            }
+        }));
+    machine
+        .run_until_halt()
+        .map_err(|e| e.to_parse_error(input))?;
+    Ok(blocks)
+}
+
+fn compute_solution_part_two(input: &str) -> Result<i64, ParseError> {
+    let mut screen = Screen::default();
+    let ball_x: Cell<Option<i64>> = Cell::new(None);
+    let paddle_x: Cell<Option<i64>> = Cell::new(None);
+    let score = Cell::new(None);
+    let joystick = input_fn(|| match (paddle_x.get(), ball_x.get()) {
+        (Some(px), Some(bx)) => Some((bx - px).signum()),
+        _ => Some(0),
+    });
+    let display = output_fn(|value| match screen.draw(value) {
+        Some((-1, 0, value)) => score.set(Some(value)),
+        Some((x, _, 3)) => paddle_x.set(Some(x)),
+        Some((x, _, 4)) => ball_x.set(Some(x)),
+        _ => (),
+    });
+
+    let mut machine = IntCodeMachine::from_string(input)
+        .map_err(|e| e.to_parse_error(input))?
+        .with_input(joystick)
+        .with_output(display);
+    machine.poke(0, 2).map_err(|e| e.to_parse_error(input))?;
+    machine
+        .run_until_halt()
+        .map_err(|e| e.to_parse_error(input))?;
+    score
+        .get()
+        .ok_or_else(|| Span::new(input).end().error("a game that outputs a score"))
+}
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2019
+    }
+
+    fn day(&self) -> u8 {
+        13
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
+    }
+}
@@ -1,21 +1,12 @@
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
 use std::collections::{BTreeMap, VecDeque};
 
-fn read_file(filename: &str) -> String {
-    use std::fs::File;
-    use std::io::prelude::*;
-
-    let mut f = File::open(filename).expect("file not found");
-    let mut contents = String::new();
-    f.read_to_string(&mut contents)
-        .expect("something went wrong reading the file");
-    contents.trim().to_owned()
-}
-
-fn parse_info(info: &str) -> (i64, String) {
-    let mut tmp = info.trim().split(' ');
-    let nr = tmp.next().unwrap().parse().unwrap();
-    let compound = tmp.next().unwrap().to_string();
-    (nr, compound)
+fn parse_info(info: Span) -> Result<(i64, Span), ParseError> {
+    let mut tmp = info.split_whitespace();
+    let nr = tmp.expect("a quantity")?.parse("a quantity")?;
+    let compound = tmp.expect("a chemical")?;
+    tmp.end()?;
+    Ok((nr, compound))
 }
 
 struct ProductInfo {
@@ -34,7 +25,9 @@ impl ProductInfo {
     }
 
     fn factor(&self, needed_quantity: i64) -> i64 {
-        if needed_quantity % self.product_coefficient == 0 {
+        if needed_quantity <= 0 {
+            0
+        } else if needed_quantity % self.product_coefficient == 0 {
             needed_quantity / self.product_coefficient
         } else {
             needed_quantity / self.product_coefficient + 1
@@ -89,19 +82,32 @@ impl Iterator for ProductInfoIterator {
     }
 }
 
-fn parse_input(input: &str) -> BTreeMap<String, ProductInfo> {
+fn parse_input(input: &str) -> Result<BTreeMap<String, ProductInfo>, ParseError> {
     let mut result = BTreeMap::new();
-    for line in input.lines() {
-        let reactants = line.split("=>").nth(0).unwrap();
-        let product = line.split("=>").nth(1).unwrap();
-        let product = parse_info(product);
-        let reactants: Vec<_> = reactants.split(',').map(|s| parse_info(s)).collect();
+    let mut all_reactants = Vec::new();
+    for line in Span::new(input).lines() {
+        let (reactants, product) = line.split_once("=>")?;
+        let product = parse_info(product)?;
+        let reactants = reactants
+            .split(",")
+            .map(parse_info)
+            .collect::<Result<Vec<_>, _>>()?;
         let coefficients: Vec<_> = reactants.iter().map(|r| r.0).collect();
-        let chemicals: Vec<_> = reactants.iter().map(|r| r.1.clone()).collect();
+        let chemicals: Vec<_> = reactants.iter().map(|r| r.1.as_str().to_owned()).collect();
         let product_info = ProductInfo::new(product.0, coefficients, chemicals);
-        result.insert(product.1, product_info);
+        result.insert(product.1.as_str().to_owned(), product_info);
+        all_reactants.extend(reactants.into_iter().map(|r| r.1));
+    }
+
+    if !result.contains_key("FUEL") {
+        return Err(Span::new(input).end().error("a reaction producing FUEL"));
+    }
+    for chemical in all_reactants {
+        if chemical.as_str() != "ORE" && !result.contains_key(chemical.as_str()) {
+            return Err(chemical.error("ORE or a chemical that has a reaction"));
+        }
     }
-    result
+    Ok(result)
 }
 
 fn ore_for_fuel(fuel: i64, info_table: &BTreeMap<String, ProductInfo>) -> i64 {
@@ -127,13 +133,13 @@ fn ore_for_fuel(fuel: i64, info_table: &BTreeMap<String, ProductInfo>) -> i64 {
     chemicals["ORE"]
 }
 
-fn compute_solution_part_one(input: &str) -> i64 {
-    let info_table = parse_input(input);
-    ore_for_fuel(1, &info_table)
+fn compute_solution_part_one(input: &str) -> Result<i64, ParseError> {
+    let info_table = parse_input(input)?;
+    Ok(ore_for_fuel(1, &info_table))
 }
 
-fn compute_solution_part_two(input: &str) -> i64 {
-    let info_table = parse_input(input);
+fn compute_solution_part_two(input: &str) -> Result<i64, ParseError> {
+    let info_table = parse_input(input)?;
     let available_ore: i64 = 1_000_000_000_000;
     let mut lower_fuel = 1;
     let mut lower_ore = ore_for_fuel(lower_fuel, &info_table);
@@ -174,18 +180,30 @@ fn compute_solution_part_two(input: &str) -> i64 {
         }
     }
 
-    middle_fuel
+    Ok(middle_fuel)
 }
 
-fn main() {
-    use std::env;
-    let args: Vec<String> = env::args().collect();
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2019
+    }
+
+    fn day(&self) -> u8 {
+        14
+    }
+
+    fn parse(&self, input: &Input) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input.trimmed())?);
+        Ok(true)
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
 
-    if args.len() != 2 {
-        println!("Usage: d14 <input filename>");
-    } else {
-        let input = read_file(&args[1]);
-        println!("solution 1 = {}", compute_solution_part_one(&input));
-        println!("solution 2 = {}", compute_solution_part_two(&input));
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
     }
 }
@@ -1,17 +1,7 @@
-use int_code_machine::IntCodeMachine;
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
+use int_code_machine::{IntCodeError, IntCodeMachine};
 use std::collections::{BTreeMap, BTreeSet};
 
-fn read_file(filename: &str) -> String {
-    use std::fs::File;
-    use std::io::prelude::*;
-
-    let mut f = File::open(filename).expect("file not found");
-    let mut contents = String::new();
-    f.read_to_string(&mut contents)
-        .expect("something went wrong reading the file");
-    contents.trim().to_owned()
-}
-
 #[derive(Clone, Copy, PartialOrd, Ord, PartialEq, Eq)]
 enum Direction {
     North,
@@ -60,7 +50,7 @@ impl Point {
         Self { x, y }
     }
 
-    fn to_direction(&self, direction: Direction) -> Self {
+    fn to_direction(self, direction: Direction) -> Self {
         match direction {
             Direction::North => Self::new(self.x, self.y + 1),
             Direction::East => Self::new(self.x + 1, self.y),
@@ -192,21 +182,19 @@ struct Droid {
 }
 
 impl Droid {
-    fn from_string(input: &str) -> Self {
-        Self {
-            brain: IntCodeMachine::from_string(input),
+    fn from_string(input: &str) -> Result<Self, IntCodeError> {
+        Ok(Self {
+            brain: IntCodeMachine::from_string(input)?,
             board: Board::new(),
             position: Point::new(0, 0),
             oxygen: None,
-        }
+        })
     }
 
-    fn move_to_direction(&mut self, direction: Direction) -> bool {
-        self.brain.default_input = Some(direction.to_machine_command());
-        self.brain.run_until_output_or_halt();
-        self.brain.default_input = None;
-        let result = self.brain.last_output_signal().unwrap();
-        match result {
+    fn move_to_direction(&mut self, direction: Direction) -> Result<bool, IntCodeError> {
+        self.brain.add_input_signal(direction.to_machine_command());
+        let result = self.brain.next_output()?;
+        let moved = match result {
             1 => {
                 self.position = self.position.to_direction(direction);
                 true
@@ -218,37 +206,39 @@ impl Droid {
             }
             0 => false,
             _ => panic!("unknown tile code"),
-        }
+        };
+        Ok(moved)
     }
 
-    fn move_to_point(&mut self, p: Point) {
+    fn move_to_point(&mut self, p: Point) -> Result<(), IntCodeError> {
         if self.position == p {
-            return;
+            return Ok(());
         }
         let directions = best_path(self.position, p, &self.board);
         for direction in directions {
-            self.move_to_direction(direction);
+            self.move_to_direction(direction)?;
         }
+        Ok(())
     }
 
-    fn explore_board(&mut self) {
+    fn explore_board(&mut self) -> Result<(), IntCodeError> {
         let origin = Point::new(0, 0);
-        let mut todo = Vec::new();
-        todo.push((origin, Direction::North));
-        todo.push((origin, Direction::West));
-        todo.push((origin, Direction::South));
-        todo.push((origin, Direction::East));
+        let mut todo = vec![
+            (origin, Direction::North),
+            (origin, Direction::West),
+            (origin, Direction::South),
+            (origin, Direction::East),
+        ];
 
         let mut checked = BTreeSet::new();
 
-        while !todo.is_empty() {
-            let (p, dir) = todo.pop().unwrap();
+        while let Some((p, dir)) = todo.pop() {
             if checked.contains(&(p, dir)) {
                 continue;
             }
 
-            self.move_to_point(p);
-            let connected = self.move_to_direction(dir);
+            self.move_to_point(p)?;
+            let connected = self.move_to_direction(dir)?;
             let new_p = self.position;
             for &new_dir in [
                 Direction::North,
@@ -269,21 +259,30 @@ impl Droid {
                 self.board.add(p, new_p);
             }
         }
+        Ok(())
     }
 }
 
-fn compute_solution_part_one(input: &str) -> usize {
-    let mut droid = Droid::from_string(input);
-    droid.explore_board();
-    let oxygen = droid.oxygen.unwrap();
+// The droid after exploring the whole board, and the position of the oxygen system
+fn explored_droid(input: &str) -> Result<(Droid, Point), ParseError> {
+    let mut droid = Droid::from_string(input).map_err(|e| e.to_parse_error(input))?;
+    droid.explore_board().map_err(|e| e.to_parse_error(input))?;
+    let oxygen = droid.oxygen.ok_or_else(|| {
+        Span::new(input)
+            .end()
+            .error("a droid that finds the oxygen system")
+    })?;
+    Ok((droid, oxygen))
+}
+
+fn compute_solution_part_one(input: &str) -> Result<usize, ParseError> {
+    let (droid, oxygen) = explored_droid(input)?;
     let origin = Point::new(0, 0);
-    best_path(origin, oxygen, &droid.board).len()
+    Ok(best_path(origin, oxygen, &droid.board).len())
 }
 
-fn compute_solution_part_two(input: &str) -> usize {
-    let mut droid = Droid::from_string(input);
-    droid.explore_board();
-    let oxygen = droid.oxygen.unwrap();
+fn compute_solution_part_two(input: &str) -> Result<usize, ParseError> {
+    let (droid, oxygen) = explored_droid(input)?;
 
     let mut filled = BTreeSet::new();
     let mut todo = Vec::new();
@@ -293,8 +292,7 @@ fn compute_solution_part_two(input: &str) -> usize {
 
     loop {
         let mut new_todo = Vec::new();
-        while !todo.is_empty() {
-            let p = todo.pop().unwrap();
+        while let Some(p) = todo.pop() {
             filled.insert(p);
             for n in droid.board.neighbors(p) {
                 if !filled.contains(&n) {
@@ -309,18 +307,25 @@ fn compute_solution_part_two(input: &str) -> usize {
         minutes += 1;
     }
 
-    minutes
+    Ok(minutes)
 }
 
-fn main() {
-    use std::env;
-    let args: Vec<String> = env::args().collect();
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2019
+    }
+
+    fn day(&self) -> u8 {
+        15
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
 
-    if args.len() != 2 {
-        println!("Usage: d15 <input filename>");
-    } else {
-        let input = read_file(&args[1]);
-        println!("solution 1 = {}", compute_solution_part_one(&input));
-        println!("solution 2 = {}", compute_solution_part_two(&input));
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
     }
 }
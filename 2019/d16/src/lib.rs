@@ -1,26 +1,21 @@
-fn read_file(filename: &str) -> String {
-    use std::fs::File;
-    use std::io::prelude::*;
-
-    let mut f = File::open(filename).expect("file not found");
-    let mut contents = String::new();
-    f.read_to_string(&mut contents)
-        .expect("something went wrong reading the file");
-    contents.trim().to_owned()
-}
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
 
 fn pattern(base_pattern: &[i64], r: usize) -> impl Iterator<Item = i64> + '_ {
     base_pattern
         .iter()
-        .flat_map(move |&x| std::iter::repeat(x).take(r))
+        .flat_map(move |&x| std::iter::repeat_n(x, r))
         .cycle()
         .skip(1)
 }
 
-fn parse_input(input: &str) -> Vec<i64> {
-    input
+fn parse_input(input: &str) -> Result<Vec<i64>, ParseError> {
+    Span::new(input)
         .chars()
-        .map(|x| x.to_digit(10).unwrap() as i64)
+        .map(|(span, x)| {
+            x.to_digit(10)
+                .map(|d| d as i64)
+                .ok_or_else(|| span.error("a digit"))
+        })
         .collect()
 }
 
@@ -38,19 +33,19 @@ fn step(state: &[i64], base_pattern: &[i64]) -> Vec<i64> {
     result
 }
 
-fn compute_solution_part_one(input: &str) -> String {
+fn compute_solution_part_one(input: &str) -> Result<String, ParseError> {
     let base_pattern = vec![0, 1, 0, -1];
-    let mut state = parse_input(input);
+    let mut state = parse_input(input)?;
 
     for _ in 0..100 {
         state = step(&state, &base_pattern);
     }
 
-    state
+    Ok(state
         .iter()
-        .map(|i| i.to_string().chars().nth(0).unwrap())
+        .map(|i| i.to_string().chars().next().unwrap())
         .take(8)
-        .collect()
+        .collect())
 }
 
 fn lazy_step(state: &mut [i64]) {
@@ -64,8 +59,8 @@ fn lazy_step(state: &mut [i64]) {
     }
 }
 
-fn compute_solution_part_two(input: &str) -> String {
-    let mut state = parse_input(input);
+fn compute_solution_part_two(input: &str) -> Result<String, ParseError> {
+    let mut state = parse_input(input)?;
     state = state
         .iter()
         .cycle()
@@ -86,23 +81,35 @@ fn compute_solution_part_two(input: &str) -> String {
         lazy_step(&mut state);
     }
 
-    state
+    Ok(state
         .iter()
         .skip(index)
-        .map(|i| i.to_string().chars().nth(0).unwrap())
+        .map(|i| i.to_string().chars().next().unwrap())
         .take(8)
-        .collect()
+        .collect())
 }
 
-fn main() {
-    use std::env;
-    let args: Vec<String> = env::args().collect();
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2019
+    }
+
+    fn day(&self) -> u8 {
+        16
+    }
+
+    fn parse(&self, input: &Input) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input.trimmed())?);
+        Ok(true)
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
 
-    if args.len() != 2 {
-        println!("Usage: d16 <input filename>");
-    } else {
-        let input = read_file(&args[1]);
-        println!("solution 1 = {}", compute_solution_part_one(&input));
-        println!("solution 2 = {}", compute_solution_part_two(&input));
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
     }
 }
@@ -0,0 +1,71 @@
+//! Compares running Intcode programs with and without the decode cache.
+//!
+//! `cargo bench -p int_code_machine` times the recursive Fibonacci program in `fib.s`. To time
+//! another program, pass its file and inputs, e.g. the BOOST program of 2019 day 9 part two:
+//! `cargo bench -p int_code_machine -- input.txt 2`.
+use int_code_machine::{assemble, IntCodeMachine};
+use std::time::{Duration, Instant};
+
+const RUNS: usize = 5;
+
+/// The input of `fib.s`, which executes about 5 million instructions for it.
+const FIB_INPUT: i64 = 25;
+
+// The fastest of `RUNS` runs, and the output
+fn time(machine: &IntCodeMachine) -> (Duration, Vec<i64>) {
+    let mut best = Duration::MAX;
+    let mut output = Vec::new();
+    for _ in 0..RUNS {
+        let mut machine = machine.clone();
+        let start = Instant::now();
+        machine.run_until_halt().expect("the program failed");
+        best = best.min(start.elapsed());
+        output = machine.output;
+    }
+    (best, output)
+}
+
+fn main() {
+    // `cargo bench` passes `--bench`
+    let args: Vec<String> = std::env::args()
+        .skip(1)
+        .filter(|arg| !arg.starts_with("--"))
+        .collect();
+    let (name, mut machine, inputs) = match args.split_first() {
+        Some((path, inputs)) => {
+            let text = std::fs::read_to_string(path).expect("could not read the program");
+            let machine = IntCodeMachine::from_string(text.trim()).expect("invalid program");
+            let inputs = inputs
+                .iter()
+                .map(|input| input.parse().expect("invalid input"))
+                .collect();
+            (path.clone(), machine, inputs)
+        }
+        None => {
+            let program = assemble(include_str!("fib.s")).unwrap();
+            (
+                "fib.s".to_owned(),
+                IntCodeMachine::new(program),
+                vec![FIB_INPUT],
+            )
+        }
+    };
+    machine.input.extend(inputs);
+
+    let (uncached, output) = time(&machine.clone().with_decode_cache(false));
+    let (cached, cached_output) = time(&machine);
+    assert_eq!(output, cached_output, "the decode cache changed the output");
+    println!("{}: output {:?}", name, output);
+    println!(
+        "without decode cache: {:>10.3} ms",
+        uncached.as_secs_f64() * 1000.0
+    );
+    println!(
+        "with decode cache:    {:>10.3} ms",
+        cached.as_secs_f64() * 1000.0
+    );
+    println!(
+        "speedup: {:.2}x",
+        uncached.as_secs_f64() / cached.as_secs_f64()
+    );
+}
@@ -0,0 +1,30 @@
+; Computes the Fibonacci number of the input recursively, keeping a frame per call on the
+; stack at the relative base: the return address at @0, the argument at @1, the result at @2
+; and a local at @3. Callers build the callee's frame at their @4.
+        rbo #stack
+        in @1
+        add #done, #0, @0
+        jt #1, #fib
+done:   out @2
+        hlt
+
+fib:    lt @1, #2, @3
+        jf @3, #recurse
+        add @1, #0, @2
+        jt #1, @0
+recurse:
+        add @1, #-1, @5
+        add #after1, #0, @4
+        rbo #4
+        jt #1, #fib
+after1: rbo #-4
+        add @6, #0, @3
+        add @1, #-2, @5
+        add #after2, #0, @4
+        rbo #4
+        jt #1, #fib
+after2: rbo #-4
+        add @3, @6, @2
+        jt #1, @0
+
+stack:  data 0
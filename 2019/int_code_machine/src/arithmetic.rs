@@ -0,0 +1,203 @@
+use crate::cache::Decoded;
+use crate::instruction::{Mode, Opcode};
+use crate::{IntCodeError, IntCodeMachine, IntInput, IntOutput};
+use num_bigint::BigInt;
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+
+/// How instructions handle results that do not fit in 64 bits, see
+/// `IntCodeMachine::with_arithmetic`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Arithmetic {
+    /// Fails with `IntCodeError::Overflow`.
+    #[default]
+    Checked,
+    /// Wraps around in two's complement.
+    Wrapping,
+    /// Computes with integers of any size. Arithmetic and comparisons accept large values, as
+    /// do the conditions of jumps and outputs, but any other use of them fails with
+    /// `IntCodeError::Overflow`: read them with `peek_big` and `last_output_big`. They read as
+    /// zero through `peek`, are written to the output as zero, and traces record them as zero.
+    BigInt,
+}
+
+impl fmt::Display for Arithmetic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Arithmetic::Checked => write!(f, "checked"),
+            Arithmetic::Wrapping => write!(f, "wrapping"),
+            Arithmetic::BigInt => write!(f, "big"),
+        }
+    }
+}
+
+impl FromStr for Arithmetic {
+    type Err = String;
+
+    /// The names `Display` writes.
+    fn from_str(name: &str) -> Result<Self, String> {
+        match name {
+            "checked" => Ok(Arithmetic::Checked),
+            "wrapping" => Ok(Arithmetic::Wrapping),
+            "big" => Ok(Arithmetic::BigInt),
+            _ => Err(format!("unknown arithmetic mode `{}`", name)),
+        }
+    }
+}
+
+impl Arithmetic {
+    pub(crate) fn add(self, a: i64, b: i64) -> Option<i64> {
+        match self {
+            Arithmetic::Wrapping => Some(a.wrapping_add(b)),
+            _ => a.checked_add(b),
+        }
+    }
+
+    pub(crate) fn mul(self, a: i64, b: i64) -> Option<i64> {
+        match self {
+            Arithmetic::Wrapping => Some(a.wrapping_mul(b)),
+            _ => a.checked_mul(b),
+        }
+    }
+}
+
+impl<I, O> IntCodeMachine<I, O> {
+    /// Reads any address like `peek`, including values that do not fit in 64 bits.
+    pub fn peek_big(&self, addr: usize) -> BigInt {
+        match self.big.get(&addr) {
+            Some(value) => value.clone(),
+            None => BigInt::from(self.peek(addr)),
+        }
+    }
+
+    /// Like `last_output_signal`, including values that do not fit in 64 bits.
+    pub fn last_output_big(&self) -> Option<BigInt> {
+        match &self.big_output {
+            Some(value) => Some(value.clone()),
+            None => self.last_output.map(BigInt::from),
+        }
+    }
+}
+
+impl<I: IntInput, O: IntOutput> IntCodeMachine<I, O> {
+    /// Executes the instructions that handle large values, and checks that the others do not
+    /// use any. Returns whether the instruction was executed.
+    pub(crate) fn execute_big(&mut self, decoded: &Decoded) -> Result<bool, IntCodeError> {
+        let ip = self.instruction_pointer;
+        match decoded.opcode {
+            Opcode::Add | Opcode::Mul | Opcode::LessThan | Opcode::Equals => {
+                let a = self.read_big(decoded, 0)?;
+                let b = self.read_big(decoded, 1)?;
+                self.check_small(decoded, 2)?;
+                let addr = self.parameter_address(decoded, 2)?;
+                let value = match decoded.opcode {
+                    Opcode::Add => a + b,
+                    Opcode::Mul => a * b,
+                    Opcode::LessThan => BigInt::from(i64::from(a < b)),
+                    _ => BigInt::from(i64::from(a == b)),
+                };
+                match i64::try_from(&value) {
+                    Ok(value) => self.write_value(value, addr)?,
+                    Err(_) => {
+                        self.write_value(0, addr)?;
+                        self.big.insert(addr, value);
+                    }
+                }
+                self.instruction_pointer = ip + 4;
+            }
+            Opcode::JumpIfTrue | Opcode::JumpIfFalse => {
+                let condition = self.read_big(decoded, 0)? != BigInt::default();
+                let target = i64::try_from(self.read_big(decoded, 1)?)
+                    .map_err(|_| IntCodeError::Overflow { ip })?;
+                if condition == (decoded.opcode == Opcode::JumpIfTrue) {
+                    self.instruction_pointer = self.to_address(target)?;
+                } else {
+                    self.instruction_pointer = ip + 3;
+                }
+            }
+            Opcode::Output => {
+                let value = self.read_big(decoded, 0)?;
+                if i64::try_from(&value).is_ok() {
+                    return Ok(false);
+                }
+                self.write_output(0);
+                self.big_output = Some(value);
+                self.instruction_pointer = ip + 2;
+            }
+            _ => {
+                for i in 0..decoded.opcode.parameter_count() {
+                    self.check_small(decoded, i)?;
+                }
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    // Fails if parameter `i`, or the value it reads, does not fit in 64 bits
+    fn check_small(&self, decoded: &Decoded, i: usize) -> Result<(), IntCodeError> {
+        let ip = self.instruction_pointer;
+        let reads = decoded.modes[i] != Mode::Immediate
+            && decoded.opcode.write_parameter() != Some(i)
+            && self.big.contains_key(&self.parameter_address(decoded, i)?);
+        if reads || self.big.contains_key(&(ip + 1 + i)) {
+            return Err(IntCodeError::Overflow { ip });
+        }
+        Ok(())
+    }
+
+    fn read_big(&self, decoded: &Decoded, i: usize) -> Result<BigInt, IntCodeError> {
+        let ip = self.instruction_pointer;
+        if decoded.modes[i] == Mode::Immediate {
+            return Ok(self.peek_big(ip + 1 + i));
+        }
+        if self.big.contains_key(&(ip + 1 + i)) {
+            return Err(IntCodeError::Overflow { ip });
+        }
+        let addr = self.parameter_address(decoded, i)?;
+        Ok(self.peek_big(addr))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Squares its input six times, then outputs x < -x, x == x, x * -1 + x and x
+    const SQUARES: &str = "3,100,1101,6,0,101,2,100,100,100,1001,101,-1,101,1005,101,6,\
+                           1002,100,-1,102,7,100,102,103,4,103,8,100,100,104,4,104,1,100,102,\
+                           105,4,105,4,100,99";
+
+    #[test]
+    fn modes() {
+        let run = |arithmetic, input| {
+            let mut machine = IntCodeMachine::from_string(SQUARES)
+                .unwrap()
+                .with_arithmetic(arithmetic);
+            machine.add_input_signal(input);
+            let result = machine.run_until_halt();
+            (result, machine)
+        };
+        // 2^64 is the first square that does not fit
+        let (result, machine) = run(Arithmetic::default(), 2);
+        assert_eq!(result, Err(IntCodeError::Overflow { ip: 6 }));
+        assert_eq!(machine.peek(100), 1 << 32);
+        let (result, machine) = run(Arithmetic::Wrapping, 3);
+        assert_eq!(result, Ok(()));
+        assert_eq!(machine.output, vec![0, 1, 0, 3i64.wrapping_pow(64)]);
+
+        let (result, machine) = run(Arithmetic::BigInt, 3);
+        assert_eq!(result, Ok(()));
+        assert_eq!(machine.output, vec![0, 1, 0, 0]);
+        assert_eq!(machine.last_output_big(), Some(BigInt::from(3).pow(64)));
+        assert_eq!(machine.peek_big(100), BigInt::from(3).pow(64));
+        assert_eq!(machine.peek(100), 0);
+        // Small results replace large values
+        let (result, machine) = run(Arithmetic::BigInt, 1);
+        assert_eq!(result, Ok(()));
+        assert_eq!(machine.output, vec![0, 1, 0, 1]);
+        assert_eq!(machine.last_output_big(), Some(BigInt::from(1)));
+        assert_eq!(machine.peek_big(102), BigInt::from(-1));
+    }
+}
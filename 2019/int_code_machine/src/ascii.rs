@@ -0,0 +1,78 @@
+use crate::{AsciiOutput, IntCodeError, IntCodeMachine, IntInput, IntOutput, RunState};
+use std::collections::VecDeque;
+
+impl<O> IntCodeMachine<VecDeque<i64>, O> {
+    /// Adds the ASCII codes of `line` and the newline that ends it to the inputs.
+    pub fn send_line(&mut self, line: &str) {
+        self.input.extend(line.bytes().map(i64::from));
+        self.input.push_back(i64::from(b'\n'));
+    }
+}
+
+impl<I: IntInput, O: IntOutput> IntCodeMachine<I, O> {
+    /// Runs until the text output ends with `prompt`, the machine needs input or it halts, and
+    /// returns what it output meanwhile: the text, and apart from it the values outside of the
+    /// ASCII range. The outputs are written to the machine's output as well.
+    pub fn read_until(&mut self, prompt: &str) -> Result<AsciiOutput, IntCodeError> {
+        let mut read = AsciiOutput::default();
+        while let RunState::Output(value) = self.run()? {
+            read.write(value);
+            if !read.text.is_empty() && read.text.ends_with(prompt) {
+                break;
+            }
+        }
+        Ok(read)
+    }
+
+    /// Like `read_until`, up to and including the next newline.
+    pub fn read_line(&mut self) -> Result<AsciiOutput, IntCodeError> {
+        self.read_until("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{assemble, AsciiOutput, IntCodeMachine, RunState};
+
+    #[test]
+    fn conversation() {
+        // Asks for a number and prints its double, without a newline before the answer
+        let program = assemble(
+            "
+                  out #63
+                  out #10
+                  in n
+            loop: eq n, #10, t
+                  jt t, #done
+                  in n
+                  jt #1, #loop
+            done: out #62
+                  out #32
+                  in n
+                  mul n, #2, n
+                  out n
+                  hlt
+            n:    data 0
+            t:    data 0
+            ",
+        )
+        .unwrap();
+        let mut machine = IntCodeMachine::new(program);
+        let read = machine.read_line().unwrap();
+        assert_eq!(read.text, "?\n");
+        assert!(read.values.is_empty());
+        assert_eq!(machine.read_line().unwrap().text, "");
+        assert_eq!(machine.run(), Ok(RunState::NeedsInput));
+
+        machine.send_line("hello");
+        assert_eq!(machine.read_until("> ").unwrap().text, "> ");
+        machine.add_input_signal(600);
+        let read = machine.read_until("> ").unwrap();
+        assert_eq!(read.text, "");
+        assert_eq!(read.values, vec![1200]);
+        assert!(machine.halted);
+        assert_eq!(machine.output, vec![63, 10, 62, 32, 1200]);
+        let all: AsciiOutput = machine.output.iter().copied().collect();
+        assert_eq!((all.text.as_str(), all.values), ("?\n> ", vec![1200]));
+    }
+}
@@ -0,0 +1,268 @@
+use crate::instruction::{Instruction, Mode, Opcode, Parameter};
+use aoc_common::{ParseError, Span};
+use std::collections::BTreeMap;
+
+// A number, or the address of a label plus an optional offset like `loop+2`
+enum Value<'a> {
+    Number(i64),
+    Label { name: Span<'a>, offset: i64 },
+}
+
+struct Operand<'a> {
+    mode: Mode,
+    value: Value<'a>,
+}
+
+enum Item<'a> {
+    Instruction {
+        opcode: Opcode,
+        operands: Vec<Operand<'a>>,
+    },
+    Data(Vec<Value<'a>>),
+}
+
+fn is_label_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+fn parse_value(token: Span) -> Result<Value, ParseError> {
+    let text = token.as_str();
+    if !text.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_') {
+        return token.parse("a number or a label").map(Value::Number);
+    }
+    let (name, offset) = match text.find(['+', '-']) {
+        Some(i) => {
+            let offset = token.slice(i, text.len());
+            let offset = offset
+                .strip_prefix("+")
+                .unwrap_or(offset)
+                .parse("an offset")?;
+            (token.slice(0, i).trim(), offset)
+        }
+        None => (token, 0),
+    };
+    if !is_label_name(name.as_str()) {
+        return Err(name.error("a label"));
+    }
+    Ok(Value::Label { name, offset })
+}
+
+fn parse_operand(token: Span) -> Result<Operand, ParseError> {
+    let token = token.trim();
+    let (mode, value) = if let Ok(value) = token.strip_prefix("#") {
+        (Mode::Immediate, value)
+    } else if let Ok(value) = token.strip_prefix("@") {
+        (Mode::Relative, value)
+    } else {
+        (Mode::Position, token)
+    };
+    Ok(Operand {
+        mode,
+        value: parse_value(value)?,
+    })
+}
+
+// The values of a `data` directive, which have no modes
+fn parse_data(values: Span) -> Result<Vec<Value>, ParseError> {
+    if values.is_empty() {
+        return Err(values.error("at least one value"));
+    }
+    values.split(",").map(|v| parse_value(v.trim())).collect()
+}
+
+fn parse_instruction<'a>(mnemonic: Span<'a>, operands: Span<'a>) -> Result<Item<'a>, ParseError> {
+    let opcode = Opcode::from_mnemonic(mnemonic.as_str())
+        .ok_or_else(|| mnemonic.error("an instruction mnemonic or `data`"))?;
+    let tokens: Vec<_> = if operands.is_empty() {
+        Vec::new()
+    } else {
+        operands.split(",").collect()
+    };
+    let count = opcode.parameter_count();
+    if tokens.len() != count {
+        let expected = format!("{} operands for `{}`", count, opcode.mnemonic());
+        return Err(match tokens.get(count) {
+            Some(extra) => extra.trim().error(expected),
+            None => operands.end().error(expected),
+        });
+    }
+
+    let mut result = Vec::with_capacity(count);
+    for (i, token) in tokens.into_iter().enumerate() {
+        let operand = parse_operand(token)?;
+        if operand.mode == Mode::Immediate && opcode.write_parameter() == Some(i) {
+            return Err(token
+                .trim()
+                .error("a position (`5`) or relative (`@5`) operand to write to"));
+        }
+        result.push(operand);
+    }
+    Ok(Item::Instruction {
+        opcode,
+        operands: result,
+    })
+}
+
+/// Translates assembly into the comma-separated values `IntCodeMachine::from_string` reads.
+///
+/// Every line holds an instruction like `add 4, #3, @-1`, whose operands are addresses in
+/// position mode, `#` values in immediate mode and `@` offsets in relative mode, or a directive
+/// `data 1, 2, 3` that places values as they are. Lines may start with labels like `loop:`,
+/// which can be used as values anywhere, also with offsets like `loop+2`. Numeric labels like
+/// `12:` instead check that the line starts at that address, so that disassembled listings
+/// assemble again. `;` starts a comment.
+pub fn assemble(source: &str) -> Result<Vec<i64>, ParseError> {
+    let mut labels = BTreeMap::new();
+    let mut items = Vec::new();
+    let mut address = 0;
+
+    for line in Span::new(source).lines() {
+        let mut code = match line.as_str().find(';') {
+            Some(i) => line.slice(0, i).trim(),
+            None => line.trim(),
+        };
+        while let Some(i) = code.as_str().find(':') {
+            let label = code.slice(0, i).trim();
+            code = code.slice(i + 1, code.as_str().len()).trim();
+            if let Ok(expected) = label.as_str().parse::<usize>() {
+                if expected != address {
+                    return Err(label.error(format!("address {}", address)));
+                }
+            } else if !is_label_name(label.as_str()) {
+                return Err(label.error("a label"));
+            } else if labels.insert(label.as_str(), address).is_some() {
+                return Err(label.error("a label that is not defined yet"));
+            }
+        }
+        if code.is_empty() {
+            continue;
+        }
+
+        let text = code.as_str();
+        let end = text.find(char::is_whitespace).unwrap_or(text.len());
+        let mnemonic = code.slice(0, end);
+        let rest = code.slice(end, text.len()).trim();
+        let item = if mnemonic.as_str() == "data" {
+            Item::Data(parse_data(rest)?)
+        } else {
+            parse_instruction(mnemonic, rest)?
+        };
+        address += match &item {
+            Item::Instruction { operands, .. } => 1 + operands.len(),
+            Item::Data(values) => values.len(),
+        };
+        items.push(item);
+    }
+
+    let resolve = |value: &Value| match value {
+        Value::Number(n) => Ok(*n),
+        Value::Label { name, offset } => labels
+            .get(name.as_str())
+            .map(|&address| address as i64 + offset)
+            .ok_or_else(|| name.error("a defined label")),
+    };
+    let mut program = Vec::with_capacity(address);
+    for item in &items {
+        match item {
+            Item::Instruction { opcode, operands } => {
+                let parameters = operands
+                    .iter()
+                    .map(|operand| {
+                        Ok(Parameter {
+                            mode: operand.mode,
+                            value: resolve(&operand.value)?,
+                        })
+                    })
+                    .collect::<Result<_, ParseError>>()?;
+                let instruction = Instruction {
+                    opcode: *opcode,
+                    parameters,
+                };
+                program.extend(instruction.encode());
+            }
+            Item::Data(values) => {
+                for value in values {
+                    program.push(resolve(value)?);
+                }
+            }
+        }
+    }
+    Ok(program)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{disassemble, IntCodeMachine};
+
+    #[test]
+    fn labels_and_data() {
+        let source = "
+            ; Outputs the countdown from the value at `start`
+            loop:  out start
+                   add start, #-1, start
+                   jt start, #loop
+            done:  hlt
+            start: data 3
+        ";
+        let program = assemble(source).unwrap();
+        assert_eq!(program, vec![4, 10, 1001, 10, -1, 10, 1005, 10, 0, 99, 3]);
+
+        let mut machine = IntCodeMachine::new(program);
+        machine.run_until_halt().unwrap();
+        assert_eq!(machine.output, vec![3, 2, 1]);
+
+        assert_eq!(assemble("a: jt #1, a+3\nhlt").unwrap(), vec![105, 1, 3, 99]);
+        assert_eq!(assemble("in @-2").unwrap(), vec![203, -2]);
+    }
+
+    #[test]
+    fn disassembled_listings_assemble() {
+        let program = "109,20,21101,9,0,0,1105,1,11,99,7,204,1,2106,0,0";
+        let memory = IntCodeMachine::from_string(program).unwrap().data;
+        let listing = disassemble(&memory).to_string();
+        assert_eq!(assemble(&listing).unwrap(), memory);
+    }
+
+    #[test]
+    fn errors() {
+        let error = |source| assemble(source).unwrap_err().to_string();
+        assert_eq!(
+            error("jt #1, end"),
+            "line 1, column 8: expected a defined label, found `end`"
+        );
+        assert_eq!(
+            error("add #1, #2, #3"),
+            "line 1, column 13: expected a position (`5`) or relative (`@5`) operand to write to, \
+             found `#3`"
+        );
+        assert_eq!(
+            error("out 1, 2"),
+            "line 1, column 8: expected 1 operands for `out`, found `2`"
+        );
+        assert_eq!(
+            error("mul 1, 2"),
+            "line 1, column 9: expected 3 operands for `mul`, found nothing"
+        );
+        assert_eq!(
+            error("jmp 5"),
+            "line 1, column 1: expected an instruction mnemonic or `data`, found `jmp`"
+        );
+        assert_eq!(
+            error("a: hlt\na: hlt"),
+            "line 2, column 1: expected a label that is not defined yet, found `a`"
+        );
+        assert_eq!(
+            error("hlt\n0: hlt"),
+            "line 2, column 1: expected address 1, found `0`"
+        );
+        assert_eq!(
+            error("data 1, x y"),
+            "line 1, column 9: expected a label, found `x y`"
+        );
+    }
+}
@@ -0,0 +1,283 @@
+use int_code_machine::{Access, Debugger, Instruction, IntCodeMachine, Stop};
+use std::io::{self, BufRead, Write};
+use std::path::Path;
+
+const HELP: &str = "\
+Commands:
+  s, step [n]                 execute n instructions (default 1)
+  c, continue                 run until a breakpoint, a watchpoint, missing input or halt
+  b, break <address>          stop when the instruction pointer reaches the address
+  d, delete <address>         remove the breakpoint at the address
+  w, watch <address> [access] stop after instructions reading or writing the address
+  unwatch <address> [access]  remove watchpoints; access is `read`, `write` or both
+  i, input <values>           add integer inputs, separated by commas or spaces
+  ascii <text>                add the text and a newline as ASCII inputs
+  p, print <address> [count]  show memory
+  set <address> <value>       change memory
+  l, list [address] [count]   disassemble from the address (default the instruction pointer)
+  info                        show registers, pending I/O, breakpoints and watchpoints
+  save <file>                 write a snapshot of the machine to the file
+  load <file>                 restore the machine from a snapshot, keeping breakpoints
+  h, help                     show this help
+  q, quit                     leave the debugger";
+
+struct Session {
+    debugger: Debugger,
+    // Outputs before this index were already shown
+    shown_outputs: usize,
+}
+
+fn parse_number<T: std::str::FromStr>(token: Option<&str>, what: &str) -> Result<T, String> {
+    let token = token.ok_or_else(|| format!("missing {}", what))?;
+    token
+        .parse()
+        .map_err(|_| format!("invalid {} `{}`", what, token))
+}
+
+fn parse_optional<T: std::str::FromStr>(
+    token: Option<&str>,
+    what: &str,
+    default: T,
+) -> Result<T, String> {
+    match token {
+        Some(_) => parse_number(token, what),
+        None => Ok(default),
+    }
+}
+
+fn parse_accesses(token: Option<&str>) -> Result<Vec<Access>, String> {
+    match token {
+        None => Ok(vec![Access::Read, Access::Write]),
+        Some("read") => Ok(vec![Access::Read]),
+        Some("write") => Ok(vec![Access::Write]),
+        Some(other) => Err(format!(
+            "invalid access `{}`, expected read or write",
+            other
+        )),
+    }
+}
+
+fn join<T: ToString>(values: impl IntoIterator<Item = T>) -> String {
+    let values: Vec<_> = values.into_iter().map(|v| v.to_string()).collect();
+    if values.is_empty() {
+        "none".to_owned()
+    } else {
+        values.join(", ")
+    }
+}
+
+impl Session {
+    fn machine(&self) -> &IntCodeMachine {
+        &self.debugger.machine
+    }
+
+    // Shows the outputs written since the last call
+    fn show_outputs(&mut self) {
+        let output = &self.debugger.machine.output;
+        if output.len() > self.shown_outputs {
+            println!("output: {}", join(&output[self.shown_outputs..]));
+            self.shown_outputs = output.len();
+        }
+    }
+
+    fn show_instruction(&self, address: usize) {
+        let marker = |address| match (
+            address == self.machine().instruction_pointer,
+            self.debugger.breakpoints().any(|b| b == address),
+        ) {
+            (true, true) => "*>",
+            (true, false) => " >",
+            (false, true) => "* ",
+            (false, false) => "  ",
+        };
+        match Instruction::decode(self.machine().data(), address) {
+            Ok(instruction) => println!("{}{:>6}: {}", marker(address), address, instruction),
+            Err(e) => println!("{}{:>6}: ({})", marker(address), address, e),
+        }
+    }
+
+    // Shows what happened after executing instructions
+    fn report(&mut self, stop: Option<Stop>) {
+        self.show_outputs();
+        if let Some(stop) = stop {
+            println!("stopped: {}", stop);
+        }
+        if !self.machine().halted {
+            self.show_instruction(self.machine().instruction_pointer);
+        }
+    }
+
+    fn step(&mut self, count: usize) -> Result<(), String> {
+        let mut stop = None;
+        for _ in 0..count {
+            stop = self.debugger.step().map_err(|e| e.to_string())?;
+            if stop.is_some() {
+                break;
+            }
+        }
+        self.report(stop);
+        Ok(())
+    }
+
+    fn resume(&mut self) -> Result<(), String> {
+        let stop = self.debugger.resume().map_err(|e| e.to_string())?;
+        self.report(Some(stop));
+        Ok(())
+    }
+
+    fn list(&self, start: usize, count: usize) {
+        let data = self.machine().data();
+        let mut address = start;
+        for _ in 0..count {
+            if address >= data.len() {
+                break;
+            }
+            self.show_instruction(address);
+            address += Instruction::decode(data, address).map_or(1, |i| i.size());
+        }
+    }
+
+    fn info(&self) {
+        let machine = self.machine();
+        println!("instruction pointer: {}", machine.instruction_pointer);
+        println!("relative base: {}", machine.relative_base_offset);
+        println!("halted: {}", machine.halted);
+        println!("pending input: {}", join(&machine.input));
+        println!(
+            "output: {} values, last {}",
+            machine.output.len(),
+            machine
+                .last_output_signal()
+                .map_or("none".to_owned(), |v| v.to_string())
+        );
+        let stats = machine.memory_stats();
+        println!(
+            "memory: {} contiguous values, {} pages",
+            stats.contiguous,
+            stats.pages.len()
+        );
+        println!("breakpoints: {}", join(self.debugger.breakpoints()));
+        println!(
+            "watchpoints: {}",
+            join(
+                self.debugger
+                    .watchpoints()
+                    .map(|(address, access)| format!("{} {}", address, access))
+            )
+        );
+    }
+
+    /// Executes one command line. Returns false when the session should end.
+    fn execute(&mut self, line: &str) -> Result<bool, String> {
+        let mut words = line.split_whitespace();
+        let command = match words.next() {
+            Some(command) => command,
+            None => return Ok(true),
+        };
+        match command {
+            "s" | "step" => self.step(parse_optional(words.next(), "count", 1)?)?,
+            "c" | "continue" => self.resume()?,
+            "b" | "break" => {
+                let address = parse_number(words.next(), "address")?;
+                if !self.debugger.add_breakpoint(address) {
+                    println!("breakpoint at {} already set", address);
+                }
+            }
+            "d" | "delete" => {
+                let address = parse_number(words.next(), "address")?;
+                if !self.debugger.remove_breakpoint(address) {
+                    println!("no breakpoint at {}", address);
+                }
+            }
+            "w" | "watch" => {
+                let address = parse_number(words.next(), "address")?;
+                for access in parse_accesses(words.next())? {
+                    self.debugger.add_watchpoint(address, access);
+                }
+            }
+            "unwatch" => {
+                let address = parse_number(words.next(), "address")?;
+                for access in parse_accesses(words.next())? {
+                    self.debugger.remove_watchpoint(address, access);
+                }
+            }
+            "i" | "input" => {
+                let rest = line.trim_start()[command.len()..].replace(',', " ");
+                let values = rest
+                    .split_whitespace()
+                    .map(|v| parse_number(Some(v), "input"))
+                    .collect::<Result<Vec<i64>, _>>()?;
+                self.debugger.machine.input.extend(values);
+            }
+            "ascii" => {
+                let text = line.trim_start()[command.len()..].trim_start();
+                self.debugger.machine.send_line(text);
+            }
+            "p" | "print" => {
+                let address: usize = parse_number(words.next(), "address")?;
+                let count = parse_optional(words.next(), "count", 1)?;
+                let end = address.checked_add(count).ok_or_else(|| {
+                    format!("address range {}+{} is out of bounds", address, count)
+                })?;
+                let values = (address..end).map(|a| self.machine().peek(a));
+                println!("{:>8}: {}", address, join(values));
+            }
+            "set" => {
+                let address: usize = parse_number(words.next(), "address")?;
+                let value = parse_number(words.next(), "value")?;
+                self.debugger
+                    .machine
+                    .poke(address, value)
+                    .map_err(|e| e.to_string())?;
+            }
+            "l" | "list" => {
+                let ip = self.machine().instruction_pointer;
+                let address = parse_optional(words.next(), "address", ip)?;
+                self.list(address, parse_optional(words.next(), "count", 10)?);
+            }
+            "info" => self.info(),
+            "save" => {
+                let path = words.next().ok_or("missing file")?;
+                std::fs::write(path, self.machine().to_snapshot())
+                    .map_err(|e| format!("could not write {}: {}", path, e))?;
+            }
+            "load" => {
+                let path = words.next().ok_or("missing file")?;
+                let text = crate::read_file(Path::new(path))?;
+                let machine = IntCodeMachine::from_snapshot(&text)
+                    .map_err(|e| format!("{}: invalid snapshot at {}\n{}", path, e, e.excerpt()))?;
+                self.shown_outputs = machine.output.len();
+                self.debugger.machine = machine;
+                self.show_instruction(self.machine().instruction_pointer);
+            }
+            "h" | "help" => println!("{}", HELP),
+            "q" | "quit" => return Ok(false),
+            _ => return Err(format!("unknown command `{}`, try `help`", command)),
+        }
+        Ok(true)
+    }
+}
+
+/// Reads commands from stdin until `quit` or the end of the input.
+pub fn run(machine: IntCodeMachine) -> Result<(), String> {
+    let mut session = Session {
+        debugger: Debugger::new(machine),
+        shown_outputs: 0,
+    };
+    session.show_instruction(0);
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+    loop {
+        print!("(intcode) ");
+        io::stdout().flush().map_err(|e| e.to_string())?;
+        let line = match lines.next() {
+            Some(line) => line.map_err(|e| format!("could not read a command: {}", e))?,
+            None => return Ok(()),
+        };
+        match session.execute(&line) {
+            Ok(true) => (),
+            Ok(false) => return Ok(()),
+            Err(e) => println!("error: {}", e),
+        }
+    }
+}
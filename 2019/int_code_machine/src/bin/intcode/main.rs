@@ -0,0 +1,135 @@
+mod debug;
+mod run;
+mod trace;
+
+use int_code_machine::{assemble, disassemble, IntCodeMachine, RunState};
+use std::path::Path;
+
+const USAGE: &str = "Usage: intcode disassemble <program file>
+       intcode assemble <source file>
+       intcode debug <program file>
+       intcode interactive <program file>
+       intcode run [options] <program file>
+       intcode profile [--listing] <program file> [input...]
+       intcode trace <program file> <trace file> [input...]
+       intcode replay <program file> <trace file>
+       intcode diff <trace file> <trace file>";
+
+fn read_file(path: &Path) -> Result<String, String> {
+    std::fs::read_to_string(path).map_err(|e| format!("could not read {}: {}", path.display(), e))
+}
+
+// Reads and parses a program, with a located error if it is invalid
+fn load_program(path: &Path) -> Result<IntCodeMachine, String> {
+    let text = read_file(path)?;
+    let text = text.trim();
+    IntCodeMachine::from_string(text).map_err(|e| {
+        let e = e.to_parse_error(text);
+        format!(
+            "{}: invalid program at {}\n{}",
+            path.display(),
+            e,
+            e.excerpt()
+        )
+    })
+}
+
+fn disassemble_file(path: &Path) -> Result<(), String> {
+    let machine = load_program(path)?;
+    print!("{}", disassemble(machine.data()));
+    Ok(())
+}
+
+fn assemble_file(path: &Path) -> Result<(), String> {
+    let source = read_file(path)?;
+    let program = assemble(&source).map_err(|e| {
+        format!(
+            "{}: invalid assembly at {}\n{}",
+            path.display(),
+            e,
+            e.excerpt()
+        )
+    })?;
+    let values: Vec<_> = program.iter().map(i64::to_string).collect();
+    println!("{}", values.join(","));
+    Ok(())
+}
+
+// Runs the program with the inputs until it halts or needs more input and prints where it spent
+// its time, optionally on the disassembly
+fn profile_file(path: &Path, inputs: &[String], listing: bool) -> Result<(), String> {
+    let mut machine = load_program(path)?;
+    for input in inputs {
+        let value = input
+            .parse()
+            .map_err(|_| format!("invalid input `{}`", input))?;
+        machine.add_input_signal(value);
+    }
+    machine.start_profile();
+    let result = loop {
+        match machine.run() {
+            Ok(RunState::Output(_)) => (),
+            Ok(state) => break Ok(state),
+            Err(e) => break Err(e),
+        }
+    };
+    let profile = machine.take_profile().unwrap_or_default();
+    match result {
+        Ok(RunState::NeedsInput) => println!("stopped waiting for input"),
+        Ok(_) => println!("halted"),
+        Err(e) => println!("stopped: {}", e),
+    }
+    print!("{}", profile);
+    if listing {
+        print!("\n{}", profile.annotate(&disassemble(machine.data())));
+    }
+    Ok(())
+}
+
+// Exits with status 1 if a comparison found a difference
+fn same(result: Result<bool, String>) -> Result<(), String> {
+    if !result? {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let result = match args.as_slice() {
+        [command, path] if command == "disassemble" => disassemble_file(Path::new(path)),
+        [command, path] if command == "assemble" => assemble_file(Path::new(path)),
+        [command, path] if command == "debug" => load_program(Path::new(path)).and_then(debug::run),
+        [command, path] if command == "interactive" => run::run(run::Options::interactive(path)),
+        [command, flag, path, inputs @ ..] if command == "profile" && flag == "--listing" => {
+            profile_file(Path::new(path), inputs, true)
+        }
+        [command, path, inputs @ ..] if command == "profile" => {
+            profile_file(Path::new(path), inputs, false)
+        }
+        [command, args @ ..] if command == "run" => match run::Options::parse(args) {
+            Ok(options) => run::run(options),
+            Err(e) => {
+                eprintln!("{}\n{}\n{}", e, USAGE, run::OPTIONS);
+                std::process::exit(2);
+            }
+        },
+        [command, program, trace, inputs @ ..] if command == "trace" => {
+            trace::record(Path::new(program), Path::new(trace), inputs)
+        }
+        [command, program, trace] if command == "replay" => {
+            same(trace::replay(Path::new(program), Path::new(trace)))
+        }
+        [command, first, second] if command == "diff" => {
+            same(trace::diff(Path::new(first), Path::new(second)))
+        }
+        _ => {
+            eprintln!("{}\n{}", USAGE, run::OPTIONS);
+            std::process::exit(2);
+        }
+    };
+    if let Err(e) = result {
+        eprintln!("{}", e);
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,155 @@
+use crate::{load_program, read_file};
+use int_code_machine::{Arithmetic, RunState};
+use std::convert::TryFrom;
+use std::io::{self, BufRead, Write};
+use std::path::PathBuf;
+
+pub const OPTIONS: &str = "Options of run:
+  --input <values>            add inputs: numbers separated by commas, or a line of text
+  --input-file <file>         add the inputs in the file
+  --stdin                     read more inputs from stdin, a line whenever they run out
+  --patch <address>=<value>   change the program before running it
+  --ascii                     inputs and outputs are text, other outputs are printed as numbers
+  --arithmetic <mode>         checked (the default), wrapping or big";
+
+enum Source {
+    Flag(String),
+    File(PathBuf),
+}
+
+/// What `intcode run` does, from its command line.
+#[derive(Default)]
+pub struct Options {
+    program: PathBuf,
+    sources: Vec<Source>,
+    stdin: bool,
+    patches: Vec<(usize, i64)>,
+    ascii: bool,
+    arithmetic: Arithmetic,
+}
+
+impl Options {
+    /// Runs the program as text on the terminal, like `run --ascii --stdin`.
+    pub fn interactive(program: &str) -> Self {
+        Self {
+            program: PathBuf::from(program),
+            stdin: true,
+            ascii: true,
+            ..Self::default()
+        }
+    }
+
+    pub fn parse(args: &[String]) -> Result<Self, String> {
+        let mut options = Self::default();
+        let mut program = None;
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            let mut value = || {
+                args.next()
+                    .ok_or_else(|| format!("missing value of {}", arg))
+            };
+            match arg.as_str() {
+                "--input" => options.sources.push(Source::Flag(value()?.clone())),
+                "--input-file" => options.sources.push(Source::File(PathBuf::from(value()?))),
+                "--stdin" => options.stdin = true,
+                "--patch" => {
+                    let patch = value()?;
+                    let parsed = patch.split_once('=').and_then(|(address, value)| {
+                        Some((address.parse().ok()?, value.parse().ok()?))
+                    });
+                    options.patches.push(parsed.ok_or_else(|| {
+                        format!("invalid patch `{}`, expected <address>=<value>", patch)
+                    })?);
+                }
+                "--ascii" => options.ascii = true,
+                "--arithmetic" => options.arithmetic = value()?.parse()?,
+                flag if flag.starts_with("--") => return Err(format!("unknown option {}", flag)),
+                path if program.is_none() => program = Some(PathBuf::from(path)),
+                path => return Err(format!("unexpected argument `{}`", path)),
+            }
+        }
+        options.program = program.ok_or("missing program file")?;
+        Ok(options)
+    }
+}
+
+// Numbers separated by commas or whitespace
+fn parse_values(text: &str) -> Result<Vec<i64>, String> {
+    text.split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|value| !value.is_empty())
+        .map(|value| {
+            value
+                .parse()
+                .map_err(|_| format!("invalid input `{}`", value))
+        })
+        .collect()
+}
+
+/// Loads, patches and runs the program until it halts, printing its outputs as they come. Fails
+/// if it needs more input than given, unless reading from stdin.
+pub fn run(options: Options) -> Result<(), String> {
+    let mut machine = load_program(&options.program)?.with_arithmetic(options.arithmetic);
+    for &(address, value) in &options.patches {
+        machine
+            .poke(address, value)
+            .map_err(|e| format!("invalid patch: {}", e))?;
+    }
+    for source in &options.sources {
+        match (source, options.ascii) {
+            (Source::Flag(text), true) => machine.send_line(text),
+            (Source::Flag(text), false) => machine.input.extend(parse_values(text)?),
+            (Source::File(path), true) => machine
+                .input
+                .extend(read_file(path)?.bytes().map(i64::from)),
+            (Source::File(path), false) => machine.input.extend(parse_values(&read_file(path)?)?),
+        }
+    }
+
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+    let write_error = |e: io::Error| format!("could not write the output: {}", e);
+    // Numbers get lines of their own, also after text
+    let mut line_start = true;
+    loop {
+        match machine.run().map_err(|e| e.to_string())? {
+            RunState::Output(value) => {
+                // Values too large for 64 bits are output as zero
+                let value = machine.last_output_big().unwrap_or_else(|| value.into());
+                let written = match u8::try_from(&value) {
+                    Ok(byte) if options.ascii && byte.is_ascii() => {
+                        line_start = byte == b'\n';
+                        write!(out, "{}", char::from(byte))
+                    }
+                    _ if line_start => writeln!(out, "{}", value),
+                    _ => {
+                        line_start = true;
+                        writeln!(out, "\n{}", value)
+                    }
+                };
+                written.map_err(write_error)?;
+            }
+            RunState::NeedsInput => {
+                out.flush().map_err(write_error)?;
+                if !options.stdin {
+                    return Err(format!(
+                        "the program needs more input at address {}",
+                        machine.instruction_pointer
+                    ));
+                }
+                // The end of stdin ends the session
+                let line = match lines.next() {
+                    Some(line) => line.map_err(|e| format!("could not read input: {}", e))?,
+                    None => return Ok(()),
+                };
+                if options.ascii {
+                    machine.send_line(&line);
+                } else {
+                    machine.input.extend(parse_values(&line)?);
+                }
+            }
+            RunState::Halted => return out.flush().map_err(write_error),
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use crate::{load_program, read_file};
+use int_code_machine::{RunState, Trace};
+use std::path::Path;
+
+/// Steps shown before the first divergence of two traces.
+const CONTEXT: usize = 3;
+
+fn load_trace(path: &Path) -> Result<Trace, String> {
+    let text = read_file(path)?;
+    Trace::parse(&text).map_err(|e| {
+        format!(
+            "{}: invalid trace at {}\n{}",
+            path.display(),
+            e,
+            e.excerpt()
+        )
+    })
+}
+
+fn save_trace(path: &Path, trace: &Trace) -> Result<(), String> {
+    std::fs::write(path, trace.to_string())
+        .map_err(|e| format!("could not write {}: {}", path.display(), e))
+}
+
+// Prints the steps around the first divergence, if any, and returns whether there is one
+fn report_divergence(expected: (&str, &Trace), actual: (&str, &Trace)) -> bool {
+    let index = match expected.1.first_divergence(actual.1) {
+        Some(index) => index,
+        None => return false,
+    };
+    println!("the traces diverge at step {}", index);
+    let width = expected.0.len().max(actual.0.len());
+    for (i, step) in expected.1.steps[index.saturating_sub(CONTEXT)..index]
+        .iter()
+        .enumerate()
+    {
+        let number = index.saturating_sub(CONTEXT) + i;
+        println!("{:width$} {:>8}: {}", "", number, step, width = width);
+    }
+    for (name, trace) in &[expected, actual] {
+        match trace.steps.get(index) {
+            Some(step) => println!("{:width$} {:>8}: {}", name, index, step, width = width),
+            None => println!("{:width$} {:>8}: (no step)", name, index, width = width),
+        }
+    }
+    true
+}
+
+/// Runs the program with the inputs until it halts or needs more input and saves the trace.
+pub fn record(program: &Path, trace_path: &Path, inputs: &[String]) -> Result<(), String> {
+    let mut machine = load_program(program)?;
+    for input in inputs {
+        let value = input
+            .parse()
+            .map_err(|_| format!("invalid input `{}`", input))?;
+        machine.add_input_signal(value);
+    }
+    machine.start_trace();
+    let result = loop {
+        match machine.run() {
+            Ok(RunState::Output(_)) => (),
+            Ok(state) => break Ok(state),
+            Err(e) => break Err(e),
+        }
+    };
+    let trace = machine.take_trace().unwrap_or_default();
+    save_trace(trace_path, &trace)?;
+    match result {
+        Ok(RunState::NeedsInput) => {
+            println!("{} steps, stopped waiting for input", trace.steps.len())
+        }
+        Ok(_) => println!("{} steps until halt", trace.steps.len()),
+        Err(e) => return Err(format!("{} steps, then {}", trace.steps.len(), e)),
+    }
+    Ok(())
+}
+
+/// Reruns a recorded trace on the program and reports where the run first differs from it.
+/// Returns whether the replay reproduced the trace.
+pub fn replay(program: &Path, trace_path: &Path) -> Result<bool, String> {
+    let mut machine = load_program(program)?;
+    let trace = load_trace(trace_path)?;
+    let result = machine.replay(&trace);
+    let replayed = machine.take_trace().unwrap_or_default();
+    let diverged = report_divergence(("recorded", &trace), ("replayed", &replayed));
+    if let Err(e) = result {
+        println!("the replay stopped: {}", e);
+    } else if !diverged {
+        println!("replayed all {} steps", trace.steps.len());
+    }
+    Ok(!diverged)
+}
+
+/// Returns whether the traces are the same.
+pub fn diff(first: &Path, second: &Path) -> Result<bool, String> {
+    let first = load_trace(first)?;
+    let second = load_trace(second)?;
+    let diverged = report_divergence(("first", &first), ("second", &second));
+    if !diverged {
+        println!("the traces are the same, {} steps", first.steps.len());
+    }
+    Ok(!diverged)
+}
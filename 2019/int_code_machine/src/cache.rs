@@ -0,0 +1,98 @@
+use crate::instruction::{Mode, Opcode};
+use crate::IntCodeError;
+
+/// An instruction decoded into what executing it needs: the opcode, and the mode and value of
+/// every parameter. Unused parameters are immediate zeros.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct Decoded {
+    pub(crate) opcode: Opcode,
+    pub(crate) modes: [Mode; 3],
+    pub(crate) values: [i64; 3],
+}
+
+impl Decoded {
+    /// Decodes the instruction at `address` of the memory `peek` reads. The addresses the
+    /// parameters refer to are only checked when executing it.
+    pub(crate) fn decode<F: Fn(usize) -> i64>(
+        peek: F,
+        address: usize,
+    ) -> Result<Self, IntCodeError> {
+        let code = peek(address);
+        let opcode = Opcode::from_code(code % 100).ok_or(IntCodeError::InvalidOpcode {
+            ip: address,
+            opcode: code,
+        })?;
+
+        let mut decoded = Self {
+            opcode,
+            modes: [Mode::Immediate; 3],
+            values: [0; 3],
+        };
+        let mut modes = code / 100;
+        for i in 0..opcode.parameter_count() {
+            let mode = Mode::from_digit(modes % 10).ok_or(IntCodeError::InvalidParameterMode {
+                ip: address,
+                parameter: i + 1,
+                mode: modes % 10,
+            })?;
+            if mode == Mode::Immediate && opcode.write_parameter() == Some(i) {
+                return Err(IntCodeError::WriteInImmediateMode {
+                    ip: address,
+                    parameter: i + 1,
+                });
+            }
+            decoded.modes[i] = mode;
+            decoded.values[i] = peek(address + i + 1);
+            modes /= 10;
+        }
+        Ok(decoded)
+    }
+}
+
+/// Decoded instructions by address, for the contiguous memory. A write invalidates the
+/// instructions that may contain the written address, so that self-modifying programs run
+/// their new code.
+#[derive(Clone, Debug)]
+pub(crate) struct DecodeCache {
+    pub(crate) enabled: bool,
+    entries: Vec<Option<Decoded>>,
+}
+
+impl Default for DecodeCache {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            entries: Vec::new(),
+        }
+    }
+}
+
+impl DecodeCache {
+    pub(crate) fn get(&self, address: usize) -> Option<Decoded> {
+        self.entries.get(address).cloned().flatten()
+    }
+
+    /// `size` is the length of the contiguous memory, which `address` has to be below.
+    pub(crate) fn insert(&mut self, address: usize, decoded: Decoded, size: usize) {
+        if !self.enabled {
+            return;
+        }
+        if self.entries.len() < size {
+            self.entries.resize(size, None);
+        }
+        self.entries[address] = Some(decoded);
+    }
+
+    /// Forgets the instructions that contain `address`, which start at most three values
+    /// before it.
+    pub(crate) fn invalidate(&mut self, address: usize) {
+        let end = (address + 1).min(self.entries.len());
+        for entry in &mut self.entries[address.saturating_sub(3).min(end)..end] {
+            *entry = None;
+        }
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
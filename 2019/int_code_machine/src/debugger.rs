@@ -0,0 +1,214 @@
+use crate::{IntCodeError, IntCodeMachine, IntInput, IntOutput};
+use std::collections::{BTreeSet, VecDeque};
+use std::fmt;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Access {
+    Read,
+    Write,
+}
+
+impl fmt::Display for Access {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Access::Read => write!(f, "read"),
+            Access::Write => write!(f, "write"),
+        }
+    }
+}
+
+/// Why the debugger handed control back.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Stop {
+    /// The instruction pointer reached a breakpoint at this address.
+    Breakpoint(usize),
+    /// The instruction at `ip` accessed a watched address.
+    Watchpoint {
+        ip: usize,
+        address: usize,
+        access: Access,
+    },
+    /// The next instruction reads input but there is none.
+    NeedsInput,
+    Halted,
+}
+
+impl fmt::Display for Stop {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Stop::Breakpoint(address) => write!(f, "breakpoint at {}", address),
+            Stop::Watchpoint {
+                ip,
+                address,
+                access,
+            } => write!(f, "{} of {} by the instruction at {}", access, address, ip),
+            Stop::NeedsInput => write!(f, "waiting for input"),
+            Stop::Halted => write!(f, "halted"),
+        }
+    }
+}
+
+/// Runs a machine one instruction at a time, stopping at breakpoints on the instruction pointer
+/// and after instructions that read or write watched addresses. The machine stays public, so
+/// its memory, relative base and I/O can be inspected and changed between steps.
+pub struct Debugger<I = VecDeque<i64>, O = Vec<i64>> {
+    pub machine: IntCodeMachine<I, O>,
+    breakpoints: BTreeSet<usize>,
+    watchpoints: BTreeSet<(usize, Access)>,
+}
+
+impl<I, O> Debugger<I, O> {
+    pub fn new(machine: IntCodeMachine<I, O>) -> Self {
+        Self {
+            machine,
+            breakpoints: BTreeSet::new(),
+            watchpoints: BTreeSet::new(),
+        }
+    }
+
+    pub fn breakpoints(&self) -> impl Iterator<Item = usize> + '_ {
+        self.breakpoints.iter().cloned()
+    }
+
+    pub fn watchpoints(&self) -> impl Iterator<Item = (usize, Access)> + '_ {
+        self.watchpoints.iter().cloned()
+    }
+
+    /// Returns false if there already was a breakpoint at `address`.
+    pub fn add_breakpoint(&mut self, address: usize) -> bool {
+        self.breakpoints.insert(address)
+    }
+
+    pub fn remove_breakpoint(&mut self, address: usize) -> bool {
+        self.breakpoints.remove(&address)
+    }
+
+    /// Returns false if `address` was already watched for `access`.
+    pub fn add_watchpoint(&mut self, address: usize, access: Access) -> bool {
+        self.watchpoints.insert((address, access))
+    }
+
+    pub fn remove_watchpoint(&mut self, address: usize, access: Access) -> bool {
+        self.watchpoints.remove(&(address, access))
+    }
+
+    /// The memory the instruction at the instruction pointer accesses, in parameter order.
+    /// Empty if the instruction cannot be decoded, since executing it fails anyway.
+    pub fn accesses(&self) -> Vec<(usize, Access)> {
+        self.machine.accesses()
+    }
+}
+
+impl<I: IntInput, O: IntOutput> Debugger<I, O> {
+    /// Executes one instruction, even one at a breakpoint. Returns why execution should stop
+    /// afterwards, if it should.
+    pub fn step(&mut self) -> Result<Option<Stop>, IntCodeError> {
+        if self.machine.halted {
+            return Ok(Some(Stop::Halted));
+        }
+        let ip = self.machine.instruction_pointer;
+        let accesses = self.accesses();
+        match self.machine.step() {
+            Err(IntCodeError::InputStarvation { .. }) => return Ok(Some(Stop::NeedsInput)),
+            result => result?,
+        }
+
+        if self.machine.halted {
+            return Ok(Some(Stop::Halted));
+        }
+        let watched = accesses
+            .into_iter()
+            .find(|access| self.watchpoints.contains(access));
+        if let Some((address, access)) = watched {
+            Ok(Some(Stop::Watchpoint {
+                ip,
+                address,
+                access,
+            }))
+        } else if self.breakpoints.contains(&self.machine.instruction_pointer) {
+            Ok(Some(Stop::Breakpoint(self.machine.instruction_pointer)))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Runs until a breakpoint or watchpoint is hit, input is missing or the machine halts.
+    pub fn resume(&mut self) -> Result<Stop, IntCodeError> {
+        loop {
+            if let Some(stop) = self.step()? {
+                return Ok(stop);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Counts the value at 10 down to zero, outputting every value
+    const COUNTDOWN: &str = "4,10,1001,10,-1,10,1005,10,0,99,3";
+
+    #[test]
+    fn breakpoints() {
+        let machine = IntCodeMachine::from_string(COUNTDOWN).unwrap();
+        let mut debugger = Debugger::new(machine);
+        assert!(debugger.add_breakpoint(6));
+        assert_eq!(debugger.resume(), Ok(Stop::Breakpoint(6)));
+        assert_eq!(debugger.machine.output, vec![3]);
+        // Resuming from a breakpoint executes the instruction at it first
+        assert_eq!(debugger.resume(), Ok(Stop::Breakpoint(6)));
+        assert_eq!(debugger.machine.output, vec![3, 2]);
+
+        assert!(debugger.remove_breakpoint(6));
+        assert_eq!(debugger.step(), Ok(None));
+        assert_eq!(debugger.machine.instruction_pointer, 0);
+        assert_eq!(debugger.resume(), Ok(Stop::Halted));
+        assert_eq!(debugger.machine.output, vec![3, 2, 1]);
+        assert_eq!(debugger.step(), Ok(Some(Stop::Halted)));
+    }
+
+    #[test]
+    fn watchpoints() {
+        let machine = IntCodeMachine::from_string(COUNTDOWN).unwrap();
+        let mut debugger = Debugger::new(machine);
+        assert_eq!(debugger.accesses(), vec![(10, Access::Read)]);
+        debugger.add_watchpoint(10, Access::Write);
+        assert_eq!(
+            debugger.resume(),
+            Ok(Stop::Watchpoint {
+                ip: 2,
+                address: 10,
+                access: Access::Write
+            })
+        );
+        assert_eq!(debugger.machine.data[10], 2);
+
+        debugger.remove_watchpoint(10, Access::Write);
+        debugger.add_watchpoint(10, Access::Read);
+        assert_eq!(
+            debugger.resume(),
+            Ok(Stop::Watchpoint {
+                ip: 6,
+                address: 10,
+                access: Access::Read
+            })
+        );
+
+        let machine = IntCodeMachine::from_string("109,5,203,1,99").unwrap();
+        let mut debugger = Debugger::new(machine);
+        debugger.add_watchpoint(6, Access::Write);
+        assert_eq!(debugger.resume(), Ok(Stop::NeedsInput));
+        assert_eq!(debugger.machine.instruction_pointer, 2);
+        debugger.machine.add_input_signal(7);
+        assert_eq!(
+            debugger.resume(),
+            Ok(Stop::Watchpoint {
+                ip: 2,
+                address: 6,
+                access: Access::Write
+            })
+        );
+        assert_eq!(debugger.machine.data[6], 7);
+    }
+}
@@ -0,0 +1,198 @@
+use crate::instruction::{Instruction, Mode, Opcode, Parameter};
+use std::collections::BTreeSet;
+use std::fmt;
+use std::ops::Range;
+
+/// Data regions are split into lines of at most this many values.
+const DATA_PER_LINE: usize = 8;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Line {
+    Instruction {
+        address: usize,
+        instruction: Instruction,
+    },
+    /// Memory that is never executed.
+    Data { address: usize, values: Vec<i64> },
+}
+
+impl Line {
+    /// The addresses of the values the line shows.
+    pub fn addresses(&self) -> Range<usize> {
+        match self {
+            Line::Instruction {
+                address,
+                instruction,
+            } => *address..address + instruction.size(),
+            Line::Data { address, values } => *address..address + values.len(),
+        }
+    }
+}
+
+/// Displays as a line of the listing, without the newline.
+impl fmt::Display for Line {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Line::Instruction {
+                address,
+                instruction,
+            } => {
+                let raw: Vec<_> = instruction.encode().iter().map(i64::to_string).collect();
+                write!(
+                    f,
+                    "{:>6}: {:<28} ; {}",
+                    address,
+                    instruction.to_string(),
+                    raw.join(",")
+                )
+            }
+            Line::Data { address, values } => {
+                let values: Vec<_> = values.iter().map(i64::to_string).collect();
+                write!(f, "{:>6}: data {}", address, values.join(", "))
+            }
+        }
+    }
+}
+
+/// A program decoded into instructions and data. Displays as one line per entry, with the
+/// address in front and the raw values of instructions in a `;` comment.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Listing {
+    pub lines: Vec<Line>,
+}
+
+// The address a jump goes to, if it is known before running the program
+fn jump_target(memory: &[i64], target: Parameter) -> Option<usize> {
+    let value = match target.mode {
+        Mode::Immediate => target.value,
+        Mode::Position if target.value >= 0 => *memory.get(target.value as usize)?,
+        _ => return None,
+    };
+    if value >= 0 {
+        Some(value as usize)
+    } else {
+        None
+    }
+}
+
+/// Finds the addresses at which executed instructions start by following the control flow from
+/// address 0. Jumps to a known address are assumed to be calls, so execution also continues after
+/// them, while jumps to a computed address (usually returns) only continue when conditional.
+/// Programs that modify their own code may execute more than this finds.
+fn instruction_starts(memory: &[i64]) -> BTreeSet<usize> {
+    let mut starts = BTreeSet::new();
+    let mut todo = vec![0];
+    while let Some(address) = todo.pop() {
+        if address >= memory.len() || starts.contains(&address) {
+            continue;
+        }
+        let instruction = match Instruction::decode(memory, address) {
+            Ok(instruction) if address + instruction.size() <= memory.len() => instruction,
+            _ => continue,
+        };
+        starts.insert(address);
+        let next = address + instruction.size();
+
+        match instruction.opcode {
+            Opcode::Halt => (),
+            Opcode::JumpIfTrue | Opcode::JumpIfFalse => {
+                let condition = instruction.parameters[0];
+                let target = instruction.parameters[1];
+                let jumps_if = instruction.opcode == Opcode::JumpIfTrue;
+                let known = condition.mode == Mode::Immediate;
+                if !known || (condition.value != 0) == jumps_if {
+                    todo.extend(jump_target(memory, target));
+                }
+                let always = known && (condition.value != 0) == jumps_if;
+                if !always || target.mode == Mode::Immediate {
+                    todo.push(next);
+                }
+            }
+            _ => todo.push(next),
+        }
+    }
+    starts
+}
+
+pub fn disassemble(memory: &[i64]) -> Listing {
+    let starts = instruction_starts(memory);
+    let mut lines = Vec::new();
+    let mut address = 0;
+    while address < memory.len() {
+        if starts.contains(&address) {
+            let instruction = Instruction::decode(memory, address).unwrap();
+            let size = instruction.size();
+            lines.push(Line::Instruction {
+                address,
+                instruction,
+            });
+            address += size;
+        } else {
+            let end = starts
+                .range(address..)
+                .next()
+                .cloned()
+                .unwrap_or(memory.len())
+                .min(address + DATA_PER_LINE);
+            lines.push(Line::Data {
+                address,
+                values: memory[address..end].to_vec(),
+            });
+            address = end;
+        }
+    }
+    Listing { lines }
+}
+
+impl fmt::Display for Listing {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for line in &self.lines {
+            writeln!(f, "{}", line)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IntCodeMachine;
+
+    #[test]
+    fn listing() {
+        // Calls a subroutine at 11 that returns to the address stored at @0
+        let program = "109,20,21101,9,0,0,1105,1,11,99,7,204,1,2106,0,0";
+        let memory = IntCodeMachine::from_string(program).unwrap().data;
+        let listing = disassemble(&memory);
+        assert_eq!(
+            listing.to_string(),
+            "     0: rbo #20                      ; 109,20
+     2: add #9, #0, @0               ; 21101,9,0,0
+     6: jt #1, #11                   ; 1105,1,11
+     9: hlt                          ; 99
+    10: data 7
+    11: out @1                       ; 204,1
+    13: jf #0, @0                    ; 2106,0,0
+"
+        );
+    }
+
+    #[test]
+    fn undecodable_code_is_data() {
+        let memory = IntCodeMachine::from_string("1106,0,4,42,3").unwrap().data;
+        let listing = disassemble(&memory);
+        assert_eq!(
+            listing.lines,
+            vec![
+                Line::Instruction {
+                    address: 0,
+                    instruction: Instruction::decode(&memory, 0).unwrap()
+                },
+                Line::Data {
+                    address: 3,
+                    values: vec![42, 3]
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,148 @@
+use std::collections::VecDeque;
+use std::convert::TryFrom;
+use std::sync::mpsc::{Receiver, Sender};
+
+/// Where a machine reads its input from. Returning `None` makes the reading instruction fail
+/// with `IntCodeError::InputStarvation`, which `run` reports as `RunState::NeedsInput`.
+pub trait IntInput {
+    fn read(&mut self) -> Option<i64>;
+}
+
+/// Where a machine writes its output to.
+pub trait IntOutput {
+    fn write(&mut self, value: i64);
+}
+
+impl IntInput for VecDeque<i64> {
+    fn read(&mut self) -> Option<i64> {
+        self.pop_front()
+    }
+}
+
+impl IntOutput for VecDeque<i64> {
+    fn write(&mut self, value: i64) {
+        self.push_back(value);
+    }
+}
+
+impl IntOutput for Vec<i64> {
+    fn write(&mut self, value: i64) {
+        self.push(value);
+    }
+}
+
+/// Does not block: an empty channel is reported as missing input, so that the host can run
+/// other machines in the meantime.
+impl IntInput for Receiver<i64> {
+    fn read(&mut self) -> Option<i64> {
+        self.try_recv().ok()
+    }
+}
+
+/// Output sent after the receiver was dropped is discarded.
+impl IntOutput for Sender<i64> {
+    fn write(&mut self, value: i64) {
+        let _ = self.send(value);
+    }
+}
+
+impl<T: IntInput + ?Sized> IntInput for &mut T {
+    fn read(&mut self) -> Option<i64> {
+        (**self).read()
+    }
+}
+
+impl<T: IntOutput + ?Sized> IntOutput for &mut T {
+    fn write(&mut self, value: i64) {
+        (**self).write(value)
+    }
+}
+
+/// Input produced by a closure, see `input_fn`.
+#[derive(Clone)]
+pub struct InputFn<F>(F);
+
+/// Reads input by calling `f`, e.g. to compute a joystick position from the current state of a
+/// game only when the program asks for it.
+pub fn input_fn<F: FnMut() -> Option<i64>>(f: F) -> InputFn<F> {
+    InputFn(f)
+}
+
+impl<F: FnMut() -> Option<i64>> IntInput for InputFn<F> {
+    fn read(&mut self) -> Option<i64> {
+        (self.0)()
+    }
+}
+
+/// Output consumed by a closure, see `output_fn`.
+#[derive(Clone)]
+pub struct OutputFn<F>(F);
+
+/// Passes every output value to `f`.
+pub fn output_fn<F: FnMut(i64)>(f: F) -> OutputFn<F> {
+    OutputFn(f)
+}
+
+impl<F: FnMut(i64)> IntOutput for OutputFn<F> {
+    fn write(&mut self, value: i64) {
+        (self.0)(value)
+    }
+}
+
+/// Text input for programs that read ASCII codes.
+#[derive(Clone, Default)]
+pub struct AsciiInput {
+    pending: VecDeque<i64>,
+}
+
+impl AsciiInput {
+    pub fn new(text: &str) -> Self {
+        let mut input = Self::default();
+        input.push_str(text);
+        input
+    }
+
+    pub fn push_str(&mut self, text: &str) {
+        self.pending.extend(text.bytes().map(i64::from));
+    }
+
+    /// Appends `line` and the newline that ends it.
+    pub fn push_line(&mut self, line: &str) {
+        self.push_str(line);
+        self.pending.push_back(i64::from(b'\n'));
+    }
+}
+
+impl IntInput for AsciiInput {
+    fn read(&mut self) -> Option<i64> {
+        self.pending.pop_front()
+    }
+}
+
+/// Collects the output of programs that print ASCII codes. Values outside of the ASCII range,
+/// like the final answers of such programs, are kept apart in `values`.
+#[derive(Clone, Default)]
+pub struct AsciiOutput {
+    pub text: String,
+    pub values: Vec<i64>,
+}
+
+/// Separates values that were already output, e.g. `machine.output.iter().copied().collect()`.
+impl std::iter::FromIterator<i64> for AsciiOutput {
+    fn from_iter<T: IntoIterator<Item = i64>>(values: T) -> Self {
+        let mut output = Self::default();
+        for value in values {
+            output.write(value);
+        }
+        output
+    }
+}
+
+impl IntOutput for AsciiOutput {
+    fn write(&mut self, value: i64) {
+        match u8::try_from(value) {
+            Ok(byte) if byte.is_ascii() => self.text.push(char::from(byte)),
+            _ => self.values.push(value),
+        }
+    }
+}
@@ -1,194 +1,940 @@
-use std::collections::VecDeque;
+mod arithmetic;
+mod ascii;
+mod assembler;
+mod cache;
+mod debugger;
+mod disassembler;
+mod instruction;
+mod io;
+mod memory;
+mod network;
+mod profile;
+mod snapshot;
+mod trace;
 
+pub use arithmetic::Arithmetic;
+pub use assembler::assemble;
+pub use debugger::{Access, Debugger, Stop};
+pub use disassembler::{disassemble, Line, Listing};
+pub use instruction::{Instruction, Mode, Opcode, Parameter};
+pub use io::{
+    input_fn, output_fn, AsciiInput, AsciiOutput, InputFn, IntInput, IntOutput, OutputFn,
+};
+pub use memory::{MemoryLimits, MemoryStats, CONTIGUOUS_SIZE, PAGE_SIZE};
+pub use network::{Network, NetworkEvent, Schedule, Topology};
+pub use profile::Profile;
+pub use trace::{Event, Trace, TraceStep};
+
+use aoc_common::{ParseError, Span};
+use cache::{DecodeCache, Decoded};
+use memory::Pages;
+use num_bigint::BigInt;
+use std::collections::{BTreeMap, VecDeque};
+use std::fmt;
+
+/// Why a program could not be loaded or could not execute its next instruction. Runtime errors
+/// carry the address of the failing instruction, which is left unexecuted: the machine is
+/// unchanged and can be resumed, e.g. after adding the missing input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IntCodeError {
+    InvalidOpcode {
+        ip: usize,
+        opcode: i64,
+    },
+    /// `parameter` counts from 1.
+    InvalidParameterMode {
+        ip: usize,
+        parameter: usize,
+        mode: i64,
+    },
+    NegativeAddress {
+        ip: usize,
+        address: i64,
+    },
+    WriteInImmediateMode {
+        ip: usize,
+        parameter: usize,
+    },
+    InputStarvation {
+        ip: usize,
+    },
+    /// `address` is beyond the configured `MemoryLimits`.
+    MemoryLimit {
+        ip: usize,
+        address: usize,
+    },
+    /// A value does not fit in 64 bits, see `Arithmetic`.
+    Overflow {
+        ip: usize,
+    },
+    /// The program halted where the host expected an output.
+    MissingOutput {
+        ip: usize,
+    },
+    /// `position` is the byte offset of `token` in the program text.
+    Parse {
+        position: usize,
+        token: String,
+    },
+}
+
+impl fmt::Display for IntCodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            IntCodeError::InvalidOpcode { ip, opcode } => {
+                write!(f, "invalid opcode {} at address {}", opcode, ip)
+            }
+            IntCodeError::InvalidParameterMode {
+                ip,
+                parameter,
+                mode,
+            } => write!(
+                f,
+                "invalid mode {} of parameter {} at address {}",
+                mode, parameter, ip
+            ),
+            IntCodeError::NegativeAddress { ip, address } => {
+                write!(f, "negative address {} used at address {}", address, ip)
+            }
+            IntCodeError::WriteInImmediateMode { ip, parameter } => write!(
+                f,
+                "write to parameter {} in immediate mode at address {}",
+                parameter, ip
+            ),
+            IntCodeError::InputStarvation { ip } => {
+                write!(f, "no input available at address {}", ip)
+            }
+            IntCodeError::MemoryLimit { ip, address } => write!(
+                f,
+                "address {} beyond the memory limits used at address {}",
+                address, ip
+            ),
+            IntCodeError::Overflow { ip } => {
+                write!(f, "overflow of a 64-bit value at address {}", ip)
+            }
+            IntCodeError::MissingOutput { ip } => {
+                write!(f, "halt without output at address {}", ip)
+            }
+            IntCodeError::Parse { position, token } => {
+                write!(f, "invalid integer `{}` at byte {}", token, position)
+            }
+        }
+    }
+}
+
+impl std::error::Error for IntCodeError {}
+
+impl IntCodeError {
+    /// Points the error at the offending part of `program`, the text the machine was loaded
+    /// from. Runtime errors point at the initial value of the failing instruction's address.
+    pub fn to_parse_error(&self, program: &str) -> ParseError {
+        let source = Span::new(program);
+        match self {
+            IntCodeError::Parse { position, token } => source
+                .slice(*position, position + token.len())
+                .error("an integer"),
+            IntCodeError::InvalidOpcode { ip, .. }
+            | IntCodeError::InvalidParameterMode { ip, .. }
+            | IntCodeError::NegativeAddress { ip, .. }
+            | IntCodeError::WriteInImmediateMode { ip, .. }
+            | IntCodeError::InputStarvation { ip }
+            | IntCodeError::MemoryLimit { ip, .. }
+            | IntCodeError::Overflow { ip }
+            | IntCodeError::MissingOutput { ip } => source
+                .split(",")
+                .nth(*ip)
+                .unwrap_or_else(|| source.end())
+                .error(format!("an executable instruction ({})", self)),
+        }
+    }
+}
+
+/// Why `run` returned control to the host.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RunState {
+    /// The next instruction reads input but there is none. Add some and run again.
+    NeedsInput,
+    /// The value was just written to the output.
+    Output(i64),
+    Halted,
+}
+
+/// A machine reads from `input` and writes to `output`. By default these are a queue that the
+/// host fills with `add_input_signal` and a vector collecting all outputs; `with_input` and
+/// `with_output` plug in anything else that implements `IntInput` and `IntOutput`.
+///
+/// `data` holds the program and the memory after it up to `CONTIGUOUS_SIZE`; higher addresses
+/// are stored sparsely, see `peek` and `poke`. Executed instructions are cached in decoded
+/// form, see `with_decode_cache`, so all writes go through `poke`.
 #[derive(Clone)]
-pub struct IntCodeMachine {
-    pub data: Vec<i64>,
-    pub input_signals: VecDeque<i64>,
-    pub output_signals: Vec<i64>,
+pub struct IntCodeMachine<I = VecDeque<i64>, O = Vec<i64>> {
+    data: Vec<i64>,
+    pub input: I,
+    pub output: O,
     pub instruction_pointer: usize,
     pub relative_base_offset: i64,
     pub halted: bool,
-    pub default_input: Option<i64>,
+    last_output: Option<i64>,
+    output_count: usize,
+    trace: Option<Trace>,
+    profile: Option<Box<Profile>>,
+    pages: Pages,
+    limits: MemoryLimits,
+    cache: DecodeCache,
+    arithmetic: Arithmetic,
+    // Values that do not fit in 64 bits, see `Arithmetic::BigInt`
+    big: BTreeMap<usize, BigInt>,
+    // The last output if it does not fit in 64 bits
+    big_output: Option<BigInt>,
 }
 
 impl IntCodeMachine {
-    pub fn from_string(input: &str) -> Self {
-        let data = input.split(',').map(|s| s.parse().unwrap()).collect();
+    pub fn from_string(input: &str) -> Result<Self, IntCodeError> {
+        let mut data = Vec::new();
+        let mut position = 0;
+        for token in input.split(',') {
+            let value = token.parse().map_err(|_| IntCodeError::Parse {
+                position,
+                token: token.to_owned(),
+            })?;
+            data.push(value);
+            position += token.len() + 1;
+        }
+        Ok(Self::new(data))
+    }
+
+    /// A machine whose memory starts with `data`.
+    pub fn new(data: Vec<i64>) -> Self {
         Self {
             data,
-            input_signals: VecDeque::new(),
-            output_signals: Vec::new(),
+            input: VecDeque::new(),
+            output: Vec::new(),
             instruction_pointer: 0,
             relative_base_offset: 0,
             halted: false,
-            default_input: None,
+            last_output: None,
+            output_count: 0,
+            trace: None,
+            profile: None,
+            pages: Pages::default(),
+            limits: MemoryLimits::default(),
+            cache: DecodeCache::default(),
+            arithmetic: Arithmetic::default(),
+            big: BTreeMap::new(),
+            big_output: None,
         }
     }
+}
 
-    pub fn step(&mut self) {
-        let instruction = self.data[self.instruction_pointer];
-        match instruction % 100 {
-            1 => self.add(),
-            2 => self.mul(),
-            3 => self.input(),
-            4 => self.output(),
-            5 => self.jmp_true(),
-            6 => self.jmp_false(),
-            7 => self.less_than(),
-            8 => self.equals(),
-            9 => self.adjust_relative_base(),
-            99 => self.halted = true,
-            _ => panic!("invalid opcode"),
+impl<I, O> IntCodeMachine<I, O> {
+    pub fn with_input<J: IntInput>(self, input: J) -> IntCodeMachine<J, O> {
+        IntCodeMachine {
+            data: self.data,
+            input,
+            output: self.output,
+            instruction_pointer: self.instruction_pointer,
+            relative_base_offset: self.relative_base_offset,
+            halted: self.halted,
+            last_output: self.last_output,
+            output_count: self.output_count,
+            trace: self.trace,
+            profile: self.profile,
+            pages: self.pages,
+            limits: self.limits,
+            cache: self.cache,
+            arithmetic: self.arithmetic,
+            big: self.big,
+            big_output: self.big_output,
         }
     }
 
-    pub fn add_input_signal(&mut self, input_signal: i64) {
-        self.input_signals.push_back(input_signal);
+    pub fn with_output<P: IntOutput>(self, output: P) -> IntCodeMachine<I, P> {
+        IntCodeMachine {
+            data: self.data,
+            input: self.input,
+            output,
+            instruction_pointer: self.instruction_pointer,
+            relative_base_offset: self.relative_base_offset,
+            halted: self.halted,
+            last_output: self.last_output,
+            output_count: self.output_count,
+            trace: self.trace,
+            profile: self.profile,
+            pages: self.pages,
+            limits: self.limits,
+            cache: self.cache,
+            arithmetic: self.arithmetic,
+            big: self.big,
+            big_output: self.big_output,
+        }
     }
 
+    /// The value written most recently, whatever the output is.
     pub fn last_output_signal(&self) -> Option<i64> {
-        self.output_signals.last().cloned()
+        self.last_output
     }
 
-    pub fn run_until_halt(&mut self) {
-        while !self.halted {
-            self.step();
-        }
+    pub fn with_memory_limits(mut self, limits: MemoryLimits) -> Self {
+        self.limits = limits;
+        self
     }
 
-    pub fn run_until_output_or_halt(&mut self) {
-        let n = self.output_signals.len();
-        while !self.halted && self.output_signals.len() == n {
-            self.step();
+    /// Overflowing arithmetic fails by default.
+    pub fn with_arithmetic(mut self, arithmetic: Arithmetic) -> Self {
+        self.arithmetic = arithmetic;
+        self
+    }
+
+    /// Executed instructions are decoded once and kept until a write changes them. Disabling
+    /// this only makes sense to measure how much it helps.
+    pub fn with_decode_cache(mut self, enabled: bool) -> Self {
+        self.cache.enabled = enabled;
+        self.cache.clear();
+        self
+    }
+
+    pub fn memory_stats(&self) -> MemoryStats {
+        MemoryStats {
+            contiguous: self.data.len(),
+            pages: self.pages.starts(),
         }
     }
 
-    fn mode(&self, offset: usize) -> i64 {
-        let mut instruction = self.data[self.instruction_pointer];
-        instruction /= 100;
-        for _ in 1..offset {
-            instruction /= 10;
+    /// The program and the contiguous memory after it that was written so far.
+    pub fn data(&self) -> &[i64] {
+        &self.data
+    }
+
+    /// Reads any address. Memory that was never written reads as zero.
+    pub fn peek(&self, addr: usize) -> i64 {
+        match self.data.get(addr) {
+            Some(value) => *value,
+            None => self.pages.get(addr),
         }
-        instruction % 10
     }
 
-    fn read_value(&mut self, offset: usize) -> i64 {
-        let ip = self.instruction_pointer;
-        let mode = self.mode(offset);
-        if mode == 0 || mode == 2 {
-            let addr = if mode == 0 {
-                self.data[ip + offset]
-            } else {
-                self.data[ip + offset] + self.relative_base_offset
-            };
-            assert!(addr >= 0);
-            let addr = addr as usize;
+    /// Writes any address within the memory limits, growing the contiguous memory or allocating
+    /// a page as needed.
+    pub fn poke(&mut self, addr: usize, value: i64) -> Result<(), IntCodeError> {
+        if !self.big.is_empty() {
+            self.big.remove(&addr);
+        }
+        if addr < self.data.len() && self.limits.max_address.is_none() {
+            self.data[addr] = value;
+            self.cache.invalidate(addr);
+            return Ok(());
+        }
+        self.check_writable(addr)?;
+        if addr < self.data.len().max(CONTIGUOUS_SIZE) {
             if addr >= self.data.len() {
                 self.data.resize(addr + 1, 0);
             }
-            self.data[addr]
-        } else if mode == 1 {
-            self.data[ip + offset]
+            self.data[addr] = value;
+            self.cache.invalidate(addr);
         } else {
-            panic!("unknown mode");
+            self.pages.set(addr, value);
         }
+        Ok(())
     }
 
-    fn write_value(&mut self, new_value: i64, offset: usize) {
-        let ip = self.instruction_pointer;
-        let addr = if self.mode(offset) == 0 {
-            self.data[ip + offset]
-        } else if self.mode(offset) == 2 {
-            self.data[ip + offset] + self.relative_base_offset
-        } else {
-            panic!("unknown mode");
+    // Fails if writing to `addr` would exceed the memory limits
+    fn check_writable(&self, addr: usize) -> Result<(), IntCodeError> {
+        let contiguous = addr < self.data.len().max(CONTIGUOUS_SIZE);
+        if self.limits.max_address.is_some_and(|max| addr > max)
+            || !(contiguous || self.pages.can_set(addr, self.limits.max_pages))
+        {
+            return Err(IntCodeError::MemoryLimit {
+                ip: self.instruction_pointer,
+                address: addr,
+            });
+        }
+        Ok(())
+    }
+
+    /// Makes `step` record every executed instruction, discarding any earlier trace.
+    pub fn start_trace(&mut self) {
+        self.trace = Some(Trace::default());
+    }
+
+    /// The trace recorded since `start_trace`, if tracing is enabled.
+    pub fn trace(&self) -> Option<&Trace> {
+        self.trace.as_ref()
+    }
+
+    /// Stops tracing and returns what was recorded.
+    pub fn take_trace(&mut self) -> Option<Trace> {
+        self.trace.take()
+    }
+
+    /// Makes `step` count what the program does, discarding any earlier profile.
+    pub fn start_profile(&mut self) {
+        self.profile = Some(Box::default());
+    }
+
+    /// The profile recorded since `start_profile`, if profiling is enabled.
+    pub fn profile(&self) -> Option<&Profile> {
+        self.profile.as_deref()
+    }
+
+    /// Stops profiling and returns what was counted.
+    pub fn take_profile(&mut self) -> Option<Profile> {
+        self.profile.take().map(|profile| *profile)
+    }
+
+    /// The memory the instruction at the instruction pointer accesses, in parameter order.
+    /// Empty if the instruction cannot be decoded, since executing it fails anyway.
+    pub(crate) fn accesses(&self) -> Vec<(usize, Access)> {
+        let decoded = match Decoded::decode(|addr| self.peek(addr), self.instruction_pointer) {
+            Ok(decoded) => decoded,
+            Err(_) => return Vec::new(),
         };
-        assert!(addr >= 0);
-        let addr = addr as usize;
-        if addr >= self.data.len() {
-            self.data.resize(addr + 1, 0);
+        let write = decoded.opcode.write_parameter();
+        let mut result = Vec::new();
+        for i in 0..decoded.opcode.parameter_count() {
+            let address = match decoded.modes[i] {
+                Mode::Immediate => continue,
+                Mode::Position => decoded.values[i],
+                Mode::Relative => match decoded.values[i].checked_add(self.relative_base_offset) {
+                    Some(address) => address,
+                    None => continue,
+                },
+            };
+            if address >= 0 {
+                let access = if write == Some(i) {
+                    Access::Write
+                } else {
+                    Access::Read
+                };
+                result.push((address as usize, access));
+            }
         }
-        self.data[addr] = new_value;
+        result
     }
 
-    fn add(&mut self) {
-        let v1 = self.read_value(1);
-        let v2 = self.read_value(2);
-        let v = v1 + v2;
-        self.write_value(v, 3);
-        self.instruction_pointer += 4;
+    fn record(&mut self, event: Event) {
+        if let Some(step) = self.trace.as_mut().and_then(|trace| trace.steps.last_mut()) {
+            step.events.push(event);
+        }
     }
+}
 
-    fn mul(&mut self) {
-        let v1 = self.read_value(1);
-        let v2 = self.read_value(2);
-        let v = v1 * v2;
-        self.write_value(v, 3);
-        self.instruction_pointer += 4;
+impl<O> IntCodeMachine<VecDeque<i64>, O> {
+    pub fn add_input_signal(&mut self, input_signal: i64) {
+        self.input.push_back(input_signal);
     }
+}
 
-    fn input(&mut self) {
-        if self.input_signals.is_empty() && self.default_input.is_none() {
-            panic!("no available input signals")
+impl<O: IntOutput> IntCodeMachine<VecDeque<i64>, O> {
+    /// Reruns `trace` on this machine, which should be in the state the trace started from: its
+    /// inputs are queued and at most as many instructions as it has steps are executed, while a
+    /// new trace records what actually happens. Compare both with `Trace::first_divergence`.
+    pub fn replay(&mut self, trace: &Trace) -> Result<(), IntCodeError> {
+        self.input.extend(trace.inputs());
+        self.start_trace();
+        for _ in &trace.steps {
+            if self.halted {
+                break;
+            }
+            self.step()?;
         }
-        let v = if self.input_signals.is_empty() {
-            self.default_input.unwrap()
-        } else {
-            self.input_signals.pop_front().unwrap()
+        Ok(())
+    }
+}
+
+impl<I: IntInput, O: IntOutput> IntCodeMachine<I, O> {
+    /// Executes the next instruction, recording it if tracing is enabled and counting it if
+    /// profiling is.
+    pub fn step(&mut self) -> Result<(), IntCodeError> {
+        if self.trace.is_none() && self.profile.is_none() {
+            return self.execute();
+        }
+        let ip = self.instruction_pointer;
+        let opcode = Opcode::from_code(self.peek(ip) % 100);
+        if self.trace.is_some() {
+            let size = opcode.map_or(1, |opcode| 1 + opcode.parameter_count());
+            let code = (ip..ip + size).map(|addr| self.peek(addr)).collect();
+            if let Some(trace) = &mut self.trace {
+                trace.steps.push(TraceStep {
+                    ip,
+                    code,
+                    events: Vec::new(),
+                });
+            }
+        }
+        let accesses = match self.profile {
+            Some(_) => self.accesses(),
+            None => Vec::new(),
+        };
+
+        let result = self.execute();
+        if result.is_err() {
+            if let Some(trace) = &mut self.trace {
+                trace.steps.pop();
+            }
+        }
+        if let Some(profile) = &mut self.profile {
+            match (&result, opcode) {
+                (Ok(()), Some(opcode)) => profile.count(ip, opcode, &accesses),
+                (Err(IntCodeError::InputStarvation { .. }), _) => profile.input_waits += 1,
+                _ => (),
+            }
+        }
+        result
+    }
+
+    #[inline]
+    fn execute(&mut self) -> Result<(), IntCodeError> {
+        let ip = self.instruction_pointer;
+        let decoded = match self.cache.get(ip) {
+            Some(decoded) => decoded,
+            None => {
+                let decoded = Decoded::decode(|addr| self.peek(addr), ip)?;
+                if ip < self.data.len() {
+                    self.cache.insert(ip, decoded, self.data.len());
+                }
+                decoded
+            }
         };
-        self.write_value(v, 1);
-        self.instruction_pointer += 2;
+
+        if self.arithmetic == Arithmetic::BigInt && self.execute_big(&decoded)? {
+            return Ok(());
+        }
+        match decoded.opcode {
+            Opcode::Add | Opcode::Mul | Opcode::LessThan | Opcode::Equals => {
+                let a = self.read_parameter(&decoded, 0)?;
+                let b = self.read_parameter(&decoded, 1)?;
+                let addr = self.parameter_address(&decoded, 2)?;
+                let value = match decoded.opcode {
+                    Opcode::Add => self.arithmetic.add(a, b),
+                    Opcode::Mul => self.arithmetic.mul(a, b),
+                    Opcode::LessThan => Some(i64::from(a < b)),
+                    _ => Some(i64::from(a == b)),
+                }
+                .ok_or(IntCodeError::Overflow { ip })?;
+                self.write_value(value, addr)?;
+                self.instruction_pointer = ip + 4;
+            }
+            Opcode::Input => {
+                let addr = self.parameter_address(&decoded, 0)?;
+                // Checked before reading so that a failing instruction does not lose the input
+                self.check_writable(addr)?;
+                let value = self
+                    .input
+                    .read()
+                    .ok_or(IntCodeError::InputStarvation { ip })?;
+                self.record(Event::Input(value));
+                self.write_value(value, addr)?;
+                self.instruction_pointer = ip + 2;
+            }
+            Opcode::Output => {
+                let value = self.read_parameter(&decoded, 0)?;
+                self.write_output(value);
+                self.big_output = None;
+                self.instruction_pointer = ip + 2;
+            }
+            Opcode::JumpIfTrue | Opcode::JumpIfFalse => {
+                let condition = self.read_parameter(&decoded, 0)? != 0;
+                let target = self.read_parameter(&decoded, 1)?;
+                if condition == (decoded.opcode == Opcode::JumpIfTrue) {
+                    self.instruction_pointer = self.to_address(target)?;
+                } else {
+                    self.instruction_pointer = ip + 3;
+                }
+            }
+            Opcode::AdjustRelativeBase => {
+                let offset = self.read_parameter(&decoded, 0)?;
+                self.relative_base_offset = self
+                    .arithmetic
+                    .add(self.relative_base_offset, offset)
+                    .ok_or(IntCodeError::Overflow { ip })?;
+                self.instruction_pointer = ip + 2;
+            }
+            Opcode::Halt => self.halted = true,
+        }
+        Ok(())
     }
 
-    fn output(&mut self) {
-        let v = self.read_value(1);
-        self.output_signals.push(v);
-        self.instruction_pointer += 2;
+    /// Runs until the machine produces an output, needs input or halts.
+    pub fn run(&mut self) -> Result<RunState, IntCodeError> {
+        loop {
+            if let Some(state) = self.advance()? {
+                return Ok(state);
+            }
+        }
     }
 
-    fn jmp_true(&mut self) {
-        let v1 = self.read_value(1);
-        let v2 = self.read_value(2);
-        if v1 != 0 {
-            assert!(v2 >= 0);
-            let addr = v2 as usize;
-            self.instruction_pointer = addr;
-        } else {
-            self.instruction_pointer += 3;
+    /// Like `run`, but executes at most `max_steps` instructions. Returns `None` if the machine
+    /// is still running after them.
+    pub fn run_for(&mut self, max_steps: usize) -> Result<Option<RunState>, IntCodeError> {
+        for _ in 0..max_steps {
+            if let Some(state) = self.advance()? {
+                return Ok(Some(state));
+            }
         }
+        Ok(None)
     }
 
-    fn jmp_false(&mut self) {
-        let v1 = self.read_value(1);
-        let v2 = self.read_value(2);
-        if v1 == 0 {
-            assert!(v2 >= 0);
-            let addr = v2 as usize;
-            self.instruction_pointer = addr;
+    // Executes one instruction and returns the state if it makes `run` stop
+    fn advance(&mut self) -> Result<Option<RunState>, IntCodeError> {
+        if self.halted {
+            return Ok(Some(RunState::Halted));
+        }
+        let n = self.output_count;
+        match self.step() {
+            Err(IntCodeError::InputStarvation { .. }) => return Ok(Some(RunState::NeedsInput)),
+            result => result?,
+        }
+        if self.halted {
+            Ok(Some(RunState::Halted))
+        } else if self.output_count > n {
+            Ok(self.last_output.map(RunState::Output))
         } else {
-            self.instruction_pointer += 3;
+            Ok(None)
+        }
+    }
+
+    pub fn run_until_halt(&mut self) -> Result<(), IntCodeError> {
+        while !self.halted {
+            self.step()?;
+        }
+        Ok(())
+    }
+
+    pub fn run_until_output_or_halt(&mut self) -> Result<(), IntCodeError> {
+        let n = self.output_count;
+        while !self.halted && self.output_count == n {
+            self.step()?;
+        }
+        Ok(())
+    }
+
+    /// Runs until the next output and returns it. Halting first is an error.
+    pub fn next_output(&mut self) -> Result<i64, IntCodeError> {
+        self.run_until_output_or_halt()?;
+        match self.last_output {
+            Some(value) if !self.halted => Ok(value),
+            _ => Err(IntCodeError::MissingOutput {
+                ip: self.instruction_pointer,
+            }),
+        }
+    }
+
+    /// The last output after running until the program halted. Having none is an error.
+    pub fn final_output(&mut self) -> Result<i64, IntCodeError> {
+        self.run_until_halt()?;
+        self.last_output.ok_or(IntCodeError::MissingOutput {
+            ip: self.instruction_pointer,
+        })
+    }
+
+    fn to_address(&self, addr: i64) -> Result<usize, IntCodeError> {
+        if addr < 0 {
+            return Err(IntCodeError::NegativeAddress {
+                ip: self.instruction_pointer,
+                address: addr,
+            });
+        }
+        Ok(addr as usize)
+    }
+
+    // The address relative parameter `i` refers to, which may be negative
+    fn relative_address(&self, decoded: &Decoded, i: usize) -> Result<i64, IntCodeError> {
+        decoded.values[i]
+            .checked_add(self.relative_base_offset)
+            .ok_or(IntCodeError::Overflow {
+                ip: self.instruction_pointer,
+            })
+    }
+
+    // The address parameter `i` refers to, for parameters not in immediate mode
+    fn parameter_address(&self, decoded: &Decoded, i: usize) -> Result<usize, IntCodeError> {
+        let addr = match decoded.modes[i] {
+            Mode::Relative => self.relative_address(decoded, i)?,
+            _ => decoded.values[i],
+        };
+        let addr = self.to_address(addr)?;
+        if self.limits.max_address.is_some_and(|max| addr > max) {
+            return Err(IntCodeError::MemoryLimit {
+                ip: self.instruction_pointer,
+                address: addr,
+            });
+        }
+        Ok(addr)
+    }
+
+    #[inline]
+    fn read_parameter(&self, decoded: &Decoded, i: usize) -> Result<i64, IntCodeError> {
+        let addr = match decoded.modes[i] {
+            Mode::Immediate => return Ok(decoded.values[i]),
+            Mode::Position => decoded.values[i],
+            Mode::Relative => self.relative_address(decoded, i)?,
+        };
+        // Most reads are of the contiguous memory, without limits
+        match self.data.get(addr as usize) {
+            Some(value) if addr >= 0 && self.limits.max_address.is_none() => Ok(*value),
+            _ => self
+                .parameter_address(decoded, i)
+                .map(|addr| self.peek(addr)),
         }
     }
 
-    fn less_than(&mut self) {
-        let v1 = self.read_value(1);
-        let v2 = self.read_value(2);
-        let v = if v1 < v2 { 1 } else { 0 };
-        self.write_value(v, 3);
-        self.instruction_pointer += 4;
+    fn write_output(&mut self, value: i64) {
+        self.output.write(value);
+        self.record(Event::Output(value));
+        self.last_output = Some(value);
+        self.output_count += 1;
+    }
+
+    fn write_value(&mut self, new_value: i64, addr: usize) -> Result<(), IntCodeError> {
+        self.poke(addr, new_value)?;
+        if self.trace.is_some() {
+            self.record(Event::Write {
+                address: addr,
+                value: new_value,
+            });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Echoes its input until it reads a zero, then prints 1000
+    pub(crate) const ECHO: &str = "3,100,1006,100,10,4,100,1105,1,0,104,1000,99";
+
+    fn run(program: &str, inputs: &[i64]) -> Result<Vec<i64>, IntCodeError> {
+        let mut machine = IntCodeMachine::from_string(program)?;
+        machine.input.extend(inputs);
+        machine.run_until_halt()?;
+        Ok(machine.output)
+    }
+
+    #[test]
+    fn programs() {
+        assert_eq!(run("3,9,8,9,10,9,4,9,99,-1,8", &[8]), Ok(vec![1]));
+        assert_eq!(run("3,3,1107,-1,8,3,4,3,99", &[9]), Ok(vec![0]));
+        assert_eq!(
+            run("104,1125899906842624,99", &[]),
+            Ok(vec![1125899906842624])
+        );
+        assert_eq!(run("109,-1,204,5,99", &[]), Ok(vec![99]));
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            run("1,0,0,0,42", &[]),
+            Err(IntCodeError::InvalidOpcode { ip: 4, opcode: 42 })
+        );
+        assert_eq!(
+            run("301,0,0,0,99", &[]),
+            Err(IntCodeError::InvalidParameterMode {
+                ip: 0,
+                parameter: 1,
+                mode: 3
+            })
+        );
+        assert_eq!(
+            run("1,-1,0,0,99", &[]),
+            Err(IntCodeError::NegativeAddress { ip: 0, address: -1 })
+        );
+        assert_eq!(
+            run("10001,0,0,0,99", &[]),
+            Err(IntCodeError::WriteInImmediateMode {
+                ip: 0,
+                parameter: 3
+            })
+        );
+        assert_eq!(
+            run("1,0,0,0,3,0,99", &[]),
+            Err(IntCodeError::InputStarvation { ip: 4 })
+        );
+        assert_eq!(
+            run("109,9223372036854775807,204,1,99", &[]),
+            Err(IntCodeError::Overflow { ip: 2 })
+        );
+        assert_eq!(
+            run("109,-9223372036854775808,203,-1,99", &[5]),
+            Err(IntCodeError::Overflow { ip: 2 })
+        );
+        assert_eq!(
+            IntCodeMachine::from_string("1,0,x,0").err(),
+            Some(IntCodeError::Parse {
+                position: 4,
+                token: "x".to_owned()
+            })
+        );
+    }
+
+    #[test]
+    fn expected_outputs() {
+        let mut machine = IntCodeMachine::from_string("104,1,104,2,99").unwrap();
+        assert_eq!(machine.next_output(), Ok(1));
+        assert_eq!(machine.next_output(), Ok(2));
+        assert_eq!(
+            machine.next_output(),
+            Err(IntCodeError::MissingOutput { ip: 4 })
+        );
+        let mut machine = IntCodeMachine::from_string("104,1,104,2,99").unwrap();
+        assert_eq!(machine.final_output(), Ok(2));
+        let mut machine = IntCodeMachine::from_string("1,0,0,0,99").unwrap();
+        assert_eq!(
+            machine.final_output(),
+            Err(IntCodeError::MissingOutput { ip: 4 })
+        );
+    }
+
+    #[test]
+    fn resume_after_starvation() {
+        let mut machine = IntCodeMachine::from_string("3,7,4,7,99").unwrap();
+        assert!(machine.run_until_halt().is_err());
+        machine.add_input_signal(5);
+        assert_eq!(machine.run_until_halt(), Ok(()));
+        assert_eq!(machine.output, vec![5]);
+    }
+
+    #[test]
+    fn run_states() {
+        let mut machine = IntCodeMachine::from_string("3,11,1001,11,1,11,4,11,1105,1,0,0").unwrap();
+        assert_eq!(machine.run(), Ok(RunState::NeedsInput));
+        machine.add_input_signal(41);
+        assert_eq!(machine.run(), Ok(RunState::Output(42)));
+        assert_eq!(machine.run(), Ok(RunState::NeedsInput));
+        assert_eq!(machine.run_for(2), Ok(Some(RunState::NeedsInput)));
+        machine.add_input_signal(1);
+        assert_eq!(machine.run_for(2), Ok(None));
+        assert_eq!(machine.run_for(1), Ok(Some(RunState::Output(2))));
+
+        let mut machine = IntCodeMachine::from_string("104,7,99").unwrap();
+        assert_eq!(machine.run(), Ok(RunState::Output(7)));
+        assert_eq!(machine.run(), Ok(RunState::Halted));
+        assert_eq!(machine.run_for(5), Ok(Some(RunState::Halted)));
     }
 
-    fn equals(&mut self) {
-        let v1 = self.read_value(1);
-        let v2 = self.read_value(2);
-        let v = if v1 == v2 { 1 } else { 0 };
-        self.write_value(v, 3);
-        self.instruction_pointer += 4;
+    #[test]
+    fn plugged_io() {
+        let mut machine = IntCodeMachine::from_string(ECHO)
+            .unwrap()
+            .with_input(AsciiInput::new("hi\n\0"))
+            .with_output(AsciiOutput::default());
+        machine.run_until_halt().unwrap();
+        assert_eq!(machine.output.text, "hi\n");
+        assert_eq!(machine.output.values, vec![1000]);
+
+        let mut next = 0;
+        let mut sum = 0;
+        let mut machine = IntCodeMachine::from_string(ECHO)
+            .unwrap()
+            .with_input(input_fn(|| {
+                next = (next + 1) % 4;
+                Some(next)
+            }))
+            .with_output(output_fn(|value| sum += value));
+        machine.run_until_halt().unwrap();
+        drop(machine);
+        assert_eq!(sum, 1006);
+
+        let (sender, receiver) = std::sync::mpsc::channel();
+        let mut machine = IntCodeMachine::from_string(ECHO)
+            .unwrap()
+            .with_input(receiver);
+        assert_eq!(machine.run(), Ok(RunState::NeedsInput));
+        sender.send(5).unwrap();
+        assert_eq!(machine.run(), Ok(RunState::Output(5)));
     }
 
-    fn adjust_relative_base(&mut self) {
-        let delta = self.read_value(1);
-        self.relative_base_offset += delta;
-        self.instruction_pointer += 2;
+    #[test]
+    fn sparse_memory() {
+        let far = "1000000000000";
+        let program = format!("1101,1,2,{},4,{},99", far, far);
+        let mut machine = IntCodeMachine::from_string(&program).unwrap();
+        machine.run_until_halt().unwrap();
+        assert_eq!(machine.output, vec![3]);
+        let stats = machine.memory_stats();
+        assert_eq!(stats.contiguous, 7);
+        assert_eq!(stats.pages, vec![1_000_000_000_000 / PAGE_SIZE * PAGE_SIZE]);
+
+        let limits = MemoryLimits {
+            max_address: Some(1000),
+            max_pages: None,
+        };
+        let mut machine = IntCodeMachine::from_string(&program)
+            .unwrap()
+            .with_memory_limits(limits);
+        assert_eq!(
+            machine.run_until_halt(),
+            Err(IntCodeError::MemoryLimit {
+                ip: 0,
+                address: 1_000_000_000_000
+            })
+        );
+
+        // The input is not lost when writing it fails
+        let limits = MemoryLimits {
+            max_address: None,
+            max_pages: Some(0),
+        };
+        let mut machine = IntCodeMachine::from_string("3,1000000,99")
+            .unwrap()
+            .with_memory_limits(limits);
+        machine.add_input_signal(5);
+        assert!(machine.run_until_halt().is_err());
+        assert_eq!(machine.input, vec![5]);
+        assert_eq!(
+            machine.poke(1_000_000, 0),
+            Err(IntCodeError::MemoryLimit {
+                ip: 0,
+                address: 1_000_000
+            })
+        );
+        assert_eq!(machine.poke(100, 1), Ok(()));
+        assert_eq!(machine.memory_stats().contiguous, 101);
+    }
+
+    #[test]
+    fn self_modifying_code() {
+        // Increments the parameter of its own output instruction
+        let program = assemble(
+            "
+            loop: out #1
+                  add loop+1, #1, loop+1
+                  lt loop+1, #4, flag
+                  jt flag, #loop
+                  hlt
+            flag: data 0
+            ",
+        )
+        .unwrap();
+        for &cached in &[true, false] {
+            let mut machine = IntCodeMachine::new(program.clone()).with_decode_cache(cached);
+            machine.run_until_halt().unwrap();
+            assert_eq!(machine.output, vec![1, 2, 3]);
+        }
+
+        // Writes of the host replace cached instructions as well
+        let mut machine = IntCodeMachine::from_string("3,0,104,1,1105,1,0").unwrap();
+        machine.add_input_signal(3);
+        assert_eq!(machine.run(), Ok(RunState::Output(1)));
+        machine.poke(3, 2).unwrap();
+        machine.add_input_signal(3);
+        assert_eq!(machine.run(), Ok(RunState::Output(2)));
+    }
+
+    #[test]
+    fn located_errors() {
+        let program = "1,0,0,0,42";
+        let error = run(program, &[]).unwrap_err().to_parse_error(program);
+        assert_eq!((error.line, error.column), (1, 9));
+        assert_eq!(error.token, "42");
+
+        let program = "1,0,x,0";
+        let error = IntCodeMachine::from_string(program)
+            .err()
+            .unwrap()
+            .to_parse_error(program);
+        assert_eq!((error.line, error.column), (1, 5));
+        assert_eq!(error.expected, "an integer");
     }
 }
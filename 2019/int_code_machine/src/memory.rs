@@ -0,0 +1,73 @@
+use std::collections::BTreeMap;
+
+/// Addresses below this (or below the length of the loaded program, if that is longer) are
+/// kept in the contiguous `IntCodeMachine::data`, which grows as they are written.
+pub const CONTIGUOUS_SIZE: usize = 1 << 16;
+
+/// Higher addresses are stored in pages of this many values, allocated on the first write.
+pub const PAGE_SIZE: usize = 1 << 10;
+
+/// Bounds for the memory a program may use. Accessing an address above `max_address`, or
+/// writing to a new page when `max_pages` are in use, fails with `IntCodeError::MemoryLimit`.
+/// Both are unlimited by default.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MemoryLimits {
+    pub max_address: Option<usize>,
+    pub max_pages: Option<usize>,
+}
+
+/// How much memory a machine uses.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MemoryStats {
+    /// The length of the contiguous memory.
+    pub contiguous: usize,
+    /// The first address of every allocated page, in ascending order.
+    pub pages: Vec<usize>,
+}
+
+// The memory beyond the contiguous part
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Pages {
+    pages: BTreeMap<usize, Box<[i64]>>,
+}
+
+impl Pages {
+    pub(crate) fn get(&self, address: usize) -> i64 {
+        self.pages
+            .get(&(address / PAGE_SIZE))
+            .map_or(0, |page| page[address % PAGE_SIZE])
+    }
+
+    /// Whether `set` can write to `address` with at most `max_pages` allocated.
+    pub(crate) fn can_set(&self, address: usize, max_pages: Option<usize>) -> bool {
+        self.pages.contains_key(&(address / PAGE_SIZE))
+            || max_pages.is_none_or(|max| self.pages.len() < max)
+    }
+
+    /// Zeros are not worth allocating a page for.
+    pub(crate) fn set(&mut self, address: usize, value: i64) {
+        let index = address / PAGE_SIZE;
+        if value == 0 && !self.pages.contains_key(&index) {
+            return;
+        }
+        let page = self
+            .pages
+            .entry(index)
+            .or_insert_with(|| vec![0; PAGE_SIZE].into_boxed_slice());
+        page[address % PAGE_SIZE] = value;
+    }
+
+    pub(crate) fn starts(&self) -> Vec<usize> {
+        self.pages.keys().map(|index| index * PAGE_SIZE).collect()
+    }
+
+    /// The addresses and values of all non-zero values, in ascending order.
+    pub(crate) fn values(&self) -> impl Iterator<Item = (usize, i64)> + '_ {
+        self.pages.iter().flat_map(|(index, page)| {
+            page.iter()
+                .enumerate()
+                .filter(|(_, value)| **value != 0)
+                .map(move |(offset, value)| (index * PAGE_SIZE + offset, *value))
+        })
+    }
+}
@@ -0,0 +1,251 @@
+use crate::{IntCodeError, IntCodeMachine, RunState};
+
+/// How the outputs of the machines of a `Network` are routed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Topology {
+    /// Every machine's outputs are the next machine's inputs. The outputs of the last machine are
+    /// collected in `Network::output`.
+    Chain,
+    /// Like `Chain`, but the last machine feeds the first.
+    Ring,
+    /// Machines output packets of `size` values, an address followed by the payload, which is
+    /// sent to the machine at that index. Reading input never blocks: a machine without pending
+    /// input reads -1 and counts as idle until it sends or receives something.
+    Packets { size: usize },
+}
+
+/// How long a machine runs before the next one gets its turn.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Schedule {
+    /// Until it produces one output, needs input or halts.
+    RoundRobin,
+    /// Until it needs input or halts.
+    UntilBlocked,
+}
+
+/// Why `Network::run` returned.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum NetworkEvent {
+    /// A packet was sent to an address outside the network. `values` starts with the address.
+    Packet {
+        from: usize,
+        values: Vec<i64>,
+    },
+    /// All machines of a packet network are idle or halted.
+    Idle,
+    /// Machines wait for input that no running machine can send.
+    Deadlock,
+    Halted,
+}
+
+/// Machines connected by a `Topology` and run in turns. Inputs for the machines, like phase
+/// settings or network addresses, are added to them before running, and later with `send`.
+pub struct Network {
+    pub machines: Vec<IntCodeMachine>,
+    /// The outputs of the last machine of a chain.
+    pub output: Vec<i64>,
+    topology: Topology,
+    schedule: Schedule,
+    // The machine whose turn it is
+    current: usize,
+    // Whether a machine waits for input, or is idle in a packet network
+    blocked: Vec<bool>,
+    // Packets that are not complete yet
+    pending: Vec<Vec<i64>>,
+}
+
+impl Network {
+    pub fn new(machines: Vec<IntCodeMachine>, topology: Topology) -> Self {
+        let count = machines.len();
+        Self {
+            machines,
+            output: Vec::new(),
+            topology,
+            schedule: Schedule::RoundRobin,
+            current: 0,
+            blocked: vec![false; count],
+            pending: vec![Vec::new(); count],
+        }
+    }
+
+    pub fn with_schedule(mut self, schedule: Schedule) -> Self {
+        self.schedule = schedule;
+        self
+    }
+
+    /// Adds inputs to the machine at index `to`.
+    pub fn send(&mut self, to: usize, values: &[i64]) {
+        self.machines[to].input.extend(values);
+        self.blocked[to] = false;
+    }
+
+    /// Runs the machines in turns until the network halts, cannot continue or sends a packet
+    /// outside. Can be called again after adding inputs with `send`.
+    pub fn run(&mut self) -> Result<NetworkEvent, IntCodeError> {
+        let packets = matches!(self.topology, Topology::Packets { .. });
+        loop {
+            let halted = |i: usize| self.machines[i].halted;
+            let count = self.machines.len();
+            if (0..count).all(halted) {
+                return Ok(NetworkEvent::Halted);
+            }
+            if (0..count).all(|i| halted(i) || self.blocked[i]) {
+                return Ok(if packets {
+                    NetworkEvent::Idle
+                } else {
+                    NetworkEvent::Deadlock
+                });
+            }
+
+            let i = self.current;
+            if !self.blocked[i] || packets {
+                if let Some(event) = self.run_machine(i, packets)? {
+                    return Ok(event);
+                }
+            }
+            self.current = (i + 1) % count;
+        }
+    }
+
+    // Gives machine `i` its turn
+    fn run_machine(
+        &mut self,
+        i: usize,
+        packets: bool,
+    ) -> Result<Option<NetworkEvent>, IntCodeError> {
+        loop {
+            match self.machines[i].run()? {
+                RunState::Output(value) => {
+                    self.machines[i].output.clear();
+                    self.blocked[i] = false;
+                    let event = self.route(i, value);
+                    if event.is_some() {
+                        return Ok(event);
+                    }
+                    if self.schedule == Schedule::RoundRobin {
+                        return Ok(None);
+                    }
+                }
+                RunState::NeedsInput => {
+                    self.blocked[i] = true;
+                    if packets {
+                        self.machines[i].add_input_signal(-1);
+                    }
+                    return Ok(None);
+                }
+                RunState::Halted => return Ok(None),
+            }
+        }
+    }
+
+    fn route(&mut self, from: usize, value: i64) -> Option<NetworkEvent> {
+        let count = self.machines.len();
+        match self.topology {
+            Topology::Chain if from + 1 == count => self.output.push(value),
+            Topology::Chain | Topology::Ring => self.send((from + 1) % count, &[value]),
+            Topology::Packets { size } => {
+                self.pending[from].push(value);
+                if self.pending[from].len() < size {
+                    return None;
+                }
+                let values = std::mem::take(&mut self.pending[from]);
+                match values[0] {
+                    address if address >= 0 && (address as usize) < count => {
+                        self.send(address as usize, &values[1..])
+                    }
+                    _ => return Some(NetworkEvent::Packet { from, values }),
+                }
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assemble;
+
+    fn amplifiers(program: &str, phases: &[i64]) -> Vec<IntCodeMachine> {
+        let machine = IntCodeMachine::from_string(program).unwrap();
+        phases
+            .iter()
+            .map(|&phase| {
+                let mut amplifier = machine.clone();
+                amplifier.add_input_signal(phase);
+                amplifier
+            })
+            .collect()
+    }
+
+    #[test]
+    fn chains_and_rings() {
+        let program = "3,15,3,16,1002,16,10,16,1,16,15,15,4,15,99,0,0";
+        let mut network = Network::new(amplifiers(program, &[4, 3, 2, 1, 0]), Topology::Chain);
+        network.send(0, &[0]);
+        assert_eq!(network.run(), Ok(NetworkEvent::Halted));
+        assert_eq!(network.output, vec![43210]);
+
+        let program = "3,26,1001,26,-4,26,3,27,1002,27,2,27,1,27,26,27,4,27,1001,28,-1,28,\
+                       1005,28,6,99,0,0,5";
+        for &schedule in &[Schedule::RoundRobin, Schedule::UntilBlocked] {
+            let machines = amplifiers(program, &[9, 8, 7, 6, 5]);
+            let mut network = Network::new(machines, Topology::Ring).with_schedule(schedule);
+            network.send(0, &[0]);
+            assert_eq!(network.run(), Ok(NetworkEvent::Halted));
+            assert_eq!(network.machines[4].last_output_signal(), Some(139629729));
+        }
+
+        // Two machines that echo their input once, waiting for each other
+        let echo = IntCodeMachine::from_string("3,0,4,0,99").unwrap();
+        let mut network = Network::new(vec![echo.clone(), echo], Topology::Ring);
+        assert_eq!(network.run(), Ok(NetworkEvent::Deadlock));
+        network.send(1, &[7]);
+        assert_eq!(network.run(), Ok(NetworkEvent::Halted));
+        assert_eq!(network.machines[0].last_output_signal(), Some(7));
+    }
+
+    #[test]
+    fn packets() {
+        // Forwards every packet (x, y) as (x, y + 1) to the next address
+        let program = assemble(
+            "
+                  in addr
+            loop: in x
+                  eq x, #-1, t
+                  jt t, #loop
+                  in y
+                  add addr, #1, dest
+                  add y, #1, y
+                  out dest
+                  out x
+                  out y
+                  jt #1, #loop
+            addr: data 0
+            x:    data 0
+            y:    data 0
+            t:    data 0
+            dest: data 0
+            ",
+        )
+        .unwrap();
+        let machines = (0..2)
+            .map(|address| {
+                let mut machine = IntCodeMachine::new(program.clone());
+                machine.add_input_signal(address);
+                machine
+            })
+            .collect();
+        let mut network = Network::new(machines, Topology::Packets { size: 3 });
+        assert_eq!(network.run(), Ok(NetworkEvent::Idle));
+        network.send(0, &[5, 10]);
+        assert_eq!(
+            network.run(),
+            Ok(NetworkEvent::Packet {
+                from: 1,
+                values: vec![2, 5, 12]
+            })
+        );
+        assert_eq!(network.run(), Ok(NetworkEvent::Idle));
+    }
+}
@@ -0,0 +1,141 @@
+use crate::{Access, Listing, Opcode};
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// How many entries of each table the report shows.
+const HOT_SPOTS: usize = 10;
+
+/// What a machine did while profiling was enabled, see `IntCodeMachine::start_profile`.
+/// Displays as a report of the most frequent opcodes, instructions and memory accesses.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Profile {
+    /// Executed instructions by opcode.
+    pub opcodes: BTreeMap<Opcode, u64>,
+    /// Executed instructions by address.
+    pub instructions: BTreeMap<usize, u64>,
+    /// Parameters read from memory by address, not counting the instructions themselves.
+    pub reads: BTreeMap<usize, u64>,
+    pub writes: BTreeMap<usize, u64>,
+    /// How often the machine needed input that was not there yet.
+    pub input_waits: u64,
+}
+
+// The entries with the highest counts, lowest keys first among equal counts
+fn hottest<K: Copy>(counts: &BTreeMap<K, u64>) -> Vec<(K, u64)> {
+    let mut entries: Vec<_> = counts.iter().map(|(key, count)| (*key, *count)).collect();
+    entries.sort_by_key(|&(_, count)| Reverse(count));
+    entries.truncate(HOT_SPOTS);
+    entries
+}
+
+fn percentage(count: u64, total: u64) -> f64 {
+    100.0 * count as f64 / total.max(1) as f64
+}
+
+// A count in a column, left empty for zero
+fn column(count: u64) -> String {
+    match count {
+        0 => String::new(),
+        count => count.to_string(),
+    }
+}
+
+impl Profile {
+    pub(crate) fn count(&mut self, ip: usize, opcode: Opcode, accesses: &[(usize, Access)]) {
+        *self.opcodes.entry(opcode).or_insert(0) += 1;
+        *self.instructions.entry(ip).or_insert(0) += 1;
+        for &(address, access) in accesses {
+            let counts = match access {
+                Access::Read => &mut self.reads,
+                Access::Write => &mut self.writes,
+            };
+            *counts.entry(address).or_insert(0) += 1;
+        }
+    }
+
+    /// The number of executed instructions.
+    pub fn steps(&self) -> u64 {
+        self.opcodes.values().sum()
+    }
+
+    /// The listing with two columns in front: how often every instruction was executed, and
+    /// how often the values of every line were read or written. Empty columns mean never.
+    pub fn annotate(&self, listing: &Listing) -> String {
+        let mut result = format!("{:>10} {:>10}\n", "executed", "accessed");
+        for line in &listing.lines {
+            let addresses = line.addresses();
+            let executed = self.instructions.get(&addresses.start).cloned();
+            let accessed = addresses
+                .flat_map(|address| vec![self.reads.get(&address), self.writes.get(&address)])
+                .flatten()
+                .sum();
+            result += &format!(
+                "{:>10} {:>10} {}\n",
+                column(executed.unwrap_or(0)),
+                column(accessed),
+                line
+            );
+        }
+        result
+    }
+}
+
+impl fmt::Display for Profile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let steps = self.steps();
+        writeln!(f, "{} steps, {} input waits", steps, self.input_waits)?;
+        writeln!(f, "\nopcode   executions")?;
+        for (opcode, count) in hottest(&self.opcodes) {
+            let share = percentage(count, steps);
+            writeln!(f, "{:<6} {:>12} {:>6.1}%", opcode.mnemonic(), count, share)?;
+        }
+        writeln!(f, "\naddress  executions")?;
+        for (address, count) in hottest(&self.instructions) {
+            let share = percentage(count, steps);
+            writeln!(f, "{:>7} {:>12} {:>6.1}%", address, count, share)?;
+        }
+        for (title, counts) in &[("reads", &self.reads), ("writes", &self.writes)] {
+            writeln!(f, "\naddress  {:>10}", title)?;
+            for (address, count) in hottest(counts) {
+                writeln!(f, "{:>7} {:>12}", address, count)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{disassemble, IntCodeMachine};
+
+    #[test]
+    fn counting() {
+        // Counts down from its input, printing every value
+        let program = "3,12,4,12,1001,12,-1,12,1005,12,2,99,0";
+        let mut machine = IntCodeMachine::from_string(program).unwrap();
+        machine.start_profile();
+        assert!(machine.run().is_ok());
+        machine.add_input_signal(3);
+        machine.run_until_halt().unwrap();
+        let profile = machine.take_profile().unwrap();
+        assert_eq!(machine.output, vec![3, 2, 1]);
+
+        assert_eq!(profile.steps(), 11);
+        assert_eq!(profile.input_waits, 1);
+        let instructions: Vec<_> = profile.instructions.iter().map(|(a, c)| (*a, *c)).collect();
+        assert_eq!(instructions, vec![(0, 1), (2, 3), (4, 3), (8, 3), (11, 1)]);
+        assert_eq!(profile.reads.get(&12), Some(&9));
+        assert_eq!(profile.writes.get(&12), Some(&4));
+
+        let report = profile.to_string();
+        assert!(report.starts_with("11 steps, 1 input waits\n\nopcode   executions\nadd "));
+        assert!(report.contains("\n      2            3   27.3%\n"));
+
+        let annotated = profile.annotate(&disassemble(&machine.data));
+        let lines: Vec<_> = annotated.lines().collect();
+        assert_eq!(lines[0], "  executed   accessed");
+        assert!(lines[3].starts_with("         3                 4: add 12, #-1, 12"));
+        assert_eq!(lines[6], "                   13     12: data 0");
+    }
+}
@@ -0,0 +1,349 @@
+use crate::{IntCodeMachine, CONTIGUOUS_SIZE};
+use aoc_common::{ParseError, Span};
+use num_bigint::BigInt;
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+
+/// The first line of a snapshot is this followed by the version of the format, which changes
+/// whenever the format does. Version 1 had no `paged` field, version 2 none of the fields
+/// after it.
+const HEADER: &str = "intcode snapshot ";
+const VERSION: u32 = 3;
+
+const FIELDS: [&str; 11] = [
+    "ip",
+    "relative_base",
+    "halted",
+    "last_output",
+    "input",
+    "output",
+    "memory",
+    "paged",
+    "arithmetic",
+    "big",
+    "big_output",
+];
+
+fn join(values: impl IntoIterator<Item = i64>) -> String {
+    let values: Vec<_> = values.into_iter().map(|v| v.to_string()).collect();
+    values.join(",")
+}
+
+fn parse_list(value: Option<Span>) -> Result<Vec<i64>, ParseError> {
+    match value {
+        Some(value) => value.split(",").map(|v| v.parse("an integer")).collect(),
+        None => Ok(Vec::new()),
+    }
+}
+
+// The `address=value` pairs of the `paged` and `big` fields, with the values still unparsed
+fn parse_pairs(value: Option<Span>) -> Result<Vec<(Span, usize, Span)>, ParseError> {
+    let pairs = match value {
+        Some(value) => value.split(","),
+        None => return Ok(Vec::new()),
+    };
+    pairs
+        .map(|pair| {
+            let (address, value) = pair.split_once("=")?;
+            Ok((address, address.parse("an address")?, value))
+        })
+        .collect()
+}
+
+// The value of a field that cannot be empty
+fn required<'a>((name, value): (Span<'a>, Option<Span<'a>>)) -> Result<Span<'a>, ParseError> {
+    value.ok_or_else(|| name.end().error("a value"))
+}
+
+// Values that fit in 64 bits are stored as such, never as large values
+fn parse_big(value: Span) -> Result<BigInt, ParseError> {
+    let big: BigInt = value.parse("an integer")?;
+    match i64::try_from(&big) {
+        Ok(_) => Err(value.error("an integer that does not fit in 64 bits")),
+        Err(_) => Ok(big),
+    }
+}
+
+impl IntCodeMachine {
+    /// The complete state of the machine as text: a header line with the format version and
+    /// then one `name value` line per field, with lists of values separated by commas. Memory
+    /// beyond the contiguous part is listed as `address=value` pairs of the non-zero values, and
+    /// so are values that do not fit in 64 bits. Traces and memory limits are not included.
+    pub fn to_snapshot(&self) -> String {
+        let last_output = self
+            .last_output
+            .map_or_else(|| "none".to_owned(), |v| v.to_string());
+        let values = [
+            self.instruction_pointer.to_string(),
+            self.relative_base_offset.to_string(),
+            self.halted.to_string(),
+            last_output,
+            join(self.input.iter().cloned()),
+            join(self.output.iter().cloned()),
+            join(self.data.iter().cloned()),
+            self.pages
+                .values()
+                .map(|(address, value)| format!("{}={}", address, value))
+                .collect::<Vec<_>>()
+                .join(","),
+            self.arithmetic.to_string(),
+            self.big
+                .iter()
+                .map(|(address, value)| format!("{}={}", address, value))
+                .collect::<Vec<_>>()
+                .join(","),
+            self.big_output
+                .as_ref()
+                .map_or_else(String::new, BigInt::to_string),
+        ];
+        let mut result = format!("{}{}\n", HEADER, VERSION);
+        for (name, value) in FIELDS.iter().zip(&values) {
+            let line = format!("{} {}", name, value);
+            result += line.trim_end();
+            result += "\n";
+        }
+        result
+    }
+
+    /// Restores a machine saved with `to_snapshot`, also by an earlier version. Rejects unknown
+    /// versions of the format, unknown, repeated or missing fields, and states the machine
+    /// cannot be in.
+    pub fn from_snapshot(text: &str) -> Result<Self, ParseError> {
+        let source = Span::new(text);
+        let mut lines = source.lines();
+        let header = lines.next().unwrap_or(source);
+        let version = header
+            .trim()
+            .strip_prefix(HEADER)
+            .and_then(|version| version.parse::<u32>(""))
+            .ok()
+            .filter(|version| (1..=VERSION).contains(version))
+            .ok_or_else(|| header.error(format!("the header `{}{}`", HEADER, VERSION)))?;
+
+        let mut fields = BTreeMap::new();
+        for line in lines {
+            let mut tokens = line.split_whitespace();
+            let name = match tokens.next() {
+                Some(name) => name,
+                None => continue,
+            };
+            if !FIELDS.contains(&name.as_str()) {
+                return Err(name.error(format!("one of the fields {}", FIELDS.join(", "))));
+            }
+            let value = tokens.next();
+            tokens.end()?;
+            if fields.insert(name.as_str(), (name, value)).is_some() {
+                return Err(name.error("a field that is not set yet"));
+            }
+        }
+        let mut field = |name: &str| {
+            fields
+                .remove(name)
+                .ok_or_else(|| source.end().error(format!("the field `{}`", name)))
+        };
+        let ip = field("ip").and_then(required)?;
+        let relative_base = field("relative_base").and_then(required)?;
+        let halted = field("halted").and_then(required)?;
+        let last_output = field("last_output").and_then(required)?;
+        let input = parse_list(field("input")?.1)?;
+        let output = parse_list(field("output")?.1)?;
+        let memory = field("memory").and_then(required)?;
+        let paged = match version {
+            1 => Vec::new(),
+            _ => parse_pairs(field("paged")?.1)?,
+        };
+        let (arithmetic, big, big_output) = match version {
+            1 | 2 => Default::default(),
+            _ => (
+                Some(field("arithmetic").and_then(required)?),
+                parse_pairs(field("big")?.1)?,
+                field("big_output")?.1,
+            ),
+        };
+        if let Some((name, _)) = fields.values().next() {
+            return Err(name.error(format!("a field of version {}", version)));
+        }
+
+        let mut machine = IntCodeMachine::new(parse_list(Some(memory))?);
+        machine.instruction_pointer = ip.parse("an address")?;
+        if machine.instruction_pointer > machine.data.len() {
+            return Err(ip.error(format!("an address up to {}", machine.data.len())));
+        }
+        machine.relative_base_offset = relative_base.parse("an integer")?;
+        machine.halted = halted.parse("`true` or `false`")?;
+        machine.last_output = match last_output.as_str() {
+            "none" => None,
+            _ => Some(last_output.parse("an integer or `none`")?),
+        };
+        for (span, address, value) in paged {
+            if address < machine.data.len().max(CONTIGUOUS_SIZE) {
+                return Err(span.error("an address beyond the contiguous memory"));
+            }
+            machine.pages.set(address, value.parse("an integer")?);
+        }
+        if let Some(arithmetic) = arithmetic {
+            machine.arithmetic = arithmetic.parse("`checked`, `wrapping` or `big`")?;
+        }
+        for (_, address, value) in big {
+            machine.big.insert(address, parse_big(value)?);
+        }
+        if let Some(value) = big_output {
+            machine.big_output = Some(parse_big(value)?);
+        }
+        machine.input = input.into();
+        machine.output_count = output.len();
+        machine.output = output;
+        Ok(machine)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::ECHO;
+    use crate::{Arithmetic, RunState};
+
+    #[test]
+    fn round_trip() {
+        let mut machine = IntCodeMachine::from_string(ECHO).unwrap();
+        machine.relative_base_offset = -3;
+        machine.input.extend(&[7, 8]);
+        assert_eq!(machine.run(), Ok(RunState::Output(7)));
+        machine.poke(100_000, 5).unwrap();
+
+        let snapshot = machine.to_snapshot();
+        assert!(snapshot.ends_with("\npaged 100000=5\narithmetic checked\nbig\nbig_output\n"));
+        assert!(snapshot.starts_with(
+            "intcode snapshot 3
+ip 7
+relative_base -3
+halted false
+last_output 7
+input 8
+output 7
+memory 3,100,1006,"
+        ));
+        let mut restored = IntCodeMachine::from_snapshot(&snapshot).unwrap();
+        assert_eq!(restored.to_snapshot(), snapshot);
+        assert_eq!(restored.peek(100_000), 5);
+
+        restored.add_input_signal(0);
+        machine.add_input_signal(0);
+        restored.run_until_halt().unwrap();
+        machine.run_until_halt().unwrap();
+        assert_eq!(restored.to_snapshot(), machine.to_snapshot());
+        assert_eq!(restored.output, vec![7, 8, 1000]);
+    }
+
+    #[test]
+    fn large_values() {
+        // Outputs 2^64 and keeps it at address 9
+        let mut machine = IntCodeMachine::from_string("1102,4294967296,4294967296,9,4,9,99,0,0,0")
+            .unwrap()
+            .with_arithmetic(Arithmetic::BigInt);
+        assert_eq!(machine.run(), Ok(RunState::Output(0)));
+
+        let snapshot = machine.to_snapshot();
+        let big = BigInt::from(1) << 64;
+        assert!(snapshot.ends_with(&format!(
+            "\narithmetic big\nbig 9={}\nbig_output {}\n",
+            big, big
+        )));
+        let restored = IntCodeMachine::from_snapshot(&snapshot).unwrap();
+        assert_eq!(restored.to_snapshot(), snapshot);
+        assert_eq!(restored.peek_big(9), big);
+        assert_eq!(restored.last_output_big(), Some(big));
+    }
+
+    #[test]
+    fn validation() {
+        let snapshot = IntCodeMachine::from_string("104,5,99")
+            .unwrap()
+            .to_snapshot();
+        let error = |old: &str, new: &str| {
+            IntCodeMachine::from_snapshot(&snapshot.replace(old, new))
+                .err()
+                .map(|e| e.to_string())
+        };
+        assert_eq!(error("", ""), None);
+        assert_eq!(
+            error("snapshot 3", "snapshot 4"),
+            Some(
+                "line 1, column 1: expected the header `intcode snapshot 3`, \
+                 found `intcode snapshot 4`"
+                    .to_owned()
+            )
+        );
+        // Version 1 had no paged memory, version 2 no arithmetic mode and large values
+        assert_eq!(
+            error("snapshot 3\n", "snapshot 2\n"),
+            Some("line 10, column 1: expected a field of version 2, found `arithmetic`".to_owned())
+        );
+        let version_2 = snapshot
+            .replace("snapshot 3", "snapshot 2")
+            .replace("arithmetic checked\nbig\nbig_output\n", "");
+        assert!(IntCodeMachine::from_snapshot(&version_2).is_ok());
+        assert_eq!(
+            IntCodeMachine::from_snapshot(&version_2.replace("snapshot 2", "snapshot 1"))
+                .err()
+                .map(|e| e.to_string()),
+            Some("line 9, column 1: expected a field of version 1, found `paged`".to_owned())
+        );
+        let version_1 = version_2
+            .replace("snapshot 2", "snapshot 1")
+            .replace("paged\n", "");
+        assert!(IntCodeMachine::from_snapshot(&version_1).is_ok());
+        assert_eq!(
+            error("paged", "paged 5=1"),
+            Some(
+                "line 9, column 7: expected an address beyond the contiguous memory, found `5`"
+                    .to_owned()
+            )
+        );
+        assert_eq!(
+            error("ip 0", "ip 4"),
+            Some("line 2, column 4: expected an address up to 3, found `4`".to_owned())
+        );
+        assert_eq!(
+            error("halted false", "halted no"),
+            Some("line 4, column 8: expected `true` or `false`, found `no`".to_owned())
+        );
+        assert_eq!(
+            error("arithmetic checked", "arithmetic exact"),
+            Some(
+                "line 10, column 12: expected `checked`, `wrapping` or `big`, found `exact`"
+                    .to_owned()
+            )
+        );
+        assert_eq!(
+            error("big\n", "big 7=12\n"),
+            Some(
+                "line 11, column 7: expected an integer that does not fit in 64 bits, found `12`"
+                    .to_owned()
+            )
+        );
+        assert_eq!(
+            error("output\n", "output\noutput 1\n"),
+            Some(
+                "line 8, column 1: expected a field that is not set yet, found `output`".to_owned()
+            )
+        );
+        assert_eq!(
+            error("memory 104,5,99\n", ""),
+            Some("line 12, column 1: expected the field `memory`, found nothing".to_owned())
+        );
+        assert_eq!(
+            error("memory 104,5,99", "memory"),
+            Some("line 8, column 7: expected a value, found nothing".to_owned())
+        );
+        assert_eq!(
+            error("input", "inputs"),
+            Some(
+                "line 6, column 1: expected one of the fields ip, relative_base, halted, \
+                 last_output, input, output, memory, paged, arithmetic, big, big_output, \
+                 found `inputs`"
+                    .to_owned()
+            )
+        );
+    }
+}
@@ -0,0 +1,200 @@
+use aoc_common::{ParseError, Span};
+use std::fmt;
+
+/// The first line of a trace file.
+const HEADER: &str = "intcode trace 1";
+
+/// Something an instruction did besides moving the instruction pointer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Event {
+    Input(i64),
+    Output(i64),
+    Write { address: usize, value: i64 },
+}
+
+impl fmt::Display for Event {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Event::Input(value) => write!(f, "i{}", value),
+            Event::Output(value) => write!(f, "o{}", value),
+            Event::Write { address, value } => write!(f, "w{}={}", address, value),
+        }
+    }
+}
+
+/// One executed instruction: its address, its values in memory and what it did.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TraceStep {
+    pub ip: usize,
+    pub code: Vec<i64>,
+    pub events: Vec<Event>,
+}
+
+/// Displays as `ip code events`, e.g. `7 1001,10,-1,10 w10=2`.
+impl fmt::Display for TraceStep {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let code: Vec<_> = self.code.iter().map(i64::to_string).collect();
+        write!(f, "{} {}", self.ip, code.join(","))?;
+        for event in &self.events {
+            write!(f, " {}", event)?;
+        }
+        Ok(())
+    }
+}
+
+/// The instructions a machine executed while tracing was enabled, see
+/// `IntCodeMachine::start_trace`. Displays as the file format `parse` reads: a header line and
+/// then one line per step.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Trace {
+    pub steps: Vec<TraceStep>,
+}
+
+fn parse_event(token: Span) -> Result<Event, ParseError> {
+    if let Ok(value) = token.strip_prefix("i") {
+        value.parse("an input value").map(Event::Input)
+    } else if let Ok(value) = token.strip_prefix("o") {
+        value.parse("an output value").map(Event::Output)
+    } else if let Ok(write) = token.strip_prefix("w") {
+        let (address, value) = write.split_once("=")?;
+        Ok(Event::Write {
+            address: address.parse("an address")?,
+            value: value.parse("a value")?,
+        })
+    } else {
+        Err(token.error("an event like `i5`, `o5` or `w10=5`"))
+    }
+}
+
+fn parse_step(line: Span) -> Result<TraceStep, ParseError> {
+    let mut tokens = line.split_whitespace();
+    let ip = tokens.expect("an address")?.parse("an address")?;
+    let code = tokens
+        .expect("the instruction")?
+        .split(",")
+        .map(|value| value.parse("an integer"))
+        .collect::<Result<_, _>>()?;
+    let events = tokens.map(parse_event).collect::<Result<_, _>>()?;
+    Ok(TraceStep { ip, code, events })
+}
+
+impl Trace {
+    pub fn parse(text: &str) -> Result<Self, ParseError> {
+        let mut lines = Span::new(text).lines();
+        let header = lines.next().unwrap_or_else(|| Span::new(text));
+        if header.trim().as_str() != HEADER {
+            return Err(header.error(format!("the header `{}`", HEADER)));
+        }
+        let steps = lines
+            .filter(|line| !line.trim().is_empty())
+            .map(parse_step)
+            .collect::<Result<_, _>>()?;
+        Ok(Self { steps })
+    }
+
+    /// All input values in the order they were read.
+    pub fn inputs(&self) -> impl Iterator<Item = i64> + '_ {
+        self.steps
+            .iter()
+            .flat_map(|step| &step.events)
+            .filter_map(|event| match event {
+                Event::Input(value) => Some(*value),
+                _ => None,
+            })
+    }
+
+    /// The index of the first step that differs between the traces, or the length of the shorter
+    /// one if it is a prefix of the other. `None` if the traces are the same.
+    pub fn first_divergence(&self, other: &Trace) -> Option<usize> {
+        let index = self
+            .steps
+            .iter()
+            .zip(&other.steps)
+            .position(|(a, b)| a != b);
+        if index.is_none() && self.steps.len() == other.steps.len() {
+            None
+        } else {
+            Some(index.unwrap_or_else(|| self.steps.len().min(other.steps.len())))
+        }
+    }
+}
+
+impl fmt::Display for Trace {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{}", HEADER)?;
+        for step in &self.steps {
+            writeln!(f, "{}", step)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::ECHO;
+    use crate::IntCodeMachine;
+
+    fn traced_run(inputs: &[i64]) -> (IntCodeMachine, Trace) {
+        let mut machine = IntCodeMachine::from_string(ECHO).unwrap();
+        machine.input.extend(inputs);
+        machine.start_trace();
+        let _ = machine.run_until_halt();
+        let trace = machine.take_trace().unwrap();
+        (machine, trace)
+    }
+
+    #[test]
+    fn recording() {
+        let (_, trace) = traced_run(&[7, 0]);
+        assert_eq!(
+            trace.to_string(),
+            "intcode trace 1
+0 3,100 i7 w100=7
+2 1006,100,10
+5 4,100 o7
+7 1105,1,0
+0 3,100 i0 w100=0
+2 1006,100,10
+10 104,1000 o1000
+12 99
+"
+        );
+        assert_eq!(Trace::parse(&trace.to_string()), Ok(trace.clone()));
+        assert_eq!(trace.inputs().collect::<Vec<_>>(), vec![7, 0]);
+
+        // Starved and failing instructions are not recorded
+        let (machine, trace) = traced_run(&[7]);
+        assert_eq!(trace.steps.len(), 4);
+        assert_eq!(machine.instruction_pointer, 0);
+    }
+
+    #[test]
+    fn replay_and_divergence() {
+        let (_, trace) = traced_run(&[7, 0]);
+        let mut machine = IntCodeMachine::from_string(ECHO).unwrap();
+        assert_eq!(machine.replay(&trace), Ok(()));
+        assert_eq!(machine.take_trace().unwrap().first_divergence(&trace), None);
+
+        let (_, other) = traced_run(&[7, 5, 0]);
+        assert_eq!(trace.first_divergence(&other), Some(4));
+        let mut shorter = trace.clone();
+        shorter.steps.pop();
+        assert_eq!(trace.first_divergence(&shorter), Some(7));
+
+        let mut machine = IntCodeMachine::from_string(&ECHO.replace("1000", "999")).unwrap();
+        assert_eq!(machine.replay(&trace), Ok(()));
+        assert_eq!(
+            machine.take_trace().unwrap().first_divergence(&trace),
+            Some(6)
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        let error = Trace::parse("intcode trace 2\n").unwrap_err();
+        assert_eq!(error.expected, "the header `intcode trace 1`");
+        let error = Trace::parse("intcode trace 1\n0 3,100 x7\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 9));
+    }
+}
@@ -0,0 +1,62 @@
+#[macro_use]
+extern crate itertools;
+
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
+
+fn parse_input(input: &str) -> Result<Vec<usize>, ParseError> {
+    Span::new(input)
+        .split_whitespace()
+        .map(|s| s.parse("an expense"))
+        .collect()
+}
+
+fn compute_solution_part_one(input: &str) -> Result<usize, ParseError> {
+    let data = parse_input(input)?;
+    for (i, j) in iproduct!(0..data.len(), 0..data.len()) {
+        if j <= i {
+            continue;
+        }
+        if data[i] + data[j] == 2020 {
+            return Ok(data[i] * data[j]);
+        }
+    }
+    panic!("no combination adding to 2020 found in input");
+}
+
+fn compute_solution_part_two(input: &str) -> Result<usize, ParseError> {
+    let data = parse_input(input)?;
+    for (i, j, k) in iproduct!(0..data.len(), 0..data.len(), 0..data.len()) {
+        if j <= i || k <= j {
+            continue;
+        }
+        if data[i] + data[j] + data[k] == 2020 {
+            return Ok(data[i] * data[j] * data[k]);
+        }
+    }
+    panic!("no combination adding to 2020 found in input");
+}
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2020
+    }
+
+    fn day(&self) -> u8 {
+        1
+    }
+
+    fn parse(&self, input: &Input) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input.trimmed())?);
+        Ok(true)
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
+    }
+}
@@ -0,0 +1,104 @@
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
+
+#[derive(Debug)]
+struct PasswordListItem {
+    n_min_key: usize,
+    n_max_key: usize,
+    key: char,
+    password: String,
+}
+
+impl PasswordListItem {
+    fn new(n_min_key: usize, n_max_key: usize, key: char, password: String) -> Self {
+        Self {
+            n_min_key,
+            n_max_key,
+            key,
+            password,
+        }
+    }
+
+    fn is_valid_old_rule(&self) -> bool {
+        let count = self.password.chars().filter(|c| *c == self.key).count();
+        count >= self.n_min_key && count <= self.n_max_key
+    }
+
+    fn is_valid_new_rule(&self) -> bool {
+        let mut count = 0;
+        for &i in [self.n_min_key, self.n_max_key].iter() {
+            if self.password.chars().nth(i - 1) == Some(self.key) {
+                count += 1;
+            }
+        }
+        count == 1
+    }
+}
+
+fn parse_item(line: Span) -> Result<PasswordListItem, ParseError> {
+    let (policy, password) = line.split_once(": ")?;
+    let (range, key) = policy.split_once(" ")?;
+    let (n_min_key, n_max_key) = range.split_once("-")?;
+    let n_min_key = match n_min_key.parse("a number")? {
+        0 => return Err(n_min_key.error("a number of at least 1")),
+        n => n,
+    };
+    let mut chars = key.as_str().chars();
+    let key = match (chars.next(), chars.next()) {
+        (Some(c), None) => c,
+        _ => return Err(key.error("a single letter")),
+    };
+    Ok(PasswordListItem::new(
+        n_min_key,
+        n_max_key.parse("a number")?,
+        key,
+        password.as_str().to_owned(),
+    ))
+}
+
+fn parse_input(input: &str) -> Result<Vec<PasswordListItem>, ParseError> {
+    Span::new(input)
+        .lines()
+        .map(|l| parse_item(l.trim()))
+        .collect()
+}
+
+fn compute_solution_part_one(input: &str) -> Result<usize, ParseError> {
+    let password_list = parse_input(input)?;
+    Ok(password_list
+        .iter()
+        .filter(|p| p.is_valid_old_rule())
+        .count())
+}
+
+fn compute_solution_part_two(input: &str) -> Result<usize, ParseError> {
+    let password_list = parse_input(input)?;
+    Ok(password_list
+        .iter()
+        .filter(|p| p.is_valid_new_rule())
+        .count())
+}
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2020
+    }
+
+    fn day(&self) -> u8 {
+        2
+    }
+
+    fn parse(&self, input: &Input) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input.trimmed())?);
+        Ok(true)
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
+    }
+}
@@ -0,0 +1,73 @@
+use aoc_common::{Answer, Input, ParseError, Puzzle};
+use aoc_grid::Grid;
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+enum Square {
+    Open,
+    Tree,
+}
+
+/// The map repeats to the right, which `Grid::get_wrapping` takes care of.
+type Field = Grid<Square>;
+
+fn parse_input(input: &str) -> Result<Field, ParseError> {
+    Grid::parse(input, "`.` or `#`", |c| match c {
+        '.' => Some(Square::Open),
+        '#' => Some(Square::Tree),
+        _ => None,
+    })
+}
+
+fn count_trees(delta_i: usize, delta_j: usize, field: &Field) -> usize {
+    let mut i = 0;
+    let mut j = 0;
+    let mut result = 0;
+
+    while i < field.height() {
+        if *field.get_wrapping(i, j) == Square::Tree {
+            result += 1
+        }
+        i += delta_i;
+        j += delta_j;
+    }
+    result
+}
+
+fn compute_solution_part_one(input: &str) -> Result<usize, ParseError> {
+    let field = parse_input(input)?;
+    Ok(count_trees(1, 3, &field))
+}
+
+fn compute_solution_part_two(input: &str) -> Result<usize, ParseError> {
+    let field = parse_input(input)?;
+    let mut result = 1;
+    for &(delta_i, delta_j) in [(1, 1), (1, 3), (1, 5), (1, 7), (2, 1)].iter() {
+        result *= count_trees(delta_i, delta_j, &field);
+    }
+    Ok(result)
+}
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2020
+    }
+
+    fn day(&self) -> u8 {
+        3
+    }
+
+    fn parse(&self, input: &Input) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input.trimmed())?);
+        Ok(true)
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
+    }
+}
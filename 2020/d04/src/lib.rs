@@ -1,20 +1,10 @@
 #[macro_use]
 extern crate lazy_static;
 
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
 use regex::Regex;
 use std::collections::BTreeMap;
 
-fn read_file(filename: &str) -> String {
-    use std::fs::File;
-    use std::io::prelude::*;
-
-    let mut f = File::open(filename).expect("file not found");
-    let mut contents = String::new();
-    f.read_to_string(&mut contents)
-        .expect("something went wrong reading the file");
-    contents.trim().to_owned()
-}
-
 #[derive(Debug)]
 struct Passport {
     entries: BTreeMap<String, String>,
@@ -83,10 +73,10 @@ impl Passport {
         let field_value = field_value.unwrap();
         if let Some(cap) = RECM.captures_iter(field_value).next() {
             let parsed: usize = cap[1].parse().unwrap();
-            parsed >= 150 && parsed <= 193
+            (150..=193).contains(&parsed)
         } else if let Some(cap) = REIN.captures_iter(field_value).next() {
             let parsed: usize = cap[1].parse().unwrap();
-            parsed >= 59 && parsed <= 76
+            (59..=76).contains(&parsed)
         } else {
             false
         }
@@ -134,40 +124,61 @@ impl Passport {
     }
 }
 
-fn parse_input(input: &str) -> Vec<Passport> {
+fn parse_input(input: &str) -> Result<Vec<Passport>, ParseError> {
     let mut result = Vec::new();
-    for record in input.split("\n\n") {
+    for record in Span::new(input).blocks() {
         let mut passport = Passport::new();
         for entry in record.split_whitespace() {
-            let mut split_entry = entry.split(':');
-            let key = split_entry.next().unwrap();
-            let value = split_entry.next().unwrap();
-            passport.insert(key, value);
+            let (key, value) = entry.split_once(":")?;
+            passport.insert(key.as_str(), value.as_str());
         }
         result.push(passport);
     }
-    result
+    Ok(result)
+}
+
+fn compute_solution_part_one(input: &str) -> Result<usize, ParseError> {
+    let passports = parse_input(input)?;
+    Ok(passports.iter().filter(|p| p.has_all_entries()).count())
 }
 
-fn compute_solution_part_one(input: &str) -> usize {
-    let passports = parse_input(input);
-    passports.iter().filter(|p| p.has_all_entries()).count()
+fn compute_solution_part_two(input: &str) -> Result<usize, ParseError> {
+    let passports = parse_input(input)?;
+    Ok(passports.iter().filter(|p| p.has_valid_entries()).count())
 }
 
-fn compute_solution_part_two(input: &str) -> usize {
-    let passports = parse_input(input);
-    passports.iter().filter(|p| p.has_valid_entries()).count()
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2020
+    }
+
+    fn day(&self) -> u8 {
+        4
+    }
+
+    fn parse(&self, input: &Input) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input.trimmed())?);
+        Ok(true)
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
+    }
 }
 
-fn main() {
-    use std::env;
-    let args: Vec<String> = env::args().collect();
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    if args.len() != 2 {
-        println!("Usage: d04 <input filename>");
-    } else {
-        let input = read_file(&args[1]);
-        println!("solution 1 = {}", compute_solution_part_one(&input));
-        println!("solution 2 = {}", compute_solution_part_two(&input));
+    #[test]
+    fn parse_errors() {
+        let e = parse_input("ecl:gry pid:1\r\n\r\nbyr 1937").unwrap_err();
+        assert_eq!(e.to_string(), "line 3, column 1: expected `:`, found `byr`");
     }
 }
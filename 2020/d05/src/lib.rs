@@ -0,0 +1,82 @@
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
+use std::collections::BTreeSet;
+
+fn parse_input(input: &str) -> Result<Vec<(usize, usize)>, ParseError> {
+    Span::new(input)
+        .split_whitespace()
+        .map(|line| {
+            let mut row: usize = 0;
+            let mut col: usize = 0;
+            if line.as_str().len() != 10 {
+                return Err(line.error("7 times `F` or `B` followed by 3 times `L` or `R`"));
+            }
+            for (i, (span, c)) in line.chars().enumerate() {
+                match (i < 7, c) {
+                    (true, 'F') | (true, 'B') => row <<= 1,
+                    (false, 'R') | (false, 'L') => col <<= 1,
+                    (true, _) => return Err(span.error("`F` or `B`")),
+                    (false, _) => return Err(span.error("`L` or `R`")),
+                };
+                match c {
+                    'B' => row += 1,
+                    'R' => col += 1,
+                    _ => (),
+                };
+            }
+            Ok((row, col))
+        })
+        .collect()
+}
+
+fn boarding_pass_to_id(pass: &(usize, usize)) -> usize {
+    pass.0 * 8 + pass.1
+}
+
+fn compute_solution_part_one(input: &str) -> Result<usize, ParseError> {
+    let boarding_passes = parse_input(input)?;
+    Ok(boarding_passes
+        .iter()
+        .map(boarding_pass_to_id)
+        .max()
+        .unwrap())
+}
+
+fn compute_solution_part_two(input: &str) -> Result<usize, ParseError> {
+    let boarding_passes = parse_input(input)?;
+    let boarding_ids: BTreeSet<usize> = boarding_passes
+        .iter()
+        .filter(|(r, _)| *r != 0 && *r != 127)
+        .map(boarding_pass_to_id)
+        .collect();
+    for id in &boarding_ids {
+        if !boarding_ids.contains(&(id - 1)) && boarding_ids.contains(&(id - 2)) {
+            return Ok(id - 1);
+        }
+    }
+    panic!("no boarding pass found")
+}
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2020
+    }
+
+    fn day(&self) -> u8 {
+        5
+    }
+
+    fn parse(&self, input: &Input) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input.trimmed())?);
+        Ok(true)
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
+    }
+}
@@ -0,0 +1,69 @@
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
+use std::collections::BTreeSet;
+
+/// The answers of every person, grouped.
+fn parse_input(input: &str) -> Result<Vec<Vec<BTreeSet<char>>>, ParseError> {
+    Span::new(input)
+        .blocks()
+        .map(|group| {
+            group
+                .split_whitespace()
+                .map(|person| {
+                    person
+                        .chars()
+                        .map(|(span, c)| match c {
+                            'a'..='z' => Ok(c),
+                            _ => Err(span.error("a question from `a` to `z`")),
+                        })
+                        .collect()
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn compute_solution_part_one(input: &str) -> Result<usize, ParseError> {
+    let groups = parse_input(input)?;
+    Ok(groups
+        .iter()
+        .map(|group| {
+            let set: BTreeSet<_> = group.iter().flatten().collect();
+            set.len()
+        })
+        .sum())
+}
+
+fn compute_solution_part_two(input: &str) -> Result<usize, ParseError> {
+    let groups = parse_input(input)?;
+    Ok(groups
+        .iter()
+        .map(|group| {
+            let initial_set = group[0].clone();
+            let set = group
+                .iter()
+                .skip(1)
+                .fold(initial_set, |acc, s| acc.intersection(s).cloned().collect());
+            set.len()
+        })
+        .sum())
+}
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2020
+    }
+
+    fn day(&self) -> u8 {
+        6
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
+    }
+}
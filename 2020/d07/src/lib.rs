@@ -1,21 +1,9 @@
-use regex::Regex;
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
 use std::collections::{BTreeMap, BTreeSet};
 
-fn read_file(filename: &str) -> String {
-    use std::fs::File;
-    use std::io::prelude::*;
-
-    let mut f = File::open(filename).expect("file not found");
-    let mut contents = String::new();
-    f.read_to_string(&mut contents)
-        .expect("something went wrong reading the file");
-    contents.trim().to_owned()
-}
-
 #[derive(Debug)]
 struct BagInfo {
     id_from_name: BTreeMap<String, usize>,
-    name_from_id: BTreeMap<usize, String>,
     parents: BTreeMap<usize, Vec<usize>>,
     children: BTreeMap<usize, Vec<(usize, usize)>>,
 }
@@ -23,13 +11,11 @@ struct BagInfo {
 impl BagInfo {
     fn new(
         id_from_name: BTreeMap<String, usize>,
-        name_from_id: BTreeMap<usize, String>,
         parents: BTreeMap<usize, Vec<usize>>,
         children: BTreeMap<usize, Vec<(usize, usize)>>,
     ) -> Self {
         Self {
             id_from_name,
-            name_from_id,
             parents,
             children,
         }
@@ -39,8 +25,7 @@ impl BagInfo {
         let mut todo = vec![main_id];
         let mut done = BTreeSet::new();
         let mut result = 0;
-        while !todo.is_empty() {
-            let id = todo.pop().unwrap();
+        while let Some(id) = todo.pop() {
             if done.contains(&id) {
                 continue;
             }
@@ -70,41 +55,35 @@ impl BagInfo {
     }
 }
 
-fn parse_input(input: &str) -> BagInfo {
-    let re_parent = Regex::new(r"(.*?) bags contain").unwrap();
-    let re_children = Regex::new(r"(\d+?) (.*?) bag").unwrap();
-    //let re_no_children = Regex::new(r"no other bags").unwrap();
-
+fn parse_input(input: &str) -> Result<BagInfo, ParseError> {
     let mut next_id = 0;
     let mut id_from_name = BTreeMap::new();
-    let mut name_from_id = BTreeMap::new();
     let mut parents = BTreeMap::new();
     let mut children = BTreeMap::new();
-
     let mut add_name = |s: String| {
-        if !id_from_name.contains_key(&s) {
-            id_from_name.insert(s.clone(), next_id);
-            name_from_id.insert(next_id, s);
+        *id_from_name.entry(s).or_insert_with(|| {
             next_id += 1;
             next_id - 1
-        } else {
-            *id_from_name.get(&s).unwrap()
-        }
+        })
     };
 
-    for line in input.lines() {
-        let parent = re_parent.captures_iter(line).next().unwrap()[1].to_owned();
-        let parent_id = add_name(parent);
+    for line in Span::new(input).lines() {
+        let (parent, contents) = line.split_once(" bags contain ")?;
+        let parent_id = add_name(parent.as_str().to_owned());
         children.entry(parent_id).or_insert_with(Vec::new);
         parents.entry(parent_id).or_insert_with(Vec::new);
-
-        if line.contains("no other bags") {
+        let contents = contents.strip_suffix(".")?;
+        if contents.as_str() == "no other bags" {
             continue;
         }
-        for cap in re_children.captures_iter(line) {
-            let n = cap[1].parse().unwrap();
-            let child = cap[2].to_owned();
-            let child_id = add_name(child);
+        for content in contents.split(", ") {
+            let (n, child) = content.split_once(" ")?;
+            let n = n.parse("a number of bags")?;
+            let child = match child.strip_suffix(" bags") {
+                Ok(child) => child,
+                Err(_) => child.strip_suffix(" bag")?,
+            };
+            let child_id = add_name(child.as_str().to_owned());
             children.entry(child_id).or_insert_with(Vec::new);
             parents.entry(child_id).or_insert_with(Vec::new);
             parents.get_mut(&child_id).unwrap().push(parent_id);
@@ -112,30 +91,45 @@ fn parse_input(input: &str) -> BagInfo {
         }
     }
 
-    BagInfo::new(id_from_name, name_from_id, parents, children)
+    if !id_from_name.contains_key("shiny gold") {
+        return Err(Span::new(input).end().error("a rule about shiny gold bags"));
+    }
+    Ok(BagInfo::new(id_from_name, parents, children))
 }
 
-fn compute_solution_part_one(input: &str) -> usize {
-    let bag_info = parse_input(input);
+fn compute_solution_part_one(input: &str) -> Result<usize, ParseError> {
+    let bag_info = parse_input(input)?;
     let id = bag_info.id_from_name.get("shiny gold").unwrap();
-    bag_info.number_of_hulls(*id)
+    Ok(bag_info.number_of_hulls(*id))
 }
 
-fn compute_solution_part_two(input: &str) -> usize {
-    let bag_info = parse_input(input);
+fn compute_solution_part_two(input: &str) -> Result<usize, ParseError> {
+    let bag_info = parse_input(input)?;
     let id = bag_info.id_from_name.get("shiny gold").unwrap();
-    bag_info.bags_within(*id)
+    Ok(bag_info.bags_within(*id))
 }
 
-fn main() {
-    use std::env;
-    let args: Vec<String> = env::args().collect();
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2020
+    }
+
+    fn day(&self) -> u8 {
+        7
+    }
+
+    fn parse(&self, input: &Input) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input.trimmed())?);
+        Ok(true)
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
 
-    if args.len() != 2 {
-        println!("Usage: d07 <input filename>");
-    } else {
-        let input = read_file(&args[1]);
-        println!("solution 1 = {}", compute_solution_part_one(&input));
-        println!("solution 2 = {}", compute_solution_part_two(&input));
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
     }
 }
@@ -1,13 +1,4 @@
-fn read_file(filename: &str) -> String {
-    use std::fs::File;
-    use std::io::prelude::*;
-
-    let mut f = File::open(filename).expect("file not found");
-    let mut contents = String::new();
-    f.read_to_string(&mut contents)
-        .expect("something went wrong reading the file");
-    contents.trim().to_owned()
-}
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
 
 enum Instruction {
     Acc(i64),
@@ -23,31 +14,30 @@ struct HandheldProcessor {
 }
 
 impl HandheldProcessor {
-    fn from_str(input: &str) -> Self {
+    fn from_str(input: &str) -> Result<Self, ParseError> {
         let mut instructions = Vec::new();
-        for line in input.lines() {
+        for line in Span::new(input).lines() {
             let mut iter = line.split_whitespace();
-            let inst = iter.next().unwrap();
-            let v: i64 = iter.next().unwrap().parse().unwrap();
-            assert!(iter.next().is_none());
-
-            let instruction = match inst {
+            let inst = iter.expect("an instruction")?;
+            let v: i64 = iter.expect("an argument")?.parse("an integer")?;
+            iter.end()?;
+            let instruction = match inst.as_str() {
                 "acc" => Instruction::Acc(v),
                 "jmp" => Instruction::Jmp(v),
                 "nop" => Instruction::Nop(v),
-                _ => panic!("unknown instruction"),
+                _ => return Err(inst.error("`acc`, `jmp` or `nop`")),
             };
             instructions.push(instruction);
         }
 
         let n = instructions.len();
 
-        Self {
+        Ok(Self {
             instructions,
             visited_instructions: vec![false; n],
             instruction_pos: 0,
             accumulator: 0,
-        }
+        })
     }
 
     fn step(&mut self) {
@@ -79,17 +69,17 @@ impl HandheldProcessor {
     }
 }
 
-fn compute_solution_part_one(input: &str) -> i64 {
-    let mut processor = HandheldProcessor::from_str(input);
+fn compute_solution_part_one(input: &str) -> Result<i64, ParseError> {
+    let mut processor = HandheldProcessor::from_str(input)?;
     processor.run_until_loop_or_outside();
-    processor.accumulator
+    Ok(processor.accumulator)
 }
 
-fn compute_solution_part_two(input: &str) -> i64 {
-    let mut processor = HandheldProcessor::from_str(input);
-    let n = processor.instructions.len();
+fn compute_solution_part_two(input: &str) -> Result<i64, ParseError> {
+    let original = HandheldProcessor::from_str(input)?;
+    let n = original.instructions.len();
     for i in 0..n {
-        processor = HandheldProcessor::from_str(input);
+        let mut processor = HandheldProcessor::from_str(input)?;
         match processor.instructions[i] {
             Instruction::Acc(_) => continue,
             Instruction::Jmp(v) => {
@@ -101,21 +91,28 @@ fn compute_solution_part_two(input: &str) -> i64 {
         }
         processor.run_until_loop_or_outside();
         if processor.instruction_pos == (processor.instructions.len() as i64) {
-            return processor.accumulator;
+            return Ok(processor.accumulator);
         }
     }
     panic!("no suitable modification found")
 }
 
-fn main() {
-    use std::env;
-    let args: Vec<String> = env::args().collect();
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2020
+    }
+
+    fn day(&self) -> u8 {
+        8
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
 
-    if args.len() != 2 {
-        println!("Usage: d08 <input filename>");
-    } else {
-        let input = read_file(&args[1]);
-        println!("solution 1 = {}", compute_solution_part_one(&input));
-        println!("solution 2 = {}", compute_solution_part_two(&input));
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
     }
 }
@@ -0,0 +1,80 @@
+#[macro_use]
+extern crate itertools;
+
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
+
+fn parse_input(input: &str) -> Result<Vec<i64>, ParseError> {
+    Span::new(input)
+        .lines()
+        .map(|l| l.parse("an integer"))
+        .collect()
+}
+
+fn is_sum(pos: usize, vals: &[i64], offset: usize) -> bool {
+    for (i, j) in iproduct!((pos - offset)..pos, (pos - offset)..pos) {
+        if j >= i || vals[i] == vals[j] {
+            continue;
+        }
+        if vals[i] + vals[j] == vals[pos] {
+            return true;
+        }
+    }
+    false
+}
+
+fn compute_solution_part_one(input: &str) -> Result<i64, ParseError> {
+    let vals = parse_input(input)?;
+    for i in 25..vals.len() {
+        if !is_sum(i, &vals, 25) {
+            return Ok(vals[i]);
+        }
+    }
+    panic!("no invalid number found in cipher");
+}
+
+fn compute_solution_part_two(input: &str) -> Result<i64, ParseError> {
+    let vals = parse_input(input)?;
+    let number = compute_solution_part_one(input)?;
+    let iter_slice = |i, n_consecutives| vals.iter().skip(i).take(n_consecutives);
+
+    for i in 0..vals.len() {
+        let mut n_consecutives = 2;
+        loop {
+            let sum: i64 = iter_slice(i, n_consecutives).sum();
+            if sum < number {
+                n_consecutives += 1;
+            } else if sum == number {
+                return Ok(iter_slice(i, n_consecutives).min().unwrap()
+                    + iter_slice(i, n_consecutives).max().unwrap());
+            } else if sum > number {
+                break;
+            }
+        }
+    }
+    panic!("no weakness found in cipher");
+}
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2020
+    }
+
+    fn day(&self) -> u8 {
+        9
+    }
+
+    fn parse(&self, input: &Input) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input.trimmed())?);
+        Ok(true)
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
+    }
+}
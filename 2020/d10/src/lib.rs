@@ -0,0 +1,75 @@
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
+use std::collections::BTreeMap;
+
+fn parse_input(input: &str) -> Result<Vec<i64>, ParseError> {
+    let span = Span::new(input);
+    let mut result = span
+        .lines()
+        .map(|l| l.parse("a joltage rating"))
+        .collect::<Result<Vec<i64>, _>>()?;
+    if result.is_empty() {
+        return Err(span.error("a joltage rating"));
+    }
+    result.sort_unstable();
+    Ok(result)
+}
+
+fn compute_solution_part_one(input: &str) -> Result<i64, ParseError> {
+    let joltage_ratings = parse_input(input)?;
+    let mut n_one_difference = 0;
+    let mut n_three_difference = 0;
+    match joltage_ratings[0] {
+        1 => n_one_difference += 1,
+        3 => n_three_difference += 1,
+        _ => panic!("invalid lowest joltage"),
+    }
+    for difference in joltage_ratings.windows(2).map(|w| w[1] - w[0]) {
+        match difference {
+            1 => n_one_difference += 1,
+            3 => n_three_difference += 1,
+            _ => panic!("unexpected difference"),
+        };
+    }
+    Ok(n_one_difference * (n_three_difference + 1))
+}
+
+fn compute_solution_part_two(input: &str) -> Result<usize, ParseError> {
+    let joltage_ratings = parse_input(input)?;
+    let mut n_ways: BTreeMap<i64, usize> = BTreeMap::new();
+    n_ways.insert(0, 1);
+    for j in joltage_ratings.iter() {
+        let mut count = 0;
+        for diff in [3, 2, 1].iter() {
+            if n_ways.contains_key(&(j - diff)) {
+                count += n_ways.get(&(j - diff)).unwrap();
+            }
+        }
+        n_ways.insert(*j, count);
+    }
+    Ok(*n_ways.get(joltage_ratings.last().unwrap()).unwrap())
+}
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2020
+    }
+
+    fn day(&self) -> u8 {
+        10
+    }
+
+    fn parse(&self, input: &Input) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input.trimmed())?);
+        Ok(true)
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
+    }
+}
@@ -1,13 +1,5 @@
-fn read_file(filename: &str) -> String {
-    use std::fs::File;
-    use std::io::prelude::*;
-
-    let mut f = File::open(filename).expect("file not found");
-    let mut contents = String::new();
-    f.read_to_string(&mut contents)
-        .expect("something went wrong reading the file");
-    contents.trim().to_owned()
-}
+use aoc_common::{Answer, Input, ParseError, Puzzle};
+use aoc_grid::Grid;
 
 #[derive(Copy, Clone)]
 enum Direction {
@@ -35,57 +27,22 @@ enum Tile {
 }
 
 struct State {
-    width: usize,
-    height: usize,
-    tiles: Vec<Tile>,
+    tiles: Grid<Tile>,
 }
 
 impl State {
-    fn new(width: usize, height: usize, tiles: Vec<Tile>) -> Self {
-        Self {
-            width,
-            height,
-            tiles,
-        }
-    }
-
-    fn from_str(input: &str) -> Self {
-        let mut tiles = Vec::new();
-
-        let mut width = 0;
-        let mut height = 0;
-
-        for line in input.lines() {
-            height += 1;
-            width = 0;
-            for c in line.chars() {
-                width += 1;
-                let newtile = match c {
-                    '.' => Tile::Floor,
-                    'L' => Tile::Empty,
-                    '#' => Tile::Occupied,
-                    _ => panic!("unknown map tile"),
-                };
-                tiles.push(newtile);
-            }
-        }
-        assert_eq!(width * height, tiles.len());
-        Self::new(width, height, tiles)
-    }
-
-    fn index_to_ij(&self, index: usize) -> (usize, usize) {
-        let i = index / self.width;
-        let j = index % self.width;
-        (i, j)
-    }
-
-    fn get_tile(&self, i: usize, j: usize) -> Tile {
-        let index = i * self.width + j;
-        self.tiles[index]
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        let tiles = Grid::parse(input, "`.`, `L` or `#`", |c| match c {
+            '.' => Some(Tile::Floor),
+            'L' => Some(Tile::Empty),
+            '#' => Some(Tile::Occupied),
+            _ => None,
+        })?;
+        Ok(Self { tiles })
     }
 
     fn walk_from(&self, i: usize, j: usize, dir: Direction) -> Option<(usize, usize)> {
-        let (delta_i, delta_j): (i64, i64) = match dir {
+        let (delta_i, delta_j) = match dir {
             Direction::Right => (0, 1),
             Direction::UpRight => (-1, 1),
             Direction::Up => (-1, 0),
@@ -95,15 +52,7 @@ impl State {
             Direction::Down => (1, 0),
             Direction::DownRight => (1, 1),
         };
-        let width = self.width as i64;
-        let height = self.height as i64;
-        let i0 = (i as i64) + delta_i;
-        let j0 = (j as i64) + delta_j;
-        if i0 < 0 || i0 > height - 1 || j0 < 0 || j0 > width - 1 {
-            None
-        } else {
-            Some((i0 as usize, j0 as usize))
-        }
+        self.tiles.offset(i, j, delta_i, delta_j)
     }
 
     fn is_occupied_in_direction(
@@ -122,7 +71,7 @@ impl State {
             i = walk_result.unwrap().0;
             j = walk_result.unwrap().1;
 
-            match self.get_tile(i, j) {
+            match self.tiles[(i, j)] {
                 Tile::Occupied => return true,
                 Tile::Empty => return false,
                 Tile::Floor => (),
@@ -136,11 +85,10 @@ impl State {
 
     fn update(&mut self, range_type: RangeType) -> bool {
         let mut changed = false;
-        let mut new_tiles = vec![Tile::Floor; self.tiles.len()];
+        let mut new_tiles = self.tiles.clone();
 
-        for index in 0..self.tiles.len() {
+        for (i, j) in self.tiles.positions() {
             let mut occupied_count = 0;
-            let (i, j) = self.index_to_ij(index);
 
             for &dir in [
                 Direction::Right,
@@ -164,8 +112,8 @@ impl State {
                 RangeType::LongRange => 5,
             };
 
-            let current_tile = self.get_tile(i, j);
-            new_tiles[index] = if current_tile == Tile::Empty && occupied_count == 0 {
+            let current_tile = self.tiles[(i, j)];
+            new_tiles[(i, j)] = if current_tile == Tile::Empty && occupied_count == 0 {
                 changed = true;
                 Tile::Occupied
             } else if current_tile == Tile::Occupied && occupied_count >= threshold {
@@ -187,31 +135,41 @@ impl State {
     }
 
     fn count_occupied(&self) -> usize {
-        self.tiles.iter().filter(|t| **t == Tile::Occupied).count()
+        self.tiles
+            .values()
+            .filter(|t| **t == Tile::Occupied)
+            .count()
     }
 }
 
-fn compute_solution_part_one(input: &str) -> usize {
-    let mut state = State::from_str(input);
+fn compute_solution_part_one(input: &str) -> Result<usize, ParseError> {
+    let mut state = State::from_str(input)?;
     state.update_until_stable(RangeType::ShortRange);
-    state.count_occupied()
+    Ok(state.count_occupied())
 }
 
-fn compute_solution_part_two(input: &str) -> usize {
-    let mut state = State::from_str(input);
+fn compute_solution_part_two(input: &str) -> Result<usize, ParseError> {
+    let mut state = State::from_str(input)?;
     state.update_until_stable(RangeType::LongRange);
-    state.count_occupied()
+    Ok(state.count_occupied())
 }
 
-fn main() {
-    use std::env;
-    let args: Vec<String> = env::args().collect();
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2020
+    }
+
+    fn day(&self) -> u8 {
+        11
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
 
-    if args.len() != 2 {
-        println!("Usage: d11 <input filename>");
-    } else {
-        let input = read_file(&args[1]);
-        println!("solution 1 = {}", compute_solution_part_one(&input));
-        println!("solution 2 = {}", compute_solution_part_two(&input));
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
     }
 }
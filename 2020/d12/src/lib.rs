@@ -1,13 +1,4 @@
-fn read_file(filename: &str) -> String {
-    use std::fs::File;
-    use std::io::prelude::*;
-
-    let mut f = File::open(filename).expect("file not found");
-    let mut contents = String::new();
-    f.read_to_string(&mut contents)
-        .expect("something went wrong reading the file");
-    contents.trim().to_owned()
-}
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
 
 #[derive(Clone, Copy)]
 enum Direction {
@@ -134,10 +125,7 @@ impl Ship {
         self.direction = self.direction.rotated(times, turn_direction);
     }
 
-    fn parse_command_no_waypoint(&mut self, command: &str) {
-        let action = command.chars().next().unwrap();
-        let amount: i64 = command.chars().skip(1).collect::<String>().parse().unwrap();
-
+    fn apply_command_no_waypoint(&mut self, (action, amount): (char, i64)) {
         match action {
             'N' => self.move_to_direction(amount, Direction::North),
             'S' => self.move_to_direction(amount, Direction::South),
@@ -150,10 +138,7 @@ impl Ship {
         }
     }
 
-    fn parse_command_with_waypoint(&mut self, command: &str) {
-        let action = command.chars().next().unwrap();
-        let amount: i64 = command.chars().skip(1).collect::<String>().parse().unwrap();
-
+    fn apply_command_with_waypoint(&mut self, (action, amount): (char, i64)) {
         match action {
             'N' => self.waypoint.y += amount,
             'S' => self.waypoint.y -= amount,
@@ -180,31 +165,59 @@ impl Default for Ship {
     }
 }
 
-fn compute_solution_part_one(input: &str) -> usize {
+fn parse_input(input: &str) -> Result<Vec<(char, i64)>, ParseError> {
+    Span::new(input)
+        .lines()
+        .map(|line| {
+            let (action_span, action) = match line.chars().next() {
+                Some(first) => first,
+                None => return Err(line.error("a navigation instruction")),
+            };
+            let amount = line.slice(1, line.as_str().len());
+            let value: i64 = amount.parse("an integer")?;
+            match action {
+                'N' | 'S' | 'W' | 'E' | 'F' => (),
+                'L' | 'R' if value % 90 == 0 => (),
+                'L' | 'R' => return Err(amount.error("a multiple of 90 degrees")),
+                _ => return Err(action_span.error("`N`, `S`, `E`, `W`, `L`, `R` or `F`")),
+            }
+            Ok((action, value))
+        })
+        .collect()
+}
+
+fn compute_solution_part_one(input: &str) -> Result<usize, ParseError> {
     let mut ship = Ship::default();
-    for line in input.lines() {
-        ship.parse_command_no_waypoint(line);
+    for command in parse_input(input)? {
+        ship.apply_command_no_waypoint(command);
     }
-    ship.distance_to_origin()
+    Ok(ship.distance_to_origin())
 }
 
-fn compute_solution_part_two(input: &str) -> usize {
+fn compute_solution_part_two(input: &str) -> Result<usize, ParseError> {
     let mut ship = Ship::default();
-    for line in input.lines() {
-        ship.parse_command_with_waypoint(line);
+    for command in parse_input(input)? {
+        ship.apply_command_with_waypoint(command);
     }
-    ship.distance_to_origin()
+    Ok(ship.distance_to_origin())
 }
 
-fn main() {
-    use std::env;
-    let args: Vec<String> = env::args().collect();
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2020
+    }
+
+    fn day(&self) -> u8 {
+        12
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
 
-    if args.len() != 2 {
-        println!("Usage: d12 <input filename>");
-    } else {
-        let input = read_file(&args[1]);
-        println!("solution 1 = {}", compute_solution_part_one(&input));
-        println!("solution 2 = {}", compute_solution_part_two(&input));
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
     }
 }
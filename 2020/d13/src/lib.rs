@@ -0,0 +1,112 @@
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
+
+fn parse_input(input: &str) -> Result<(i64, Vec<Option<i64>>), ParseError> {
+    let mut lines = Span::new(input).lines();
+    let current_time = lines.expect("a timestamp")?.trim().parse("a timestamp")?;
+    let bus_ids = lines
+        .expect("a list of bus IDs")?
+        .trim()
+        .split(",")
+        .map(|id_str| {
+            if id_str.as_str() == "x" {
+                Ok(None)
+            } else {
+                match id_str.parse("a bus ID or `x`")? {
+                    0 => Err(id_str.error("a positive bus ID")),
+                    id => Ok(Some(id)),
+                }
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    lines.end()?;
+    if bus_ids.iter().all(Option::is_none) {
+        return Err(Span::new(input).error("at least one bus ID"));
+    }
+    Ok((current_time, bus_ids))
+}
+
+// https://rosettacode.org/wiki/Chinese_remainder_theorem#Rust
+fn egcd(a: i64, b: i64) -> (i64, i64, i64) {
+    if a == 0 {
+        (b, 0, 1)
+    } else {
+        let (g, x, y) = egcd(b % a, a);
+        (g, y - (b / a) * x, x)
+    }
+}
+
+fn mod_inv(x: i64, n: i64) -> Option<i64> {
+    let (g, x, _) = egcd(x, n);
+    if g == 1 {
+        Some((x % n + n) % n)
+    } else {
+        None
+    }
+}
+
+fn chinese_remainder(residues: &[i64], modulii: &[i64]) -> Option<i64> {
+    let prod = modulii.iter().product::<i64>();
+
+    let mut sum = 0;
+
+    for (&residue, &modulus) in residues.iter().zip(modulii) {
+        let p = prod / modulus;
+        sum += residue * mod_inv(p, modulus)? * p
+    }
+
+    Some(sum % prod)
+}
+
+fn compute_solution_part_one(input: &str) -> Result<i64, ParseError> {
+    let wait_time = |current_time: i64, bus_id: i64| bus_id - current_time % bus_id;
+    let (current_time, bus_ids) = parse_input(input)?;
+    let min_id = bus_ids
+        .iter()
+        .filter(|bus_id| bus_id.is_some())
+        .min_by_key(|bus_id| wait_time(current_time, bus_id.unwrap()))
+        .unwrap()
+        .unwrap();
+    Ok(wait_time(current_time, min_id) * min_id)
+}
+
+fn compute_solution_part_two(input: &str) -> Result<i64, ParseError> {
+    let (_, bus_ids) = parse_input(input)?;
+    let wait_times: Vec<i64> = bus_ids
+        .iter()
+        .enumerate()
+        .filter(|(_, bus_id)| bus_id.is_some())
+        .map(|(i, _)| i as i64)
+        .collect();
+    let bus_ids: Vec<i64> = bus_ids.iter().flatten().copied().collect();
+    let residues: Vec<i64> = wait_times
+        .iter()
+        .zip(bus_ids.iter())
+        .map(|(w, b)| b - w % b)
+        .collect();
+    Ok(chinese_remainder(&residues, &bus_ids).unwrap())
+}
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2020
+    }
+
+    fn day(&self) -> u8 {
+        13
+    }
+
+    fn parse(&self, input: &Input) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input.trimmed())?);
+        Ok(true)
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
+    }
+}
@@ -1,18 +1,34 @@
-#[macro_use]
-extern crate lazy_static;
-
-use regex::Regex;
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
 use std::collections::BTreeMap;
 
-fn read_file(filename: &str) -> String {
-    use std::fs::File;
-    use std::io::prelude::*;
+enum Instruction {
+    Mask(String),
+    Write(usize, u64),
+}
 
-    let mut f = File::open(filename).expect("file not found");
-    let mut contents = String::new();
-    f.read_to_string(&mut contents)
-        .expect("something went wrong reading the file");
-    contents.trim().to_owned()
+fn parse_input(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    Span::new(input)
+        .lines()
+        .map(|line| {
+            let (target, value) = line.split_once(" = ")?;
+            if target.as_str() == "mask" {
+                if value.as_str().len() != 36 {
+                    return Err(value.error("a mask of 36 bits"));
+                }
+                for (span, c) in value.chars() {
+                    if !matches!(c, '0' | '1' | 'X') {
+                        return Err(span.error("`0`, `1` or `X`"));
+                    }
+                }
+                Ok(Instruction::Mask(value.as_str().to_owned()))
+            } else {
+                let address = target.strip_prefix("mem[")?.strip_suffix("]")?;
+                let address = address.parse("a memory address")?;
+                let value = value.parse("a 36 bit value")?;
+                Ok(Instruction::Write(address, value))
+            }
+        })
+        .collect()
 }
 
 struct BitMaskSystem {
@@ -64,27 +80,15 @@ impl BitMaskSystem {
         self.mask_or = mask_or;
     }
 
-    fn parse_instruction(&mut self, instruction: &str) {
-        lazy_static! {
-            static ref RE_MASK: Regex = Regex::new(r"mask = ([01X]*)").unwrap();
-            static ref RE_VALUE: Regex = Regex::new(r"mem\[(\d*)\] = (\d*)").unwrap();
-        }
-
-        if RE_MASK.is_match(instruction) {
-            let cap = RE_MASK.captures_iter(instruction).next().unwrap();
-            self.write_mask(&cap[1]);
-        } else if RE_VALUE.is_match(instruction) {
-            let cap = RE_VALUE.captures_iter(instruction).next().unwrap();
-            let address = cap[1].parse().unwrap();
-            let value = cap[2].parse().unwrap();
-            self.write_memory(address, value);
-        } else {
-            panic!("unknown instruction")
+    fn execute(&mut self, instruction: &Instruction) {
+        match instruction {
+            Instruction::Mask(mask) => self.write_mask(mask),
+            Instruction::Write(address, value) => self.write_memory(*address, *value),
         }
     }
 
     fn sum_of_memory(&self) -> u64 {
-        self.memory.iter().map(|(_, v)| v).sum()
+        self.memory.values().sum()
     }
 }
 
@@ -115,7 +119,7 @@ impl BitMaskSystemV2 {
 
         for x in 0..2u64.pow(n_x) {
             let mut address = address.rotate_right(36) as u64;
-            let mut x = x.rotate_right(n_x) as u64;
+            let mut x = x.rotate_right(n_x);
             let mut real_address: u64 = 0;
             for c in self.mask_template.chars() {
                 real_address <<= 1;
@@ -144,27 +148,15 @@ impl BitMaskSystemV2 {
         self.mask_template = mask.to_string();
     }
 
-    fn parse_instruction(&mut self, instruction: &str) {
-        lazy_static! {
-            static ref RE_MASK: Regex = Regex::new(r"mask = ([01X]*)").unwrap();
-            static ref RE_VALUE: Regex = Regex::new(r"mem\[(\d*)\] = (\d*)").unwrap();
-        }
-
-        if RE_MASK.is_match(instruction) {
-            let cap = RE_MASK.captures_iter(instruction).next().unwrap();
-            self.write_mask(&cap[1]);
-        } else if RE_VALUE.is_match(instruction) {
-            let cap = RE_VALUE.captures_iter(instruction).next().unwrap();
-            let address = cap[1].parse().unwrap();
-            let value = cap[2].parse().unwrap();
-            self.write_memory(address, value);
-        } else {
-            panic!("unknown instruction")
+    fn execute(&mut self, instruction: &Instruction) {
+        match instruction {
+            Instruction::Mask(mask) => self.write_mask(mask),
+            Instruction::Write(address, value) => self.write_memory(*address, *value),
         }
     }
 
     fn sum_of_memory(&self) -> u64 {
-        self.memory.iter().map(|(_, v)| v).sum()
+        self.memory.values().sum()
     }
 }
 
@@ -174,31 +166,38 @@ impl Default for BitMaskSystemV2 {
     }
 }
 
-fn compute_solution_part_one(input: &str) -> u64 {
+fn compute_solution_part_one(input: &str) -> Result<u64, ParseError> {
     let mut bit_mask_system = BitMaskSystem::default();
-    for line in input.lines() {
-        bit_mask_system.parse_instruction(line);
+    for instruction in parse_input(input)?.iter() {
+        bit_mask_system.execute(instruction);
     }
-    bit_mask_system.sum_of_memory()
+    Ok(bit_mask_system.sum_of_memory())
 }
 
-fn compute_solution_part_two(input: &str) -> u64 {
+fn compute_solution_part_two(input: &str) -> Result<u64, ParseError> {
     let mut bit_mask_system = BitMaskSystemV2::default();
-    for line in input.lines() {
-        bit_mask_system.parse_instruction(line);
+    for instruction in parse_input(input)?.iter() {
+        bit_mask_system.execute(instruction);
     }
-    bit_mask_system.sum_of_memory()
+    Ok(bit_mask_system.sum_of_memory())
 }
 
-fn main() {
-    use std::env;
-    let args: Vec<String> = env::args().collect();
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2020
+    }
+
+    fn day(&self) -> u8 {
+        14
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
 
-    if args.len() != 2 {
-        println!("Usage: d14 <input filename>");
-    } else {
-        let input = read_file(&args[1]);
-        println!("solution 1 = {}", compute_solution_part_one(&input));
-        println!("solution 2 = {}", compute_solution_part_two(&input));
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
     }
 }
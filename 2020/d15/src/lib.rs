@@ -0,0 +1,56 @@
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
+use std::collections::BTreeMap;
+
+fn parse_input(input: &str) -> Result<BTreeMap<usize, usize>, ParseError> {
+    let mut result = BTreeMap::new();
+    for (i, number_str) in Span::new(input).split(",").enumerate() {
+        result.insert(number_str.parse("a starting number")?, i + 1);
+    }
+    Ok(result)
+}
+
+fn play_game_until(max_turn: usize, input: &str) -> Result<usize, ParseError> {
+    let mut numbers = parse_input(input)?;
+    let (last_number, max_input_turn) = numbers.iter().max_by_key(|(_, v)| *v).unwrap();
+    let mut last_number = *last_number;
+
+    for turn in (max_input_turn + 1)..=max_turn {
+        let next_number = numbers.get(&last_number).map(|l| turn - 1 - l).unwrap_or(0);
+        numbers.insert(last_number, turn - 1);
+        last_number = next_number;
+    }
+    Ok(last_number)
+}
+
+fn compute_solution_part_one(input: &str) -> Result<usize, ParseError> {
+    play_game_until(2020, input)
+}
+
+fn compute_solution_part_two(input: &str) -> Result<usize, ParseError> {
+    play_game_until(30000000, input)
+}
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2020
+    }
+
+    fn day(&self) -> u8 {
+        15
+    }
+
+    fn parse(&self, input: &Input) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input.trimmed())?);
+        Ok(true)
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
+    }
+}
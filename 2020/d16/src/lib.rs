@@ -1,20 +1,9 @@
 #[macro_use]
 extern crate itertools;
 
-use regex::Regex;
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
 use std::collections::{HashMap, HashSet};
 
-fn read_file(filename: &str) -> String {
-    use std::fs::File;
-    use std::io::prelude::*;
-
-    let mut f = File::open(filename).expect("file not found");
-    let mut contents = String::new();
-    f.read_to_string(&mut contents)
-        .expect("something went wrong reading the file");
-    contents.trim().to_owned()
-}
-
 struct TicketInfo {
     field_descriptors: HashMap<String, Vec<(usize, usize)>>,
     my_ticket: Vec<usize>,
@@ -35,7 +24,7 @@ impl TicketInfo {
     }
 
     fn in_any_range(&self, value: usize) -> bool {
-        for range in self.field_descriptors.iter().flat_map(|(_, v)| v.iter()) {
+        for range in self.field_descriptors.values().flat_map(|v| v.iter()) {
             if value >= range.0 && value <= range.1 {
                 return true;
             }
@@ -73,86 +62,74 @@ impl Default for TicketInfo {
     }
 }
 
-fn parse_input(input: &str) -> TicketInfo {
-    let mut ticket_info = TicketInfo::default();
+fn parse_ticket(line: Span) -> Result<Vec<usize>, ParseError> {
+    line.split(",").map(|n| n.parse("a ticket value")).collect()
+}
 
-    let re_field_name = Regex::new(r"(.*):").unwrap();
-    let re_ranges = Regex::new(r"(\d*)-(\d*)").unwrap();
-    for line in input.lines() {
-        if line.contains("your ticket") {
-            break;
-        }
-        if !re_field_name.is_match(line) {
-            continue;
-        }
-        let field_name = re_field_name.captures_iter(line).next().unwrap()[1].to_string();
-        let mut ranges = Vec::new();
-        for cap in re_ranges.captures_iter(line) {
-            let lower: usize = cap[1].parse().unwrap();
-            let upper: usize = cap[2].parse().unwrap();
-            ranges.push((lower, upper));
-        }
-        ticket_info.field_descriptors.insert(field_name, ranges);
+fn parse_input(input: &str) -> Result<TicketInfo, ParseError> {
+    let mut ticket_info = TicketInfo::default();
+    let mut blocks = Span::new(input).blocks();
+
+    for line in blocks.expect("the ticket fields")?.lines() {
+        let (field_name, ranges) = line.split_once(": ")?;
+        let mut parsed_ranges = Vec::new();
+        for range in ranges.split(" or ") {
+            let (lower, upper) = range.split_once("-")?;
+            let lower: usize = lower.parse("a lower bound")?;
+            let upper: usize = upper.parse("an upper bound")?;
+            parsed_ranges.push((lower, upper));
+        }
+        let field_name = field_name.as_str().to_string();
+        ticket_info
+            .field_descriptors
+            .insert(field_name, parsed_ranges);
     }
 
-    let mut read = false;
-    for line in input.lines() {
-        if line.contains("your ticket") {
-            read = true;
-            continue;
-        }
-        if !read {
-            continue;
-        }
-        for n in line.split(',') {
-            ticket_info.my_ticket.push(n.parse().unwrap());
+    let mut lines = blocks.expect("your ticket")?.lines();
+    lines
+        .expect("`your ticket:`")?
+        .strip_prefix("your ticket:")?;
+    ticket_info.my_ticket = parse_ticket(lines.expect("your ticket")?)?;
+    lines.end()?;
+
+    let mut lines = blocks.expect("the nearby tickets")?.lines();
+    lines
+        .expect("`nearby tickets:`")?
+        .strip_prefix("nearby tickets:")?;
+    let n = ticket_info.my_ticket.len();
+    for line in lines {
+        let ticket = parse_ticket(line)?;
+        if ticket.len() != n {
+            return Err(line.error(format!("a ticket of {} values", n)));
         }
-        break;
+        ticket_info.other_tickets.push(ticket);
     }
+    blocks.end()?;
 
-    let mut read = false;
-    for line in input.lines() {
-        if line.contains("nearby tickets") {
-            read = true;
-            continue;
-        }
-        if !read {
-            continue;
-        }
-        let mut new_ticket = Vec::new();
-        for n in line.split(',') {
-            new_ticket.push(n.parse().unwrap());
-        }
-        ticket_info.other_tickets.push(new_ticket);
-    }
-    let n = ticket_info.my_ticket.len();
-    for other_ticket in ticket_info.other_tickets.iter() {
-        assert_eq!(n, other_ticket.len());
-    }
-    ticket_info
+    Ok(ticket_info)
 }
 
-fn compute_solution_part_one(input: &str) -> usize {
-    let ticket_info = parse_input(input);
+fn compute_solution_part_one(input: &str) -> Result<usize, ParseError> {
+    let ticket_info = parse_input(input)?;
     let mut result = 0;
     for value in ticket_info.other_tickets.iter().flat_map(|t| t.iter()) {
         if !ticket_info.in_any_range(*value) {
             result += value;
         }
     }
-    result
+    Ok(result)
 }
 
-fn compute_solution_part_two(input: &str) -> usize {
-    let mut ticket_info = parse_input(input);
+fn compute_solution_part_two(input: &str) -> Result<usize, ParseError> {
+    let mut ticket_info = parse_input(input)?;
     ticket_info.remove_invalid_tickets();
     let n_cols = ticket_info.my_ticket.len();
     let n_rows = ticket_info.other_tickets.len();
     let mut candidates: Vec<HashSet<String>> = vec![
         ticket_info
             .field_descriptors
-            .iter()
-            .map(|(k, _)| k.to_string())
+            .keys()
+            .map(|k| k.to_string())
             .collect();
         n_cols
     ];
@@ -192,9 +169,9 @@ fn compute_solution_part_two(input: &str) -> usize {
         let next_index = next_index.unwrap();
         let candidate = candidates[next_index].iter().next().unwrap().clone();
         assigned.insert(candidate.to_string());
-        for i in 0..n_cols {
+        for (i, c) in candidates.iter_mut().enumerate() {
             if i != next_index {
-                candidates[i].remove(&candidate as &str);
+                c.remove(&candidate as &str);
             }
         }
     }
@@ -215,19 +192,30 @@ fn compute_solution_part_two(input: &str) -> usize {
     {
         result *= ticket_info.my_ticket[i];
     }
-
-    result
+    Ok(result)
 }
 
-fn main() {
-    use std::env;
-    let args: Vec<String> = env::args().collect();
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2020
+    }
+
+    fn day(&self) -> u8 {
+        16
+    }
+
+    fn parse(&self, input: &Input) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input.trimmed())?);
+        Ok(true)
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
 
-    if args.len() != 2 {
-        println!("Usage: d16 <input filename>");
-    } else {
-        let input = read_file(&args[1]);
-        println!("solution 1 = {}", compute_solution_part_one(&input));
-        println!("solution 2 = {}", compute_solution_part_two(&input));
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
     }
 }
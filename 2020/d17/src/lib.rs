@@ -1,17 +1,7 @@
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
 use std::collections::{BTreeMap, BTreeSet};
 use std::ops::Add;
 
-fn read_file(filename: &str) -> String {
-    use std::fs::File;
-    use std::io::prelude::*;
-
-    let mut f = File::open(filename).expect("file not found");
-    let mut contents = String::new();
-    f.read_to_string(&mut contents)
-        .expect("something went wrong reading the file");
-    contents.trim().to_owned()
-}
-
 struct NeighborIterator {
     origin: Coordinate,
     delta: Coordinate,
@@ -35,17 +25,17 @@ impl NeighborIterator {
     }
 
     fn step_delta(&mut self) {
-        let vals = [
+        let mut vals = [
             &mut self.delta.x,
             &mut self.delta.y,
             &mut self.delta.z,
             &mut self.delta.w,
         ];
         let n_dim = if self.use_four_dimensions { 4 } else { 3 };
-        for i in 0..n_dim {
-            *vals[i] += 1;
-            if *vals[i] == 2 {
-                *vals[i] = -1;
+        for val in vals.iter_mut().take(n_dim) {
+            **val += 1;
+            if **val == 2 {
+                **val = -1;
             } else {
                 break;
             }
@@ -115,18 +105,22 @@ impl Add for Coordinate {
     }
 }
 
-fn parse_input(input: &str) -> BTreeSet<Coordinate> {
+fn parse_input(input: &str) -> Result<BTreeSet<Coordinate>, ParseError> {
     let mut alive = BTreeSet::new();
     let z = 0;
     let w = 0;
-    for (y, line) in input.lines().enumerate() {
-        for (x, c) in line.chars().enumerate() {
-            if c == '#' {
-                alive.insert(Coordinate::new(x as i64, y as i64, z, w));
+    for (y, line) in Span::new(input).lines().enumerate() {
+        for (x, (span, c)) in line.chars().enumerate() {
+            match c {
+                '#' => {
+                    alive.insert(Coordinate::new(x as i64, y as i64, z, w));
+                }
+                '.' => (),
+                _ => return Err(span.error("`#` or `.`")),
             }
         }
     }
-    alive
+    Ok(alive)
 }
 
 fn step(alive: &BTreeSet<Coordinate>, use_four_dimensions: bool) -> BTreeSet<Coordinate> {
@@ -154,31 +148,43 @@ fn step(alive: &BTreeSet<Coordinate>, use_four_dimensions: bool) -> BTreeSet<Coo
     new_alive
 }
 
-fn test_reactor(use_four_dimensions: bool, input: &str) -> usize {
-    let mut alive = parse_input(input);
+fn test_reactor(use_four_dimensions: bool, input: &str) -> Result<usize, ParseError> {
+    let mut alive = parse_input(input)?;
     for _ in 0..6 {
         alive = step(&alive, use_four_dimensions);
     }
-    alive.len()
+    Ok(alive.len())
 }
 
-fn compute_solution_part_one(input: &str) -> usize {
+fn compute_solution_part_one(input: &str) -> Result<usize, ParseError> {
     test_reactor(false, input)
 }
 
-fn compute_solution_part_two(input: &str) -> usize {
+fn compute_solution_part_two(input: &str) -> Result<usize, ParseError> {
     test_reactor(true, input)
 }
 
-fn main() {
-    use std::env;
-    let args: Vec<String> = env::args().collect();
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2020
+    }
+
+    fn day(&self) -> u8 {
+        17
+    }
+
+    fn parse(&self, input: &Input) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input.trimmed())?);
+        Ok(true)
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
 
-    if args.len() != 2 {
-        println!("Usage: d17 <input filename>");
-    } else {
-        let input = read_file(&args[1]);
-        println!("solution 1 = {}", compute_solution_part_one(&input));
-        println!("solution 2 = {}", compute_solution_part_two(&input));
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
     }
 }
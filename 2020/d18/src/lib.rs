@@ -1,13 +1,4 @@
-fn read_file(filename: &str) -> String {
-    use std::fs::File;
-    use std::io::prelude::*;
-
-    let mut f = File::open(filename).expect("file not found");
-    let mut contents = String::new();
-    f.read_to_string(&mut contents)
-        .expect("something went wrong reading the file");
-    contents.trim().to_owned()
-}
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
 
 #[derive(Debug, Eq, PartialEq, Clone, Copy)]
 enum Symbol {
@@ -27,38 +18,57 @@ impl Symbol {
     }
 }
 
-fn parse_line(line: &str) -> Vec<Symbol> {
+fn parse_line(line: Span) -> Result<Vec<Symbol>, ParseError> {
     let mut result = Vec::new();
-    let mut current_number = None;
-
-    for c in line.chars().filter(|c| *c != ' ') {
-        match c {
-            '+' | '*' | '(' | ')' => {
-                if current_number.is_some() {
-                    result.push(Symbol::Number(current_number.unwrap()));
-                    current_number = None;
-                }
+    let mut current_number: Option<i64> = None;
+    let mut expect_operand = true;
+    let mut depth = 0;
+
+    for (span, c) in line.chars().filter(|(_, c)| *c != ' ') {
+        if let Some(n) = current_number {
+            if let Some(digit) = c.to_digit(10) {
+                current_number = Some(n * 10 + digit as i64);
+                continue;
             }
-            _ => (),
+            result.push(Symbol::Number(n));
+            current_number = None;
         }
         match c {
-            '0'..='9' => {
-                if current_number.is_none() {
-                    current_number = Some(0);
-                }
-                current_number = current_number.map(|n| n * 10 + (c.to_digit(10).unwrap() as i64));
+            '0'..='9' if expect_operand => {
+                current_number = c.to_digit(10).map(|digit| digit as i64);
+                expect_operand = false;
+            }
+            '(' if expect_operand => {
+                result.push(Symbol::OpenBracket);
+                depth += 1;
             }
-            '+' => result.push(Symbol::Addition),
-            '*' => result.push(Symbol::Multiplication),
-            '(' => result.push(Symbol::OpenBracket),
-            ')' => result.push(Symbol::CloseBracket),
-            _ => panic!("invalid symbol in expression"),
+            ')' if !expect_operand && depth > 0 => {
+                result.push(Symbol::CloseBracket);
+                depth -= 1;
+            }
+            '+' if !expect_operand => {
+                result.push(Symbol::Addition);
+                expect_operand = true;
+            }
+            '*' if !expect_operand => {
+                result.push(Symbol::Multiplication);
+                expect_operand = true;
+            }
+            _ if expect_operand => return Err(span.error("a number or `(`")),
+            _ if depth > 0 => return Err(span.error("`+`, `*` or `)`")),
+            _ => return Err(span.error("`+` or `*`")),
         }
     }
-    if current_number.is_some() {
-        result.push(Symbol::Number(current_number.unwrap()));
+    if let Some(n) = current_number {
+        result.push(Symbol::Number(n));
     }
-    result
+    if expect_operand {
+        return Err(line.end().error("a number or `(`"));
+    }
+    if depth > 0 {
+        return Err(line.end().error("`)`"));
+    }
+    Ok(result)
 }
 
 fn contains_brackets(e: &[Symbol]) -> bool {
@@ -112,8 +122,8 @@ fn parse_expression_no_brackets_addition_first(e: &[Symbol]) -> i64 {
 
 fn inner_bracket_indices(e: &[Symbol]) -> (usize, usize) {
     let mut first_bracket = None;
-    for i in 0..e.len() {
-        match e[i] {
+    for (i, s) in e.iter().enumerate() {
+        match s {
             Symbol::OpenBracket => first_bracket = Some(i),
             Symbol::CloseBracket => {
                 assert!(first_bracket.is_some());
@@ -145,33 +155,40 @@ fn parse_expression(e: &[Symbol], addition_first: bool) -> i64 {
     }
 }
 
-fn compute_solution_part_one(input: &str) -> i64 {
+fn compute_solution_part_one(input: &str) -> Result<i64, ParseError> {
     let mut sum = 0;
-    for line in input.lines() {
-        let parsed_symbols = parse_line(line);
+    for line in Span::new(input).lines() {
+        let parsed_symbols = parse_line(line)?;
         sum += parse_expression(&parsed_symbols, false);
     }
-    sum
+    Ok(sum)
 }
 
-fn compute_solution_part_two(input: &str) -> i64 {
+fn compute_solution_part_two(input: &str) -> Result<i64, ParseError> {
     let mut sum = 0;
-    for line in input.lines() {
-        let parsed_symbols = parse_line(line);
+    for line in Span::new(input).lines() {
+        let parsed_symbols = parse_line(line)?;
         sum += parse_expression(&parsed_symbols, true);
     }
-    sum
+    Ok(sum)
 }
 
-fn main() {
-    use std::env;
-    let args: Vec<String> = env::args().collect();
+pub struct Solution;
 
-    if args.len() != 2 {
-        println!("Usage: d18 <input filename>");
-    } else {
-        let input = read_file(&args[1]);
-        println!("solution 1 = {}", compute_solution_part_one(&input));
-        println!("solution 2 = {}", compute_solution_part_two(&input));
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2020
+    }
+
+    fn day(&self) -> u8 {
+        18
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
     }
 }
@@ -0,0 +1,138 @@
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
+use std::collections::BTreeMap;
+
+#[derive(Debug)]
+enum Rule {
+    Match(char),
+    SubRule(Vec<Vec<usize>>),
+}
+
+fn parse_rule<'a>(
+    line: Span<'a>,
+    references: &mut Vec<(Span<'a>, usize)>,
+) -> Result<(usize, Rule), ParseError> {
+    let (n, rule) = line.split_once(": ")?;
+    let n: usize = n.parse("a rule number")?;
+    if let Ok(quoted) = rule.strip_prefix("\"") {
+        let mut chars = quoted.strip_suffix("\"")?.chars();
+        match (chars.next(), chars.next()) {
+            (Some((_, c)), None) => Ok((n, Rule::Match(c))),
+            _ => Err(quoted.error("a single quoted character")),
+        }
+    } else {
+        let mut sub_rules = Vec::new();
+        for sub_parents in rule.split(" | ") {
+            let mut sub_rule = Vec::new();
+            for m in sub_parents.split_whitespace() {
+                let m = m.parse("a rule number")?;
+                references.push((sub_parents, m));
+                sub_rule.push(m);
+            }
+            sub_rules.push(sub_rule);
+        }
+        Ok((n, Rule::SubRule(sub_rules)))
+    }
+}
+
+fn parse_input(input: &str) -> Result<(BTreeMap<usize, Rule>, Vec<String>), ParseError> {
+    let mut blocks = Span::new(input).blocks();
+    let rule_block = blocks.expect("the rules")?;
+    let mut rules = BTreeMap::new();
+    let mut references = vec![(rule_block, 0)];
+    for line in rule_block.lines() {
+        let (n, rule) = parse_rule(line, &mut references)?;
+        rules.insert(n, rule);
+    }
+    for (span, n) in references {
+        if !rules.contains_key(&n) {
+            return Err(span.error(format!("a reference to a defined rule, not {}", n)));
+        }
+    }
+
+    let messages = blocks
+        .expect("the messages")?
+        .lines()
+        .map(|line| line.as_str().to_string())
+        .collect();
+    blocks.end()?;
+
+    Ok((rules, messages))
+}
+
+fn is_valid_message(message: &str, rule_numbers: &[usize], rules: &BTreeMap<usize, Rule>) -> bool {
+    if message.chars().count() == 0 && rule_numbers.is_empty() {
+        return true;
+    }
+    if (message.chars().count() == 0 && !rule_numbers.is_empty())
+        || (message.chars().count() > 0 && rule_numbers.is_empty())
+    {
+        return false;
+    }
+
+    match &rules[&rule_numbers[0]] {
+        Rule::Match(c) => {
+            if *c != message.chars().next().unwrap() {
+                false
+            } else {
+                let new_message: String = message.chars().skip(1).collect();
+                is_valid_message(&new_message, &rule_numbers[1..], rules)
+            }
+        }
+        Rule::SubRule(v) => {
+            let mut recursive_result = false;
+            for subvec in v.iter() {
+                let mut new_rule_numbers = subvec.clone();
+                new_rule_numbers.extend_from_slice(&rule_numbers[1..]);
+                recursive_result =
+                    recursive_result || is_valid_message(message, &new_rule_numbers, rules);
+            }
+            recursive_result
+        }
+    }
+}
+
+fn compute_solution_part_one(input: &str) -> Result<usize, ParseError> {
+    let (rules, messages) = parse_input(input)?;
+    Ok(messages
+        .iter()
+        .filter(|message| is_valid_message(message, &[0], &rules))
+        .count())
+}
+
+fn compute_solution_part_two(input: &str) -> Result<usize, ParseError> {
+    let (mut rules, messages) = parse_input(input)?;
+    if !rules.contains_key(&42) || !rules.contains_key(&31) {
+        return Err(Span::new(input).error("rules 42 and 31"));
+    }
+    rules.insert(8, Rule::SubRule(vec![vec![42], vec![42, 8]]));
+    rules.insert(11, Rule::SubRule(vec![vec![42, 31], vec![42, 11, 31]]));
+    Ok(messages
+        .iter()
+        .filter(|message| is_valid_message(message, &[0], &rules))
+        .count())
+}
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2020
+    }
+
+    fn day(&self) -> u8 {
+        19
+    }
+
+    fn parse(&self, input: &Input) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input.trimmed())?);
+        Ok(true)
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
+    }
+}
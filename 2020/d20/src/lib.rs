@@ -1,15 +1,6 @@
+use aoc_common::{Answer, Input, ParseError, Picture, PictureExport, Puzzle, Span};
 use std::collections::{BTreeMap, BTreeSet};
-
-fn read_file(filename: &str) -> String {
-    use std::fs::File;
-    use std::io::prelude::*;
-
-    let mut f = File::open(filename).expect("file not found");
-    let mut contents = String::new();
-    f.read_to_string(&mut contents)
-        .expect("something went wrong reading the file");
-    contents.trim().to_owned()
-}
+use std::io;
 
 #[derive(Debug, Copy, Clone)]
 enum Orientation {
@@ -176,69 +167,53 @@ impl Tile {
         other: &Self,
         from_direction: Direction,
     ) -> Option<Orientation> {
-        for other_orientation in Orientation::iter() {
-            if self.have_matching_borders(
-                self_orientation,
-                other,
-                other_orientation,
-                from_direction,
-            ) {
-                return Some(other_orientation);
-            }
-        }
-        None
+        Orientation::iter().find(|&other_orientation| {
+            self.have_matching_borders(self_orientation, other, other_orientation, from_direction)
+        })
     }
 }
 
-fn parse_input(input: &str) -> BTreeMap<usize, Tile> {
+fn parse_input(input: &str) -> Result<BTreeMap<usize, Tile>, ParseError> {
+    let input = Span::new(input);
     let mut result = BTreeMap::new();
-
-    let mut id = None;
-    let mut n = None;
-    let mut data = Vec::new();
-
-    for line in input.lines() {
-        if line.contains("Tile") {
-            id = Some(
-                line.split_whitespace()
-                    .nth(1)
-                    .unwrap()
-                    .split(':')
-                    .next()
-                    .unwrap()
-                    .parse()
-                    .unwrap(),
-            );
-            data = Vec::new();
-            continue;
-        }
-        if line.chars().count() == 0 {
-            result.insert(id.unwrap(), Tile::new(n.unwrap(), n.unwrap(), data.clone()));
-            id = None;
-            n = None;
-            continue;
-        }
-        n = Some(line.chars().count());
-        for c in line.chars() {
-            match c {
-                '#' => data.push(true),
-                '.' => data.push(false),
-                _ => panic!("unknown image data"),
+    let mut size = None;
+
+    for block in input.blocks() {
+        let mut lines = block.lines();
+        let id = lines
+            .expect("a tile header")?
+            .strip_prefix("Tile ")?
+            .strip_suffix(":")?
+            .parse("a tile ID")?;
+        let mut data = Vec::new();
+        let mut n = 0;
+        for line in lines {
+            let width = line.as_str().chars().count();
+            let expected = *size.get_or_insert(width);
+            if width != expected {
+                return Err(line.error(format!("a row of {} pixels", expected)));
             }
+            for (span, c) in line.chars() {
+                match c {
+                    '#' => data.push(true),
+                    '.' => data.push(false),
+                    _ => return Err(span.error("`#` or `.`")),
+                }
+            }
+            n += 1;
         }
+        match size {
+            Some(width) if width == n => (),
+            Some(width) => return Err(block.end().error(format!("{} rows of pixels", width))),
+            None => return Err(block.end().error("a row of pixels")),
+        }
+        result.insert(id, Tile::new(n, n, data));
     }
 
-    if n.is_some() {
-        result.insert(id.unwrap(), Tile::new(n.unwrap(), n.unwrap(), data));
-    }
-
-    let n = result.iter().next().unwrap().1.width;
-
-    for (_, tile) in result.iter() {
-        assert_eq!(n, tile.width);
+    if result.is_empty() {
+        return Err(input.error("at least one tile"));
     }
-
-    result
+    Ok(result)
 }
 
 fn relative_direction(parent: (i64, i64), child: (i64, i64)) -> Direction {
@@ -263,7 +238,7 @@ fn relative_direction(parent: (i64, i64), child: (i64, i64)) -> Direction {
 fn find_all_tiles_orientation(
     tiles: &BTreeMap<usize, Tile>,
 ) -> BTreeMap<(i64, i64), (usize, Orientation)> {
-    let origin_tile = *tiles.iter().map(|(id, _)| id).min().unwrap();
+    let origin_tile = *tiles.keys().min().unwrap();
 
     // coordinate --> (id, Orientiation)
     let mut assigned: BTreeMap<(i64, i64), (usize, Orientation)> = BTreeMap::new();
@@ -276,7 +251,7 @@ fn find_all_tiles_orientation(
     coordinates_todo.insert((0, 1), (0, 0));
     coordinates_todo.insert((0, -1), (0, 0));
 
-    let mut ids_todo: BTreeSet<usize> = tiles.iter().map(|(id, _)| id).cloned().collect();
+    let mut ids_todo: BTreeSet<usize> = tiles.keys().cloned().collect();
     ids_todo.remove(&origin_tile);
 
     while !ids_todo.is_empty() {
@@ -305,8 +280,8 @@ fn find_all_tiles_orientation(
     assigned
 }
 
-fn compute_solution_part_one(input: &str) -> usize {
-    let tiles = parse_input(input);
+fn compute_solution_part_one(input: &str) -> Result<usize, ParseError> {
+    let tiles = parse_input(input)?;
     let assigned = find_all_tiles_orientation(&tiles);
 
     let max_x = *assigned.keys().map(|(x, _)| x).max().unwrap();
@@ -326,14 +301,14 @@ fn compute_solution_part_one(input: &str) -> usize {
         let (id, _) = assigned[&(x, y)];
         result *= id;
     }
-    result
+    Ok(result)
 }
 
 fn merge_mini_tiles(
     tiles: &BTreeMap<usize, Tile>,
     assigned: &BTreeMap<(i64, i64), (usize, Orientation)>,
 ) -> Tile {
-    let n = tiles.iter().map(|(_, t)| t.width).next().unwrap();
+    let n = tiles.values().map(|t| t.width).next().unwrap();
 
     let max_x = *assigned.keys().map(|(x, _)| x).max().unwrap();
     let min_x = *assigned.keys().map(|(x, _)| x).min().unwrap();
@@ -429,7 +404,7 @@ fn check_for_monster(i: usize, j: usize, tile: &Tile, orientation: Orientation)
     true
 }
 
-fn delete_monster(i: usize, j: usize, tile: &mut Tile, orientation: Orientation) {
+fn write_monster(i: usize, j: usize, tile: &mut Tile, orientation: Orientation, newval: bool) {
     let i = i as i64;
     let j = j as i64;
     let deltas: [(i64, i64); 15] = [
@@ -462,15 +437,12 @@ fn delete_monster(i: usize, j: usize, tile: &mut Tile, orientation: Orientation)
         if i0 >= height || j0 >= width {
             panic!("invalid coordinates")
         }
-        tile.write_pixel(i0, j0, orientation, false);
+        tile.write_pixel(i0, j0, orientation, newval);
     }
 }
 
-fn compute_solution_part_two(input: &str) -> usize {
-    let tiles = parse_input(input);
-    let assigned = find_all_tiles_orientation(&tiles);
-    let mut merged = merge_mini_tiles(&tiles, &assigned);
-
+// Returns the orientation of the merged image that shows the most monsters, and their positions
+fn find_monsters(merged: &Tile) -> (Orientation, Vec<(usize, usize)>) {
     let mut max_orientation = Orientation::Original;
     let mut max_monster_coords = Vec::new();
 
@@ -480,7 +452,7 @@ fn compute_solution_part_two(input: &str) -> usize {
         let (width, height) = merged.dims_of_orientation(orientation);
         for i in 0..height {
             for j in 0..width {
-                if check_for_monster(i, j, &merged, orientation) {
+                if check_for_monster(i, j, merged, orientation) {
                     count += 1;
                     coords.push((i, j));
                 }
@@ -492,22 +464,77 @@ fn compute_solution_part_two(input: &str) -> usize {
         }
     }
 
+    (max_orientation, max_monster_coords)
+}
+
+fn compute_solution_part_two(input: &str) -> Result<usize, ParseError> {
+    let tiles = parse_input(input)?;
+    let assigned = find_all_tiles_orientation(&tiles);
+    let mut merged = merge_mini_tiles(&tiles, &assigned);
+    let (max_orientation, max_monster_coords) = find_monsters(&merged);
+
     for &(i, j) in max_monster_coords.iter() {
-        delete_monster(i, j, &mut merged, max_orientation);
+        write_monster(i, j, &mut merged, max_orientation, false);
     }
 
-    merged.data.iter().filter(|v| **v).count()
+    Ok(merged.data.iter().filter(|v| **v).count())
 }
 
-fn main() {
-    use std::env;
-    let args: Vec<String> = env::args().collect();
+// Draws the merged image with the sea monsters highlighted, one frame per monster found
+fn export_image(input: &str, export: &mut PictureExport) -> io::Result<bool> {
+    let tiles = parse_input(input).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    let assigned = find_all_tiles_orientation(&tiles);
+    let merged = merge_mini_tiles(&tiles, &assigned);
+    let (orientation, monster_coords) = find_monsters(&merged);
+
+    let mut monsters = Tile::new(merged.width, merged.height, vec![false; merged.data.len()]);
+    let (width, height) = merged.dims_of_orientation(orientation);
+    let picture = |monsters: &Tile| {
+        Picture::color(width, height, |i, j| {
+            if monsters.read_pixel(i, j, orientation) {
+                [40, 200, 60]
+            } else if merged.read_pixel(i, j, orientation) {
+                [120, 170, 230]
+            } else {
+                [10, 40, 90]
+            }
+        })
+    };
 
-    if args.len() != 2 {
-        println!("Usage: d20 <input filename>");
-    } else {
-        let input = read_file(&args[1]);
-        println!("solution 1 = {}", compute_solution_part_one(&input));
-        println!("solution 2 = {}", compute_solution_part_two(&input));
+    export.frame(|| picture(&monsters))?;
+    for &(i, j) in monster_coords.iter() {
+        write_monster(i, j, &mut monsters, orientation, true);
+        export.frame(|| picture(&monsters))?;
+    }
+    export.finish(&picture(&monsters))?;
+    Ok(true)
+}
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2020
+    }
+
+    fn day(&self) -> u8 {
+        20
+    }
+
+    fn parse(&self, input: &Input) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input.trimmed())?);
+        Ok(true)
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
+    }
+
+    fn export_image(&self, input: &Input, export: &mut PictureExport) -> io::Result<bool> {
+        export_image(input.trimmed(), export)
     }
 }
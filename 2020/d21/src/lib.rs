@@ -1,45 +1,29 @@
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
 use std::collections::{BTreeMap, BTreeSet};
 
-fn read_file(filename: &str) -> String {
-    use std::fs::File;
-    use std::io::prelude::*;
+// The ingredients and the allergens of a food
+type Food = (BTreeSet<String>, BTreeSet<String>);
 
-    let mut f = File::open(filename).expect("file not found");
-    let mut contents = String::new();
-    f.read_to_string(&mut contents)
-        .expect("something went wrong reading the file");
-    contents.trim().to_owned()
-}
-
-fn parse_input(input: &str) -> Vec<(BTreeSet<String>, BTreeSet<String>)> {
+fn parse_input(input: &str) -> Result<Vec<Food>, ParseError> {
     let mut result = Vec::new();
-
-    for line in input.lines() {
-        let ingredients: BTreeSet<String> = line
-            .split('(')
-            .next()
-            .unwrap()
+    for line in Span::new(input).lines() {
+        let (ingredients, allergens) = line.split_once(" (contains ")?;
+        let ingredients: BTreeSet<String> = ingredients
             .split_whitespace()
-            .map(|s| s.to_string())
+            .map(|s| s.as_str().to_string())
             .collect();
-        let allergens: BTreeSet<String> = line
-            .split("contains ")
-            .nth(1)
-            .unwrap()
-            .split(')')
-            .next()
-            .unwrap()
+        let allergens: BTreeSet<String> = allergens
+            .strip_suffix(")")?
             .split(", ")
-            .map(|s| s.to_string())
+            .map(|s| s.as_str().to_string())
             .collect();
         result.push((ingredients, allergens));
     }
-
-    result
+    Ok(result)
 }
 
-fn compute_solution_part_one(input: &str) -> usize {
-    let list_of_foods = parse_input(input);
+fn compute_solution_part_one(input: &str) -> Result<usize, ParseError> {
+    let list_of_foods = parse_input(input)?;
     let (allergens, ingredients) = {
         let mut allergens = BTreeSet::new();
         let mut ingredients = BTreeSet::new();
@@ -78,12 +62,11 @@ fn compute_solution_part_one(input: &str) -> usize {
     for (i, _) in list_of_foods.iter() {
         count += i.intersection(&contains_no_allergens).count()
     }
-
-    count
+    Ok(count)
 }
 
-fn compute_solution_part_two(input: &str) -> String {
-    let list_of_foods = parse_input(input);
+fn compute_solution_part_two(input: &str) -> Result<String, ParseError> {
+    let list_of_foods = parse_input(input)?;
     let (allergens, ingredients) = {
         let mut allergens = BTreeSet::new();
         let mut ingredients = BTreeSet::new();
@@ -113,7 +96,7 @@ fn compute_solution_part_two(input: &str) -> String {
         let tmp = candidates
             .iter()
             .find(|(_, i_set)| i_set.len() == 1 && !assigned.contains(i_set.iter().next().unwrap()))
-            .map(|(a, i)| (a.clone(), i.iter().cloned().next().unwrap()));
+            .map(|(a, i)| (a.clone(), i.iter().next().cloned().unwrap()));
         if let Some((a0, i0)) = tmp {
             assigned.insert(i0.clone());
             changed = true;
@@ -128,28 +111,39 @@ fn compute_solution_part_two(input: &str) -> String {
 
     // btreemap is sorted
     let mut result: String = candidates
-        .iter()
-        .map(|(_, i)| i.iter().next().unwrap().to_string())
+        .values()
+        .map(|i| i.iter().next().unwrap().to_string())
         .next()
         .unwrap();
 
     for (_, i) in candidates.iter().skip(1) {
-        result.push_str(",");
+        result.push(',');
         result.push_str(i.iter().next().unwrap());
     }
-
-    result
+    Ok(result)
 }
 
-fn main() {
-    use std::env;
-    let args: Vec<String> = env::args().collect();
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2020
+    }
+
+    fn day(&self) -> u8 {
+        21
+    }
+
+    fn parse(&self, input: &Input) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input.trimmed())?);
+        Ok(true)
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
 
-    if args.len() != 2 {
-        println!("Usage: d21 <input filename>");
-    } else {
-        let input = read_file(&args[1]);
-        println!("solution 1 = {}", compute_solution_part_one(&input));
-        println!("solution 2 = {}", compute_solution_part_two(&input));
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
     }
 }
@@ -1,37 +1,21 @@
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
 use std::collections::{BTreeSet, VecDeque};
 
-fn read_file(filename: &str) -> String {
-    use std::fs::File;
-    use std::io::prelude::*;
-
-    let mut f = File::open(filename).expect("file not found");
-    let mut contents = String::new();
-    f.read_to_string(&mut contents)
-        .expect("something went wrong reading the file");
-    contents.trim().to_owned()
+fn parse_deck(block: Span, header: &str) -> Result<VecDeque<usize>, ParseError> {
+    let mut lines = block.lines();
+    let line = lines.expect(header)?;
+    if line.as_str() != header {
+        return Err(line.error(format!("`{}`", header)));
+    }
+    lines.map(|line| line.parse("a card")).collect()
 }
 
-fn parse_input(input: &str) -> (VecDeque<usize>, VecDeque<usize>) {
-    let mut player_one = VecDeque::new();
-    let mut player_two = VecDeque::new();
-    let mut read_one = true;
-
-    for line in input.lines() {
-        if line.chars().count() == 0 {
-            read_one = false;
-            continue;
-        }
-        if line.contains("Player") {
-            continue;
-        }
-
-        if read_one {
-            player_one.push_back(line.parse().unwrap());
-        } else {
-            player_two.push_back(line.parse().unwrap());
-        }
-    }
-    (player_one, player_two)
+fn parse_input(input: &str) -> Result<(VecDeque<usize>, VecDeque<usize>), ParseError> {
+    let mut decks = Span::new(input).blocks();
+    let player_one = parse_deck(decks.expect("the deck of player 1")?, "Player 1:")?;
+    let player_two = parse_deck(decks.expect("the deck of player 2")?, "Player 2:")?;
+    decks.end()?;
+    Ok((player_one, player_two))
 }
 
 #[derive(Eq, PartialEq)]
@@ -117,8 +101,8 @@ fn play_game_recursive(
     }
 }
 
-fn compute_solution_part_one(input: &str) -> usize {
-    let (mut player_one, mut player_two) = parse_input(input);
+fn compute_solution_part_one(input: &str) -> Result<usize, ParseError> {
+    let (mut player_one, mut player_two) = parse_input(input)?;
     let mut game_state = GameState::Continue;
 
     while game_state == GameState::Continue {
@@ -131,34 +115,46 @@ fn compute_solution_part_one(input: &str) -> usize {
         player_two
     };
 
-    player_win
+    Ok(player_win
         .iter()
         .rev()
         .enumerate()
         .map(|(i, n)| (i + 1) * n)
-        .sum()
+        .sum())
 }
 
-fn compute_solution_part_two(input: &str) -> usize {
-    let (player_one, player_two) = parse_input(input);
+fn compute_solution_part_two(input: &str) -> Result<usize, ParseError> {
+    let (player_one, player_two) = parse_input(input)?;
     let (player_win, _) = play_game_recursive(player_one, player_two);
-    player_win
+    Ok(player_win
         .iter()
         .rev()
         .enumerate()
         .map(|(i, n)| (i + 1) * n)
-        .sum()
+        .sum())
 }
 
-fn main() {
-    use std::env;
-    let args: Vec<String> = env::args().collect();
+pub struct Solution;
 
-    if args.len() != 2 {
-        println!("Usage: d22 <input filename>");
-    } else {
-        let input = read_file(&args[1]);
-        println!("solution 1 = {}", compute_solution_part_one(&input));
-        println!("solution 2 = {}", compute_solution_part_two(&input));
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2020
+    }
+
+    fn day(&self) -> u8 {
+        22
+    }
+
+    fn parse(&self, input: &Input) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input.trimmed())?);
+        Ok(true)
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
     }
 }
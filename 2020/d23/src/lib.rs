@@ -1,16 +1,6 @@
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
 use std::collections::BTreeMap;
 
-fn read_file(filename: &str) -> String {
-    use std::fs::File;
-    use std::io::prelude::*;
-
-    let mut f = File::open(filename).expect("file not found");
-    let mut contents = String::new();
-    f.read_to_string(&mut contents)
-        .expect("something went wrong reading the file");
-    contents.trim().to_owned()
-}
-
 #[derive(Debug)]
 struct GameState {
     successors: BTreeMap<usize, usize>,
@@ -20,11 +10,19 @@ struct GameState {
 }
 
 impl GameState {
-    fn new(input: &str) -> Self {
-        let cups: Vec<usize> = input
-            .chars()
-            .map(|c| c.to_digit(10).unwrap() as usize)
-            .collect();
+    fn new(input: &str) -> Result<Self, ParseError> {
+        let input = Span::new(input);
+        let mut cups: Vec<usize> = Vec::new();
+        for (span, c) in input.chars() {
+            match c.to_digit(10) {
+                Some(cup) if cup > 0 && !cups.contains(&(cup as usize)) => cups.push(cup as usize),
+                Some(_) => return Err(span.error("a cup label that is not used yet")),
+                None => return Err(span.error("a cup label")),
+            }
+        }
+        if cups.len() < 4 || cups.iter().any(|&cup| cup > cups.len()) {
+            return Err(input.error("the cups labeled 1 to n, with n at least 4"));
+        }
         let min_cup = *cups.iter().min().unwrap();
         let max_cup = *cups.iter().max().unwrap();
         let current_cup = cups[0];
@@ -35,12 +33,12 @@ impl GameState {
             successors.insert(a, b);
         }
 
-        Self {
+        Ok(Self {
             successors,
             current_cup,
             min_cup,
             max_cup,
-        }
+        })
     }
 
     fn step(&mut self) {
@@ -59,7 +57,7 @@ impl GameState {
             self.current_cup - 1
         };
 
-        while moved_cups.iter().any(|&c| c == destination_cup) {
+        while moved_cups.contains(&destination_cup) {
             destination_cup = if destination_cup == self.min_cup {
                 self.max_cup
             } else {
@@ -102,34 +100,41 @@ impl GameState {
     }
 }
 
-fn compute_solution_part_one(input: &str) -> String {
-    let mut game_state = GameState::new(input);
+fn compute_solution_part_one(input: &str) -> Result<String, ParseError> {
+    let mut game_state = GameState::new(input)?;
     for _ in 0..100 {
         game_state.step();
     }
-    game_state.cup_string(1)
+    Ok(game_state.cup_string(1))
 }
 
-fn compute_solution_part_two(input: &str) -> usize {
-    let mut game_state = GameState::new(input);
+fn compute_solution_part_two(input: &str) -> Result<usize, ParseError> {
+    let mut game_state = GameState::new(input)?;
     game_state.increase_cups_to(1_000_000);
     for _ in 0..10_000_000 {
         game_state.step();
     }
     let a = game_state.successors[&1];
     let b = game_state.successors[&a];
-    a * b
+    Ok(a * b)
 }
 
-fn main() {
-    use std::env;
-    let args: Vec<String> = env::args().collect();
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2020
+    }
+
+    fn day(&self) -> u8 {
+        23
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
 
-    if args.len() != 2 {
-        println!("Usage: d22 <input filename>");
-    } else {
-        let input = read_file(&args[1]);
-        println!("solution 1 = {}", compute_solution_part_one(&input));
-        println!("solution 2 = {}", compute_solution_part_two(&input));
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
     }
 }
@@ -1,16 +1,6 @@
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
 use std::collections::{BTreeMap, BTreeSet};
 
-fn read_file(filename: &str) -> String {
-    use std::fs::File;
-    use std::io::prelude::*;
-
-    let mut f = File::open(filename).expect("file not found");
-    let mut contents = String::new();
-    f.read_to_string(&mut contents)
-        .expect("something went wrong reading the file");
-    contents.trim().to_owned()
-}
-
 #[derive(Copy, Clone)]
 enum Direction {
     East,
@@ -47,7 +37,7 @@ impl Coordinate {
         Self { q, r }
     }
 
-    fn to_direction(&self, direction: Direction) -> Self {
+    fn to_direction(self, direction: Direction) -> Self {
         match direction {
             Direction::East => Self::new(self.q + 1, self.r),
             Direction::SouthEast => Self::new(self.q, self.r + 1),
@@ -62,11 +52,11 @@ impl Coordinate {
         Direction::iter().map(move |d| self.to_direction(d))
     }
 
-    fn parse_line(line: &str) -> Self {
+    fn parse_line(line: Span) -> Result<Self, ParseError> {
         let mut result = Self::new(0, 0);
         let mut north = false;
         let mut south = false;
-        for c in line.chars() {
+        for (span, c) in line.chars() {
             match (north, south, c) {
                 (false, false, 'n') => {
                     north = true;
@@ -96,24 +86,28 @@ impl Coordinate {
                     result = result.to_direction(Direction::SouthWest);
                     south = false;
                 }
-                _ => panic!("invalid input"),
+                (false, false, _) => return Err(span.error("`e`, `w`, `n` or `s`")),
+                _ => return Err(span.error("`e` or `w`")),
             }
         }
-        result
+        if north || south {
+            return Err(line.end().error("`e` or `w`"));
+        }
+        Ok(result)
     }
 }
 
-fn parse_input(input: &str) -> BTreeSet<Coordinate> {
+fn parse_input(input: &str) -> Result<BTreeSet<Coordinate>, ParseError> {
     let mut black = BTreeSet::new();
-    for line in input.lines() {
-        let coordinate = Coordinate::parse_line(line);
+    for line in Span::new(input).lines() {
+        let coordinate = Coordinate::parse_line(line)?;
         if black.contains(&coordinate) {
             black.remove(&coordinate);
         } else {
             black.insert(coordinate);
         }
     }
-    black
+    Ok(black)
 }
 
 fn exhibit_step(black: &BTreeSet<Coordinate>) -> BTreeSet<Coordinate> {
@@ -144,28 +138,40 @@ fn exhibit_step(black: &BTreeSet<Coordinate>) -> BTreeSet<Coordinate> {
     next_black
 }
 
-fn compute_solution_part_one(input: &str) -> usize {
-    let black = parse_input(input);
-    black.len()
+fn compute_solution_part_one(input: &str) -> Result<usize, ParseError> {
+    let black = parse_input(input)?;
+    Ok(black.len())
 }
 
-fn compute_solution_part_two(input: &str) -> usize {
-    let mut black = parse_input(input);
+fn compute_solution_part_two(input: &str) -> Result<usize, ParseError> {
+    let mut black = parse_input(input)?;
     for _ in 0..100 {
         black = exhibit_step(&black);
     }
-    black.len()
+    Ok(black.len())
 }
 
-fn main() {
-    use std::env;
-    let args: Vec<String> = env::args().collect();
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2020
+    }
+
+    fn day(&self) -> u8 {
+        24
+    }
+
+    fn parse(&self, input: &Input) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input.trimmed())?);
+        Ok(true)
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
 
-    if args.len() != 2 {
-        println!("Usage: d24 <input filename>");
-    } else {
-        let input = read_file(&args[1]);
-        println!("solution 1 = {}", compute_solution_part_one(&input));
-        println!("solution 2 = {}", compute_solution_part_two(&input));
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
     }
 }
@@ -0,0 +1,75 @@
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
+
+fn parse_public_key(line: Span) -> Result<usize, ParseError> {
+    match line.trim().parse("a public key")? {
+        key @ 1..=20_201_226 => Ok(key),
+        _ => Err(line.error("a public key between 1 and 20201226")),
+    }
+}
+
+fn parse_input(input: &str) -> Result<(usize, usize), ParseError> {
+    let mut lines = Span::new(input).lines();
+    let card_public_key = parse_public_key(lines.expect("the public key of the card")?)?;
+    let door_public_key = parse_public_key(lines.expect("the public key of the door")?)?;
+    lines.end()?;
+    Ok((card_public_key, door_public_key))
+}
+
+fn transform(value: usize, subject_number: usize) -> usize {
+    (value * subject_number) % 20_201_227
+}
+
+fn find_loop_size(public_key: usize) -> usize {
+    let mut loop_nr = 0;
+    let mut value = 1;
+    let subject_number = 7;
+    loop {
+        if value == public_key {
+            return loop_nr;
+        }
+        value = transform(value, subject_number);
+        loop_nr += 1;
+    }
+}
+
+fn encrypt(subject_number: usize, loop_size: usize) -> usize {
+    let mut value = 1;
+    for _ in 0..loop_size {
+        value = transform(value, subject_number);
+    }
+    value
+}
+
+fn compute_solution_part_one(input: &str) -> Result<usize, ParseError> {
+    let (card_public_key, door_public_key) = parse_input(input)?;
+    let card_loop_size = find_loop_size(card_public_key);
+    let door_loop_size = find_loop_size(door_public_key);
+    let encryption_key = encrypt(door_public_key, card_loop_size);
+    assert_eq!(encryption_key, encrypt(card_public_key, door_loop_size));
+    Ok(encryption_key)
+}
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2020
+    }
+
+    fn day(&self) -> u8 {
+        25
+    }
+
+    fn parse(&self, input: &Input) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input.trimmed())?);
+        Ok(true)
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
+
+    fn has_part_two(&self) -> bool {
+        false
+    }
+}
@@ -0,0 +1,35 @@
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
+
+fn parse_input(input: &str) -> Result<Vec<usize>, ParseError> {
+    Span::new(input).lines().map(|l| l.parse("a depth")).collect()
+}
+
+fn compute_solution_part_one(input: &str) -> Result<usize, ParseError> {
+    let depths = parse_input(input)?;
+    Ok(depths.iter().skip(1).zip(depths.iter()).filter(|(d1, d2)| d1 > d2).count())
+}
+
+fn compute_solution_part_two(input: &str) -> Result<usize, ParseError> {
+    let depths = parse_input(input)?;
+    Ok(depths.iter().skip(3).zip(depths.iter()).filter(|(d1, d2)| d1 > d2).count())
+}
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2021
+    }
+
+    fn day(&self) -> u8 {
+        1
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
+    }
+}
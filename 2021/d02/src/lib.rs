@@ -0,0 +1,96 @@
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
+
+#[derive(Default)]
+struct Position {
+    x: i64,
+    depth: i64,
+    aim: i64,
+}
+
+impl Position {
+    fn apply_command(&mut self, c: &Command) {
+        match c {
+            Command::Forward(n) => self.x += n,
+            Command::Up(n) => self.depth -= n,
+            Command::Down(n) => self.depth += n,
+        }
+    }
+
+    fn apply_command_with_aim(&mut self, c: &Command) {
+        match c {
+            Command::Forward(n) => {
+                self.x += n;
+                self.depth += n * self.aim;
+            }
+            Command::Up(n) => self.aim -= n,
+            Command::Down(n) => self.aim += n,
+        }
+    }
+}
+
+enum Command {
+    Forward(i64),
+    Down(i64),
+    Up(i64),
+}
+
+fn parse_input(input: &str) -> Result<Vec<Command>, ParseError> {
+    let mut result = Vec::new();
+    for line in Span::new(input).lines() {
+        let mut iter = line.split(" ");
+        let cs = iter.expect("a command")?;
+        let amount: i64 = iter.expect("an amount")?.parse("an amount")?;
+        iter.end()?;
+        let c = match cs.as_str() {
+            "forward" => Command::Forward(amount),
+            "up" => Command::Up(amount),
+            "down" => Command::Down(amount),
+            _ => return Err(cs.error("`forward`, `up` or `down`")),
+        };
+        result.push(c);
+    }
+    Ok(result)
+}
+
+fn compute_solution_part_one(input: &str) -> Result<i64, ParseError> {
+    let commands = parse_input(input)?;
+    let mut p = Position::default();
+    for c in commands.iter() {
+        p.apply_command(c);
+    }
+    Ok(p.x * p.depth)
+}
+
+fn compute_solution_part_two(input: &str) -> Result<i64, ParseError> {
+    let commands = parse_input(input)?;
+    let mut p = Position::default();
+    for c in commands.iter() {
+        p.apply_command_with_aim(c);
+    }
+    Ok(p.x * p.depth)
+}
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2021
+    }
+
+    fn day(&self) -> u8 {
+        2
+    }
+
+    fn parse(&self, input: &Input) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input.trimmed())?);
+        Ok(true)
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
+    }
+}
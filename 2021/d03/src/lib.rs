@@ -1,13 +1,4 @@
-fn read_file(filename: &str) -> String {
-    use std::fs::File;
-    use std::io::prelude::*;
-
-    let mut f = File::open(filename).expect("file not found");
-    let mut contents = String::new();
-    f.read_to_string(&mut contents)
-        .expect("something went wrong reading the file");
-    contents.trim().to_owned()
-}
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
 
 struct Bitset {
     data: Vec<bool>,
@@ -16,31 +7,37 @@ struct Bitset {
 }
 
 impl Bitset {
-    fn from_input(input: &str) -> Self {
+    fn from_input(input: &str) -> Result<Self, ParseError> {
+        let input = Span::new(input);
         let mut data = Vec::new();
-        let n_bits = input.lines().next().unwrap().chars().count();
+        let n_bits = input
+            .as_str()
+            .lines()
+            .next()
+            .map_or(0, |l| l.chars().count());
         let n_lines = input.lines().count();
+        if n_bits == 0 || n_bits > 64 {
+            return Err(input.error("a binary number of 1 to 64 bits"));
+        }
 
         for line in input.lines() {
-            assert_eq!(
-                n_bits,
-                line.chars().count(),
-                "Not every input line has the same number of bits."
-            );
-            for c in line.chars() {
+            if line.as_str().chars().count() != n_bits {
+                return Err(line.error(format!("a binary number of {} bits", n_bits)));
+            }
+            for (span, c) in line.chars() {
                 match c {
                     '0' => data.push(false),
                     '1' => data.push(true),
-                    _ => panic!("input not a binary number"),
+                    _ => return Err(span.error("`0` or `1`")),
                 }
             }
         }
 
-        Self {
+        Ok(Self {
             data,
             n_bits,
             n_lines,
-        }
+        })
     }
 
     fn get(&self, line_number: usize, bit_number: usize) -> bool {
@@ -50,8 +47,8 @@ impl Bitset {
     }
 }
 
-fn compute_solution_part_one(input: &str) -> u64 {
-    let bitset = Bitset::from_input(input);
+fn compute_solution_part_one(input: &str) -> Result<u64, ParseError> {
+    let bitset = Bitset::from_input(input)?;
 
     let mut counts_one = vec![0usize; bitset.n_bits];
     let mut counts_zero = vec![0usize; bitset.n_bits];
@@ -78,7 +75,7 @@ fn compute_solution_part_one(input: &str) -> u64 {
         }
     }
 
-    gamma_rate * epsilon_rate
+    Ok(gamma_rate * epsilon_rate)
 }
 
 fn reduce_bitset(bitset: &Bitset, keep_function: fn(usize, usize) -> bool) -> u64 {
@@ -119,23 +116,30 @@ fn reduce_bitset(bitset: &Bitset, keep_function: fn(usize, usize) -> bool) -> u6
     result
 }
 
-fn compute_solution_part_two(input: &str) -> u64 {
-    let bitset = Bitset::from_input(input);
+fn compute_solution_part_two(input: &str) -> Result<u64, ParseError> {
+    let bitset = Bitset::from_input(input)?;
     let oxygen = reduce_bitset(&bitset, |count_zero, count_one| count_one >= count_zero);
     let co2 = reduce_bitset(&bitset, |count_zero, count_one| count_zero > count_one);
 
-    oxygen * co2
+    Ok(oxygen * co2)
 }
 
-fn main() {
-    use std::env;
-    let args: Vec<String> = env::args().collect();
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2021
+    }
+
+    fn day(&self) -> u8 {
+        3
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
 
-    if args.len() != 2 {
-        println!("Usage: d03 <input filename>");
-    } else {
-        let input = read_file(&args[1]);
-        println!("solution 1 = {}", compute_solution_part_one(&input));
-        println!("solution 2 = {}", compute_solution_part_two(&input));
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
     }
 }
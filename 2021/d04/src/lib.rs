@@ -1,41 +1,24 @@
-fn read_file(filename: &str) -> String {
-    use std::fs::File;
-    use std::io::prelude::*;
-
-    let mut f = File::open(filename).expect("file not found");
-    let mut contents = String::new();
-    f.read_to_string(&mut contents)
-        .expect("something went wrong reading the file");
-    contents.trim().to_owned()
-}
-
-fn parse_input(input: &str) -> (Vec<usize>, Vec<BingoTip>) {
-    let drawn_numbers: Vec<usize> = input
-        .lines()
-        .next()
-        .unwrap()
-        .split(',')
-        .map(|n| n.parse().unwrap())
-        .collect();
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
+
+fn parse_input(input: &str) -> Result<(Vec<usize>, Vec<BingoTip>), ParseError> {
+    let mut blocks = Span::new(input).blocks();
+    let drawn_numbers = blocks
+        .expect("the drawn numbers")?
+        .split(",")
+        .map(|n| n.parse("a drawn number"))
+        .collect::<Result<Vec<usize>, _>>()?;
     let mut bingo_tips = Vec::new();
 
-    let mut line_iter = input.lines().skip(2);
-    let mut line = line_iter.next();
-
-    let mut builder = BingoTipBuilder::default();
-
-    while line.is_some() {
-        builder.feed_str(line.unwrap());
-
-        if builder.buildable() {
-            bingo_tips.push(builder.build());
-            builder = BingoTipBuilder::default();
+    for block in blocks {
+        let mut builder = BingoTipBuilder::default();
+        builder.feed_str(block)?;
+        if !builder.buildable() {
+            return Err(block.end().error("a board of 25 numbers"));
         }
-
-        line = line_iter.next();
+        bingo_tips.push(builder.build());
     }
 
-    (drawn_numbers, bingo_tips)
+    Ok((drawn_numbers, bingo_tips))
 }
 
 struct BingoTip {
@@ -114,11 +97,15 @@ impl Default for BingoTipBuilder {
 }
 
 impl BingoTipBuilder {
-    fn feed_str(&mut self, s: &str) {
+    fn feed_str(&mut self, s: Span) -> Result<(), ParseError> {
         for n in s.split_whitespace() {
-            self.numbers[self.n_numbers_feeded] = n.parse().unwrap();
+            if self.buildable() {
+                return Err(n.error("the end of the board"));
+            }
+            self.numbers[self.n_numbers_feeded] = n.parse("a board number")?;
             self.n_numbers_feeded += 1;
         }
+        Ok(())
     }
 
     fn buildable(&self) -> bool {
@@ -134,8 +121,8 @@ impl BingoTipBuilder {
     }
 }
 
-fn compute_solution_part_one(input: &str) -> usize {
-    let (drawn_numbers, mut bingo_tips) = parse_input(input);
+fn compute_solution_part_one(input: &str) -> Result<usize, ParseError> {
+    let (drawn_numbers, mut bingo_tips) = parse_input(input)?;
 
     let mut winning_score = None;
     for &n in drawn_numbers.iter() {
@@ -152,11 +139,11 @@ fn compute_solution_part_one(input: &str) -> usize {
         }
     }
 
-    winning_score.unwrap()
+    Ok(winning_score.unwrap())
 }
 
-fn compute_solution_part_two(input: &str) -> usize {
-    let (drawn_numbers, mut bingo_tips) = parse_input(input);
+fn compute_solution_part_two(input: &str) -> Result<usize, ParseError> {
+    let (drawn_numbers, mut bingo_tips) = parse_input(input)?;
     let mut unfinished: Vec<usize> = (0..bingo_tips.len()).collect();
     let mut last_score = None;
 
@@ -170,25 +157,33 @@ fn compute_solution_part_two(input: &str) -> usize {
             break;
         }
 
-        unfinished = unfinished
-            .iter()
-            .filter(|&&i| !bingo_tips[i].is_bingo())
-            .cloned()
-            .collect()
+        unfinished.retain(|&i| !bingo_tips[i].is_bingo())
     }
 
-    last_score.unwrap()
+    Ok(last_score.unwrap())
 }
 
-fn main() {
-    use std::env;
-    let args: Vec<String> = env::args().collect();
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2021
+    }
+
+    fn day(&self) -> u8 {
+        4
+    }
+
+    fn parse(&self, input: &Input) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input.trimmed())?);
+        Ok(true)
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
 
-    if args.len() != 2 {
-        println!("Usage: d04 <input filename>");
-    } else {
-        let input = read_file(&args[1]);
-        println!("solution 1 = {}", compute_solution_part_one(&input));
-        println!("solution 2 = {}", compute_solution_part_two(&input));
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
     }
 }
@@ -1,44 +1,30 @@
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
 use std::collections::BTreeMap;
 
-fn read_file(filename: &str) -> String {
-    use std::fs::File;
-    use std::io::prelude::*;
-
-    let mut f = File::open(filename).expect("file not found");
-    let mut contents = String::new();
-    f.read_to_string(&mut contents)
-        .expect("something went wrong reading the file");
-    contents.trim().to_owned()
-}
-
-fn parse_input(input: &str) -> Vec<Line> {
-    fn parse_tuple(t: &str) -> (i64, i64) {
-        let mut iter = t.split(',');
-        let x = iter.next().unwrap().parse().unwrap();
-        let y = iter.next().unwrap().parse().unwrap();
-        (x, y)
+fn parse_input(input: &str) -> Result<Vec<Line>, ParseError> {
+    fn parse_tuple(t: Span) -> Result<(i64, i64), ParseError> {
+        let (x, y) = t.split_once(",")?;
+        Ok((x.parse("an x coordinate")?, y.parse("a y coordinate")?))
     }
 
-    fn parse_line(line: &str) -> (i64, i64, i64, i64) {
-        let mut iter = line.split(" -> ");
-        let start = iter.next();
-        let end = iter.next();
-        assert!(start.is_some());
-        assert!(end.is_some());
-
-        let (x1, y1) = parse_tuple(start.unwrap());
-        let (x2, y2) = parse_tuple(end.unwrap());
-        (x1, y1, x2, y2)
+    fn parse_line(line: Span) -> Result<(i64, i64, i64, i64), ParseError> {
+        let (start, end) = line.split_once(" -> ")?;
+        let (x1, y1) = parse_tuple(start)?;
+        let (x2, y2) = parse_tuple(end)?;
+        if x1 != x2 && y1 != y2 && (x2 - x1).abs() != (y2 - y1).abs() {
+            return Err(line.error("a horizontal, vertical or diagonal line"));
+        }
+        Ok((x1, y1, x2, y2))
     }
 
     let mut result = Vec::new();
 
-    for line in input.lines() {
-        let (x1, y1, x2, y2) = parse_line(line);
+    for line in Span::new(input).lines() {
+        let (x1, y1, x2, y2) = parse_line(line)?;
         result.push(Line::from_coords(x1, y1, x2, y2));
     }
 
-    result
+    Ok(result)
 }
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, PartialOrd, Ord)]
@@ -130,8 +116,8 @@ impl Iterator for LineIter {
     }
 }
 
-fn compute_solution_part_one(input: &str) -> usize {
-    let lines = parse_input(input);
+fn compute_solution_part_one(input: &str) -> Result<usize, ParseError> {
+    let lines = parse_input(input)?;
 
     let mut full_lines: BTreeMap<Point, usize> = BTreeMap::new();
 
@@ -154,11 +140,11 @@ fn compute_solution_part_one(input: &str) -> usize {
         }
     }
 
-    result
+    Ok(result)
 }
 
-fn compute_solution_part_two(input: &str) -> usize {
-    let lines = parse_input(input);
+fn compute_solution_part_two(input: &str) -> Result<usize, ParseError> {
+    let lines = parse_input(input)?;
 
     let mut full_lines: BTreeMap<Point, usize> = BTreeMap::new();
 
@@ -177,18 +163,30 @@ fn compute_solution_part_two(input: &str) -> usize {
         }
     }
 
-    result
+    Ok(result)
 }
 
-fn main() {
-    use std::env;
-    let args: Vec<String> = env::args().collect();
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2021
+    }
+
+    fn day(&self) -> u8 {
+        5
+    }
+
+    fn parse(&self, input: &Input) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input.trimmed())?);
+        Ok(true)
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
 
-    if args.len() != 2 {
-        println!("Usage: d05 <input filename>");
-    } else {
-        let input = read_file(&args[1]);
-        println!("solution 1 = {}", compute_solution_part_one(&input));
-        println!("solution 2 = {}", compute_solution_part_two(&input));
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
     }
 }
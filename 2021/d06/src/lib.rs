@@ -0,0 +1,62 @@
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
+
+fn parse_input(input: &str) -> Result<[usize; 9], ParseError> {
+    let mut result = [0; 9];
+    for n in Span::new(input).split(",") {
+        let index: usize = n.parse("a timer")?;
+        if index >= result.len() {
+            return Err(n.error("a timer from 0 to 8"));
+        }
+        result[index] += 1;
+    }
+    Ok(result)
+}
+
+fn step(state: &[usize; 9]) -> [usize; 9] {
+    let mut new_state = [0; 9];
+    new_state[..(state.len() - 1)].clone_from_slice(&state[1..]);
+    new_state[8] += state[0];
+    new_state[6] += state[0];
+    new_state
+}
+
+fn compute_solution_part_one(input: &str) -> Result<usize, ParseError> {
+    let mut state = parse_input(input)?;
+    for _ in 0..80 {
+        state = step(&state);
+    }
+    Ok(state.iter().sum())
+}
+
+fn compute_solution_part_two(input: &str) -> Result<usize, ParseError> {
+    let mut state = parse_input(input)?;
+    for _ in 0..256 {
+        state = step(&state);
+    }
+    Ok(state.iter().sum())
+}
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2021
+    }
+
+    fn day(&self) -> u8 {
+        6
+    }
+
+    fn parse(&self, input: &Input) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input.trimmed())?);
+        Ok(true)
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
+    }
+}
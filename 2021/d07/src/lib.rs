@@ -0,0 +1,89 @@
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
+
+fn parse_input(input: &str) -> Result<Vec<i64>, ParseError> {
+    Span::new(input)
+        .split(",")
+        .map(|s| s.parse("a horizontal position"))
+        .collect()
+}
+
+fn compute_solution_part_one(input: &str) -> Result<i64, ParseError> {
+    let mut positions = parse_input(input)?;
+
+    // edge cases
+    if positions.len() <= 1 {
+        return Ok(0);
+    } else if positions.len() == 2 {
+        return Ok((positions[1] - positions[0]).abs());
+    }
+
+    fn fuel_cost(x: i64, positions: &[i64]) -> i64 {
+        positions.iter().map(|p| (p - x).abs()).sum()
+    }
+
+    let center = positions.len() / 2;
+    positions.select_nth_unstable(center);
+    let center_pos = positions[center];
+    let center_fuel = fuel_cost(center_pos, &positions);
+
+    if positions.len() % 2 == 1 {
+        return Ok(center_fuel);
+    }
+
+    let center_left = center - 1;
+    let center_left_pos = positions[center_left];
+    let center_left_fuel = fuel_cost(center_left_pos, &positions);
+
+    if center_fuel < center_left_fuel {
+        Ok(center_fuel)
+    } else {
+        Ok(center_left_fuel)
+    }
+}
+
+fn compute_solution_part_two(input: &str) -> Result<i64, ParseError> {
+    let positions = parse_input(input)?;
+
+    if positions.len() <= 1 {
+        return Ok(0);
+    }
+
+    let fuel_cost = |x: i64| -> i64 {
+        positions
+            .iter()
+            .map(|p| {
+                let d = (p - x).abs();
+                d * (d + 1) / 2
+            })
+            .sum()
+    };
+
+    let almost_mean: i64 = positions.iter().sum::<i64>() / (positions.len() as i64);
+
+    // Safety margin of three around the mean.
+    // Should be more than enough
+    Ok(((almost_mean - 3)..=(almost_mean + 3))
+        .map(fuel_cost)
+        .min()
+        .unwrap())
+}
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2021
+    }
+
+    fn day(&self) -> u8 {
+        7
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
+    }
+}
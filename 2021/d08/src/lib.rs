@@ -1,26 +1,16 @@
-fn read_file(filename: &str) -> String {
-    use std::fs::File;
-    use std::io::prelude::*;
-
-    let mut f = File::open(filename).expect("file not found");
-    let mut contents = String::new();
-    f.read_to_string(&mut contents)
-        .expect("something went wrong reading the file");
-    contents.trim().to_owned()
-}
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
 
-fn compute_solution_part_one(input: &str) -> usize {
+fn compute_solution_part_one(input: &str) -> Result<usize, ParseError> {
     let mut sum_1478 = 0;
-    for line in input.lines() {
-        let digits = line.split(" | ").nth(1).unwrap();
+    for (_, digits) in parse_input(input)? {
         sum_1478 += digits
-            .split_whitespace()
-            .map(|d| d.chars().count())
+            .iter()
+            .map(|d| d.count_active())
             .filter(|&c| c == 2 || c == 4 || c == 3 || c == 7)
             .count();
     }
 
-    sum_1478
+    Ok(sum_1478)
 }
 
 #[derive(Copy, Clone)]
@@ -35,9 +25,9 @@ impl SegmentConfiguration {
         Self { active: [false; 7] }
     }
 
-    fn from_str(s: &str) -> Self {
+    fn from_str(s: Span) -> Result<Self, ParseError> {
         let mut result = Self::zeros();
-        for c in s.chars() {
+        for (span, c) in s.chars() {
             match c {
                 'a' => result.active[0] = true,
                 'b' => result.active[1] = true,
@@ -46,10 +36,10 @@ impl SegmentConfiguration {
                 'e' => result.active[4] = true,
                 'f' => result.active[5] = true,
                 'g' => result.active[6] = true,
-                _ => panic!("invalid segment configuration"),
+                _ => return Err(span.error("a segment from `a` to `g`")),
             }
         }
-        result
+        Ok(result)
     }
 
     fn count_active(&self) -> usize {
@@ -71,23 +61,31 @@ impl SegmentConfiguration {
     }
 }
 
-fn get_entry_value(entry: &str) -> usize {
-    let mut samples = [SegmentConfiguration::zeros(); 10];
-    let mut digits = [SegmentConfiguration::zeros(); 4];
-    let samples_iter = entry.split(" | ").next().unwrap().split_whitespace();
-    let digits_iter = entry.split(" | ").nth(1).unwrap().split_whitespace();
+type Entry = ([SegmentConfiguration; 10], [SegmentConfiguration; 4]);
 
-    for (i, s) in samples_iter.enumerate() {
-        samples[i] = SegmentConfiguration::from_str(s);
+fn parse_configurations(s: Span, result: &mut [SegmentConfiguration]) -> Result<(), ParseError> {
+    let mut iter = s.split_whitespace();
+    for configuration in result.iter_mut() {
+        *configuration = SegmentConfiguration::from_str(iter.expect("a segment pattern")?)?;
     }
+    iter.end()
+}
 
-    for (i, s) in digits_iter.enumerate() {
-        digits[i] = SegmentConfiguration::from_str(s);
+fn parse_input(input: &str) -> Result<Vec<Entry>, ParseError> {
+    let mut result = Vec::new();
+    for line in Span::new(input).lines() {
+        let (samples_span, digits_span) = line.split_once(" | ")?;
+        let mut samples = [SegmentConfiguration::zeros(); 10];
+        let mut digits = [SegmentConfiguration::zeros(); 4];
+        parse_configurations(samples_span, &mut samples)?;
+        parse_configurations(digits_span, &mut digits)?;
+        result.push((samples, digits));
     }
+    Ok(result)
+}
 
-    // All digits assigned?
-    assert_eq!(samples.iter().filter(|s| s.count_active() != 0).count(), 10);
-    assert_eq!(digits.iter().filter(|s| s.count_active() != 0).count(), 4);
+fn get_entry_value(entry: &Entry) -> usize {
+    let (mut samples, digits) = *entry;
 
     let mut identified_digits = [SegmentConfiguration::zeros(); 10];
 
@@ -148,23 +146,30 @@ fn get_entry_value(entry: &str) -> usize {
     result
 }
 
-fn compute_solution_part_two(input: &str) -> usize {
+fn compute_solution_part_two(input: &str) -> Result<usize, ParseError> {
     let mut result = 0;
-    for line in input.lines() {
-        result += get_entry_value(line);
+    for entry in parse_input(input)?.iter() {
+        result += get_entry_value(entry);
     }
-    result
+    Ok(result)
 }
 
-fn main() {
-    use std::env;
-    let args: Vec<String> = env::args().collect();
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2021
+    }
+
+    fn day(&self) -> u8 {
+        8
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
 
-    if args.len() != 2 {
-        println!("Usage: d08 <input filename>");
-    } else {
-        let input = read_file(&args[1]);
-        println!("solution 1 = {}", compute_solution_part_one(&input));
-        println!("solution 2 = {}", compute_solution_part_two(&input));
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
     }
 }
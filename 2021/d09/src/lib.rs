@@ -0,0 +1,125 @@
+use aoc_common::{Answer, Input, ParseError, Puzzle};
+use aoc_grid::Grid;
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2021
+    }
+
+    fn day(&self) -> u8 {
+        9
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
+    }
+}
+
+fn compute_solution_part_one(input: &str) -> Result<usize, ParseError> {
+    let map = CaveMap::from_str(input)?;
+    let mut s = 0;
+
+    for (i, j) in map.tiles.positions() {
+        if map.is_lowpoint(i, j) {
+            s += map.tiles[(i, j)] as usize;
+            s += 1;
+        }
+    }
+    Ok(s)
+}
+
+fn compute_solution_part_two(input: &str) -> Result<usize, ParseError> {
+    let map = CaveMap::from_str(input)?;
+    let mut three_largest = [0usize; 3];
+
+    fn maybe_insert_value(newval: usize, three_largest: &mut [usize; 3]) {
+        if newval > three_largest[0] {
+            three_largest[2] = three_largest[1];
+            three_largest[1] = three_largest[0];
+            three_largest[0] = newval;
+        } else if newval > three_largest[1] {
+            three_largest[2] = three_largest[1];
+            three_largest[1] = newval;
+        } else if newval > three_largest[2] {
+            three_largest[2] = newval;
+        }
+    }
+
+    for (i, j) in map.tiles.positions() {
+        let basin_size = map.basin_size(i, j);
+        maybe_insert_value(basin_size, &mut three_largest);
+    }
+
+    Ok(three_largest[0] * three_largest[1] * three_largest[2])
+}
+
+struct CaveMap {
+    tiles: Grid<u8>,
+}
+
+impl CaveMap {
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        let tiles = Grid::parse(input, "a height", |c| c.to_digit(10).map(|d| d as u8))?;
+        Ok(Self { tiles })
+    }
+
+    fn is_lowpoint(&self, row: usize, col: usize) -> bool {
+        let val = self.tiles[(row, col)];
+
+        for (i, j) in self.tiles.neighbors4(row, col) {
+            let neighbor_val = self.tiles[(i, j)];
+            if neighbor_val <= val {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    // Count basin size if (row, col) is a lowpoint
+    // Otherwise, return 0
+    fn basin_size(&self, row: usize, col: usize) -> usize {
+        use std::collections::BTreeSet;
+
+        if !self.is_lowpoint(row, col) {
+            return 0;
+        }
+
+        let mut n_lowpoints = 0;
+
+        let mut todo = vec![(row, col)];
+        let mut done = BTreeSet::new();
+
+        while let Some((i, j)) = todo.pop() {
+            if done.contains(&(i, j)) {
+                continue;
+            }
+            done.insert((i, j));
+
+            if self.is_lowpoint(i, j) {
+                n_lowpoints += 1;
+            }
+
+            for (ni, nj) in self.tiles.neighbors4(i, j) {
+                if done.contains(&(ni, nj)) {
+                    continue;
+                }
+
+                let val = self.tiles[(ni, nj)];
+                if val != 9u8 {
+                    todo.push((ni, nj));
+                }
+            }
+        }
+
+        assert_eq!(n_lowpoints, 1, "not implemented");
+
+        done.len()
+    }
+}
@@ -1,34 +1,40 @@
-fn main() {
-    use std::env;
-    let args: Vec<String> = env::args().collect();
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
 
-    if args.len() != 2 {
-        println!("Usage: d10 <input filename>");
-    } else {
-        let input = read_file(&args[1]);
-        println!("solution 1 = {}", compute_solution_part_one(&input));
-        println!("solution 2 = {}", compute_solution_part_two(&input));
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2021
+    }
+
+    fn day(&self) -> u8 {
+        10
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
     }
 }
 
-fn compute_solution_part_one(input: &str) -> usize {
+fn compute_solution_part_one(input: &str) -> Result<usize, ParseError> {
     let mut result = 0;
-
-    for line in input.lines() {
-        let r = check_line(line);
+    for line in Span::new(input).lines() {
+        let r = check_line(line)?;
         if let CheckLineResult::Corrupt(c) = r {
             result += points_corrupt(c);
         }
     }
-
-    result
+    Ok(result)
 }
 
-fn compute_solution_part_two(input: &str) -> usize {
+fn compute_solution_part_two(input: &str) -> Result<usize, ParseError> {
     let mut points = Vec::new();
-
-    for line in input.lines() {
-        let r = check_line(line);
+    for line in Span::new(input).lines() {
+        let r = check_line(line)?;
         if let CheckLineResult::Incomplete(mut s) = r {
             s.reverse();
             points.push(
@@ -47,13 +53,13 @@ fn compute_solution_part_two(input: &str) -> usize {
     );
     let median_index = points.len() / 2;
     points.select_nth_unstable(median_index);
-    points[median_index]
+    Ok(points[median_index])
 }
 
-fn check_line(line: &str) -> CheckLineResult {
+fn check_line(line: Span) -> Result<CheckLineResult, ParseError> {
     let mut state = Vec::new();
     let mut breaking_char = None;
-    for c in line.chars() {
+    for (span, c) in line.chars() {
         match c {
             '(' | '[' | '{' | '<' => state.push(c),
             ')' | ']' | '}' | '>' => {
@@ -68,16 +74,16 @@ fn check_line(line: &str) -> CheckLineResult {
                     break;
                 }
             }
-            _ => panic!("invalid input."),
+            _ => return Err(span.error("a bracket")),
         }
     }
 
     if let Some(c) = breaking_char {
-        CheckLineResult::Corrupt(c)
+        Ok(CheckLineResult::Corrupt(c))
     } else if state.is_empty() {
-        CheckLineResult::Complete
+        Ok(CheckLineResult::Complete)
     } else {
-        CheckLineResult::Incomplete(state)
+        Ok(CheckLineResult::Incomplete(state))
     }
 }
 
@@ -120,14 +126,3 @@ fn inverse_bracket(c: char) -> char {
         _ => panic!("invalid input: {}", c),
     }
 }
-
-fn read_file(filename: &str) -> String {
-    use std::fs::File;
-    use std::io::prelude::*;
-
-    let mut f = File::open(filename).expect("file not found");
-    let mut contents = String::new();
-    f.read_to_string(&mut contents)
-        .expect("something went wrong reading the file");
-    contents.trim().to_owned()
-}
@@ -0,0 +1,108 @@
+use aoc_common::{Answer, Input, ParseError, Puzzle};
+use aoc_grid::Grid;
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2021
+    }
+
+    fn day(&self) -> u8 {
+        11
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
+    }
+}
+
+fn compute_solution_part_one(input: &str) -> Result<usize, ParseError> {
+    let mut octopus_map = OctopusMap::from_str(input)?;
+    let mut result = 0;
+
+    for _ in 0..100 {
+        result += octopus_map.step();
+    }
+    Ok(result)
+}
+
+fn compute_solution_part_two(input: &str) -> Result<usize, ParseError> {
+    let mut octopus_map = OctopusMap::from_str(input)?;
+    let mut step_number = 0;
+    loop {
+        let n_flashes = octopus_map.step();
+        step_number += 1;
+
+        if n_flashes == octopus_map.data.width() * octopus_map.data.height() {
+            return Ok(step_number);
+        }
+    }
+}
+
+struct OctopusMap {
+    data: Grid<CellState>,
+}
+
+impl OctopusMap {
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        let data = Grid::parse(input, "an energy level", |c| {
+            c.to_digit(10).map(|d| CellState::new(d as usize))
+        })?;
+        Ok(Self { data })
+    }
+
+    // Performs a step and returns the number of flashes
+    fn step(&mut self) -> usize {
+        for c in self.data.values_mut() {
+            c.energy_level += 1;
+        }
+
+        let mut changed = true;
+
+        while changed {
+            changed = false;
+
+            for (i, j) in self.data.positions() {
+                let cell = &mut self.data[(i, j)];
+                if cell.energy_level < 10 || cell.flash_in_current_step {
+                    continue;
+                }
+
+                changed = true;
+                cell.flash_in_current_step = true;
+                let neighbors: Vec<_> = self.data.neighbors8(i, j).collect();
+                for (ni, nj) in neighbors {
+                    self.data[(ni, nj)].energy_level += 1;
+                }
+            }
+        }
+
+        self.data
+            .values_mut()
+            .filter(|s| s.flash_in_current_step)
+            .map(|s| {
+                s.flash_in_current_step = false;
+                s.energy_level = 0;
+            })
+            .count()
+    }
+}
+
+struct CellState {
+    energy_level: usize,
+    flash_in_current_step: bool,
+}
+
+impl CellState {
+    fn new(energy_level: usize) -> Self {
+        Self {
+            energy_level,
+            flash_in_current_step: false,
+        }
+    }
+}
@@ -1,34 +1,43 @@
-fn main() {
-    use std::env;
-    let args: Vec<String> = env::args().collect();
-
-    if args.len() != 2 {
-        println!("Usage: d12 <input filename>");
-    } else {
-        let input = read_file(&args[1]);
-        println!("solution 1 = {}", compute_solution_part_one(&input));
-        println!("solution 2 = {}", compute_solution_part_two(&input));
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2021
+    }
+
+    fn day(&self) -> u8 {
+        12
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
     }
 }
 
-fn compute_solution_part_one(input: &str) -> usize {
-    let cavegraph = CaveGraph::from_str(input);
+fn compute_solution_part_one(input: &str) -> Result<usize, ParseError> {
+    let cavegraph = CaveGraph::from_str(input)?;
     let node = NodeList {
         id: cavegraph.id_start,
         prev: None,
     };
 
-    compute_ways_no_double_small_cave(&node, &cavegraph)
+    Ok(compute_ways_no_double_small_cave(&node, &cavegraph))
 }
 
-fn compute_solution_part_two(input: &str) -> usize {
-    let cavegraph = CaveGraph::from_str(input);
+fn compute_solution_part_two(input: &str) -> Result<usize, ParseError> {
+    let cavegraph = CaveGraph::from_str(input)?;
     let node = NodeList {
         id: cavegraph.id_start,
         prev: None,
     };
 
-    compute_ways_one_double_small_cave(&node, &cavegraph)
+    Ok(compute_ways_one_double_small_cave(&node, &cavegraph))
 }
 
 fn compute_ways_no_double_small_cave(prev: &NodeList, cavegraph: &CaveGraph) -> usize {
@@ -105,19 +114,24 @@ struct CaveGraph {
 }
 
 impl CaveGraph {
-    fn from_str(input: &str) -> Self {
+    fn from_str(input: &str) -> Result<Self, ParseError> {
         use std::cmp::Ordering;
 
         fn is_uppercase(s: &str) -> bool {
             s.to_uppercase() == s
         }
 
+        let input = Span::new(input);
+        let mut edges = Vec::new();
         let mut names = Vec::new();
         for line in input.lines() {
-            for (i, s) in line.split('-').enumerate() {
-                assert!(i < 2);
-                names.push(s.to_owned());
+            let (name_a, name_b) = line.split_once("-")?;
+            if is_uppercase(name_a.as_str()) && is_uppercase(name_b.as_str()) {
+                return Err(line.error("a connection involving a small cave"));
             }
+            names.push(name_a.as_str().to_owned());
+            names.push(name_b.as_str().to_owned());
+            edges.push((name_a.as_str(), name_b.as_str()));
         }
 
         names.sort_by(|a, b| {
@@ -137,8 +151,14 @@ impl CaveGraph {
         let n_large_nodes = names.iter().filter(|s| is_uppercase(s)).count();
         let n_small_nodes = names.len() - n_large_nodes;
 
-        let id_start = names.iter().position(|s| s.as_str() == "start").unwrap();
-        let id_end = names.iter().position(|s| s.as_str() == "end").unwrap();
+        let id_start = names
+            .iter()
+            .position(|s| s.as_str() == "start")
+            .ok_or_else(|| input.end().error("a connection to the `start` cave"))?;
+        let id_end = names
+            .iter()
+            .position(|s| s.as_str() == "end")
+            .ok_or_else(|| input.end().error("a connection to the `end` cave"))?;
 
         let mut result = Self {
             n_small_nodes,
@@ -149,11 +169,7 @@ impl CaveGraph {
             names,
         };
 
-        for line in input.lines() {
-            let mut line_iter = line.split('-');
-            let name_a = line_iter.next().unwrap();
-            let name_b = line_iter.next().unwrap();
-
+        for (name_a, name_b) in edges {
             let id_a = result
                 .names
                 .iter()
@@ -165,12 +181,10 @@ impl CaveGraph {
                 .position(|s| s.as_str() == name_b)
                 .unwrap();
 
-            assert!(!(result.is_large_node(id_a) && result.is_large_node(id_b)));
-
             result.insert_edge(id_a, id_b);
         }
 
-        result
+        Ok(result)
     }
 
     fn insert_edge(&mut self, id_a: usize, id_b: usize) {
@@ -218,11 +232,7 @@ impl CaveGraph {
         id < self.n_small_nodes
     }
 
-    fn is_large_node(&self, id: usize) -> bool {
-        id >= self.n_small_nodes
-    }
-
-    fn connections(&self, id: usize) -> ConnectionsIter {
+    fn connections(&self, id: usize) -> ConnectionsIter<'_> {
         assert!(id < self.n_small_nodes + self.n_large_nodes);
 
         let pos = self.data[2 * id];
@@ -261,14 +271,3 @@ impl<'a> Iterator for ConnectionsIter<'a> {
         result
     }
 }
-
-fn read_file(filename: &str) -> String {
-    use std::fs::File;
-    use std::io::prelude::*;
-
-    let mut f = File::open(filename).expect("file not found");
-    let mut contents = String::new();
-    f.read_to_string(&mut contents)
-        .expect("something went wrong reading the file");
-    contents.trim().to_owned()
-}
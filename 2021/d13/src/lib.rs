@@ -1,84 +1,83 @@
-fn main() {
-    use std::env;
-    let args: Vec<String> = env::args().collect();
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
+use aoc_grid::{ocr, Grid};
 
-    if args.len() != 2 {
-        println!("Usage: d13 <input filename>");
-    } else {
-        let input = read_file(&args[1]);
-        println!("solution 1 = {}", compute_solution_part_one(&input));
-        println!("solution 2 = \n{}", compute_solution_part_two(&input));
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2021
+    }
+
+    fn day(&self) -> u8 {
+        13
+    }
+
+    fn parse(&self, input: &Input) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input.trimmed())?);
+        Ok(true)
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
     }
 }
 
-fn compute_solution_part_one(input: &str) -> usize {
-    let (mut paper, folds) = parse_input(input);
+fn compute_solution_part_one(input: &str) -> Result<usize, ParseError> {
+    let (mut paper, folds) = parse_input(input)?;
     paper = paper.folded(folds[0]);
-    paper.count_dots()
+    Ok(paper.count_dots())
 }
 
-fn compute_solution_part_two(input: &str) -> String {
-    let (mut paper, folds) = parse_input(input);
+fn compute_solution_part_two(input: &str) -> Result<String, ParseError> {
+    let (mut paper, folds) = parse_input(input)?;
     for f in folds.iter() {
         paper = paper.folded(*f);
     }
-    paper.to_string()
+    let image = Grid::new(paper.width, paper.height, paper.data);
+    ocr::recognize_at(&image, Span::new(input).end())
 }
 
-fn parse_input(input: &str) -> (DottedPaper, Vec<Fold>) {
-    let mut max_x: usize = 0;
-    let mut max_y: usize = 0;
-    for line in input.lines() {
-        if line.is_empty() {
-            break;
-        }
-        let mut line_iter = line.split(',');
-        let x = line_iter.next().unwrap().parse().unwrap();
-        let y = line_iter.next().unwrap().parse().unwrap();
-        if x > max_x {
-            max_x = x;
-        }
-        if y > max_y {
-            max_y = y;
-        }
-    }
-
-    let mut paper = DottedPaper::zeros(max_x + 1, max_y + 1);
+fn parse_input(input: &str) -> Result<(DottedPaper, Vec<Fold>), ParseError> {
+    let mut input_blocks = Span::new(input).blocks();
+    let dots: Vec<(usize, usize)> = input_blocks
+        .expect("the dots")?
+        .lines()
+        .map(|line| {
+            let (x, y) = line.split_once(",")?;
+            Ok((x.parse("an x coordinate")?, y.parse("a y coordinate")?))
+        })
+        .collect::<Result<_, ParseError>>()?;
 
-    let mut lines = input.lines();
+    let max_x = dots.iter().map(|&(x, _)| x).max().unwrap_or(0);
+    let max_y = dots.iter().map(|&(_, y)| y).max().unwrap_or(0);
 
-    loop {
-        let line = lines.next().unwrap();
-        if line.is_empty() {
-            break;
-        }
-        let mut line_iter = line.split(',');
-        let x = line_iter.next().unwrap().parse().unwrap();
-        let y = line_iter.next().unwrap().parse().unwrap();
+    let mut paper = DottedPaper::zeros(max_x + 1, max_y + 1);
+    for &(x, y) in &dots {
         paper.set(x, y, true);
     }
 
     let mut folds = Vec::new();
-
-    loop {
-        let line = lines.next();
-        if line.is_none() {
-            break;
-        }
-        let line = line.unwrap();
-
-        let mut line_iter = line.split('=');
-        let text = line_iter.next().unwrap();
-        let amount = line_iter.next().unwrap().parse().unwrap();
-
-        match text {
-            "fold along x" => folds.push(Fold::Vertical(amount)),
-            "fold along y" => folds.push(Fold::Horizontal(amount)),
-            _ => panic!("invalid input"),
+    if let Some(block) = input_blocks.next() {
+        for line in block.lines() {
+            let (text, amount) = line.split_once("=")?;
+            let amount = amount.parse("a fold position")?;
+            match text.as_str() {
+                "fold along x" => folds.push(Fold::Vertical(amount)),
+                "fold along y" => folds.push(Fold::Horizontal(amount)),
+                _ => return Err(text.error("`fold along x` or `fold along y`")),
+            }
         }
     }
+    input_blocks.end()?;
+    if folds.is_empty() {
+        return Err(Span::new(input).end().error("a fold instruction"));
+    }
 
-    (paper, folds)
+    Ok((paper, folds))
 }
 
 struct DottedPaper {
@@ -100,26 +99,6 @@ impl DottedPaper {
         self.data.iter().filter(|d| **d).count()
     }
 
-    fn to_string(&self) -> String {
-        self.data
-            .iter()
-            .enumerate()
-            .map(|(i, c)| {
-                let x = i % self.width;
-                let do_break = x == self.width - 1;
-                if *c && do_break {
-                    "#\n"
-                } else if *c {
-                    "#"
-                } else if do_break {
-                    ".\n"
-                } else {
-                    "."
-                }
-            })
-            .collect()
-    }
-
     fn get(&self, x: usize, y: usize) -> bool {
         assert!(x < self.width);
         assert!(y < self.height);
@@ -197,19 +176,32 @@ impl DottedPaper {
     }
 }
 
+impl std::fmt::Display for DottedPaper {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let s: String = self
+            .data
+            .iter()
+            .enumerate()
+            .map(|(i, c)| {
+                let x = i % self.width;
+                let do_break = x == self.width - 1;
+                if *c && do_break {
+                    "#\n"
+                } else if *c {
+                    "#"
+                } else if do_break {
+                    ".\n"
+                } else {
+                    "."
+                }
+            })
+            .collect();
+        write!(f, "{}", s)
+    }
+}
+
 #[derive(Copy, Clone)]
 enum Fold {
     Horizontal(usize),
     Vertical(usize),
 }
-
-fn read_file(filename: &str) -> String {
-    use std::fs::File;
-    use std::io::prelude::*;
-
-    let mut f = File::open(filename).expect("file not found");
-    let mut contents = String::new();
-    f.read_to_string(&mut contents)
-        .expect("something went wrong reading the file");
-    contents.trim().to_owned()
-}
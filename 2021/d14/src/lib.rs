@@ -0,0 +1,114 @@
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
+use std::collections::HashMap;
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2021
+    }
+
+    fn day(&self) -> u8 {
+        14
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
+    }
+}
+
+fn compute_solution_part_one(input: &str) -> Result<usize, ParseError> {
+    count_max_min(input, 10)
+}
+
+fn compute_solution_part_two(input: &str) -> Result<usize, ParseError> {
+    count_max_min(input, 40)
+}
+
+type Substitutions = HashMap<(char, char), char>;
+
+fn parse_element(span: Span) -> Result<char, ParseError> {
+    let mut chars = span.chars();
+    match (chars.next(), chars.next()) {
+        (Some((_, c)), None) => Ok(c),
+        _ => Err(span.error("a single element")),
+    }
+}
+
+fn parse_input(input: &str) -> Result<(Vec<char>, Substitutions), ParseError> {
+    let mut blocks = Span::new(input).blocks();
+    let template = blocks.expect("a polymer template")?;
+    let init_state_vec: Vec<char> = template.as_str().chars().collect();
+    if init_state_vec.is_empty() {
+        return Err(template.error("a polymer template"));
+    }
+
+    let mut substitutions = HashMap::new();
+    for line in blocks.expect("the insertion rules")?.lines() {
+        let (pair, r) = line.split_once(" -> ")?;
+        let mut pair_iter = pair.chars().map(|(_, c)| c);
+        let (a, b) = match (pair_iter.next(), pair_iter.next(), pair_iter.next()) {
+            (Some(a), Some(b), None) => (a, b),
+            _ => return Err(pair.error("a pair of elements")),
+        };
+        substitutions.insert((a, b), parse_element(r)?);
+    }
+    blocks.end()?;
+
+    Ok((init_state_vec, substitutions))
+}
+
+fn count_max_min(input: &str, turns: usize) -> Result<usize, ParseError> {
+    let (init_state_vec, substitutions) = parse_input(input)?;
+    let first = *init_state_vec.first().unwrap();
+    let last = *init_state_vec.last().unwrap();
+    let mut state: HashMap<(char, char), usize> = HashMap::new();
+
+    for w in init_state_vec.windows(2) {
+        let n = state.entry((w[0], w[1])).or_insert(0);
+        *n += 1;
+    }
+
+    for _ in 0..turns {
+        let mut new_state: HashMap<(char, char), usize> = HashMap::new();
+
+        for (k, v) in state.iter() {
+            let a = k.0;
+            let b = k.1;
+
+            match substitutions.get(&(a, b)) {
+                Some(&c) => {
+                    let n = new_state.entry((a, c)).or_insert(0);
+                    *n += v;
+                    let n = new_state.entry((c, b)).or_insert(0);
+                    *n += v;
+                }
+                None => {
+                    let n = new_state.entry((a, b)).or_insert(0);
+                    *n += v;
+                }
+            }
+        }
+
+        state = new_state;
+    }
+
+    let mut counter: HashMap<char, usize> = HashMap::new();
+    counter.insert(first, 1);
+    counter.insert(last, 1);
+    for (&(a, b), v) in state.iter() {
+        let n = counter.entry(a).or_insert(0);
+        *n += v;
+        let n = counter.entry(b).or_insert(0);
+        *n += v;
+    }
+
+    let max = counter.values().max().unwrap();
+    let min = counter.values().min().unwrap();
+
+    Ok((max - min) / 2)
+}
@@ -0,0 +1,126 @@
+use aoc_common::{Answer, Input, ParseError, Puzzle};
+use aoc_grid::Grid;
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2021
+    }
+
+    fn day(&self) -> u8 {
+        15
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
+    }
+}
+
+fn compute_solution_part_one(input: &str) -> Result<usize, ParseError> {
+    let chitons = parse_chitons(input)?;
+    Ok(smallest_risk(&chitons))
+}
+
+fn compute_solution_part_two(input: &str) -> Result<usize, ParseError> {
+    let small_chitons = parse_chitons(input)?;
+    let chitons = increase_size(&small_chitons);
+    Ok(smallest_risk(&chitons))
+}
+
+// Compute the smalles risk to go from the top left, to the bottom right
+fn smallest_risk(chitons: &Grid<usize>) -> usize {
+    use std::collections::BinaryHeap;
+    use std::cmp::Reverse;
+
+    let mut risk = Grid::filled(chitons.width(), chitons.height(), None);
+    risk[(0, 0)] = Some(0);
+
+    let mut todo = BinaryHeap::new();
+    todo.push(Reverse(RiskEntry::new(0, 0, 0)));
+
+    while !todo.is_empty() {
+        let risk_entry = todo.pop().unwrap();
+
+        let i = risk_entry.0.i;
+        let j = risk_entry.0.j;
+        let r = risk_entry.0.risk;
+
+        if r > risk[(i, j)].unwrap() {
+            continue;
+        }
+
+        for (ni, nj) in chitons.neighbors4(i, j) {
+            let new_r = r + chitons[(ni, nj)];
+            let old_r = risk[(ni, nj)];
+
+            let do_set = match old_r {
+                Some(old_r) => old_r > new_r,
+                None => true,
+            };
+
+            if do_set {
+                todo.push(Reverse(RiskEntry::new(ni, nj, new_r)));
+                risk[(ni, nj)] = Some(new_r);
+            }
+        }
+    }
+
+    risk[(risk.height() - 1, risk.width() - 1)].unwrap()
+}
+
+fn increase_size(chitons: &Grid<usize>) -> Grid<usize> {
+    Grid::from_fn(chitons.width() * 5, chitons.height() * 5, |i, j| {
+        let add_i = i / chitons.height();
+        let add_j = j / chitons.width();
+
+        let mut new_val = *chitons.get_wrapping(i, j);
+        new_val += add_i + add_j;
+        (new_val - 1) % 9 + 1
+    })
+}
+
+fn parse_chitons(input: &str) -> Result<Grid<usize>, ParseError> {
+    Grid::parse(input, "a risk level", |c| c.to_digit(10).map(|d| d as usize))
+}
+
+#[derive(Eq, PartialEq)]
+struct RiskEntry {
+    i: usize,
+    j: usize,
+    risk: usize,
+}
+
+impl RiskEntry {
+    fn new(i: usize, j: usize, risk: usize) -> Self {
+        Self {
+            i,
+            j,
+            risk,
+        }
+    }
+}
+
+impl PartialOrd for RiskEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering > {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for RiskEntry {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        if self.risk < other.risk {
+            std::cmp::Ordering::Less
+        } else if self.risk > other.risk {
+            std::cmp::Ordering::Greater
+        } else {
+            let dist_self = self.i + self.j;
+            let dist_other = other.i + other.j;
+            dist_other.cmp(&dist_self)
+        }
+    }
+}
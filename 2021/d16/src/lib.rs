@@ -0,0 +1,355 @@
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2021
+    }
+
+    fn day(&self) -> u8 {
+        16
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
+    }
+}
+
+fn compute_solution_part_one(input: &str) -> Result<u64, ParseError> {
+    let transmission = Transmission::from_str(input)?;
+    let packet = transmission.parse_outermost_packet()?;
+
+    Ok(packet.version_sum())
+}
+
+fn compute_solution_part_two(input: &str) -> Result<u64, ParseError> {
+    let transmission = Transmission::from_str(input)?;
+    let packet = transmission.parse_outermost_packet()?;
+
+    Ok(packet.eval())
+}
+
+// The bits of the hexadecimal input, together with the input itself
+// to be able to point at the digit that holds a broken packet.
+struct Transmission<'a> {
+    input: Span<'a>,
+    bits_stream: Vec<bool>,
+}
+
+impl<'a> Transmission<'a> {
+    fn from_str(input: &'a str) -> Result<Self, ParseError> {
+        let input = Span::new(input);
+        if input.is_empty() {
+            return Err(input.error("a hexadecimal digit"));
+        }
+
+        let mut bits_stream = Vec::new();
+        for (span, c) in input.chars() {
+            push_bits(&mut bits_stream, c).ok_or_else(|| span.error("a hexadecimal digit"))?;
+        }
+
+        Ok(Self { input, bits_stream })
+    }
+
+    fn error_at(&self, index: usize, expected: &str) -> ParseError {
+        let n = self.input.as_str().len();
+        let digit = (index / 4).min(n);
+        self.input.slice(digit, (digit + 1).min(n)).error(expected)
+    }
+
+    // Reads `n_bits` starting at `index`, without going beyond `end`.
+    fn read(&self, index: usize, end: usize, n_bits: usize) -> Result<u64, ParseError> {
+        if index + n_bits > end {
+            return Err(self.error_at(end, "more bits of the packet"));
+        }
+        Ok(parse_slice(&self.bits_stream[index..(index + n_bits)]))
+    }
+
+    fn parse_outermost_packet(&self) -> Result<Packet, ParseError> {
+        let (_, mut packet) = self.parse_packets(0, self.bits_stream.len(), Some(1))?;
+        Ok(packet.pop().unwrap())
+    }
+
+    // Parses packets in the bits `start..end` and returns the number of bits read.
+    fn parse_packets(
+        &self,
+        start: usize,
+        end: usize,
+        max_packets: Option<usize>,
+    ) -> Result<(usize, Vec<Packet>), ParseError> {
+        let mut result = Vec::new();
+        let mut index = start;
+
+        while index < end {
+            if let Some(m) = max_packets {
+                if result.len() >= m {
+                    break;
+                }
+            }
+
+            let packet_start = index;
+
+            let packet_version = self.read(index, end, 3)?;
+            index += 3;
+
+            let packet_type_id = self.read(index, end, 3)?;
+            index += 3;
+
+            if packet_type_id == 4 {
+                let (i, n) = self.parse_literal(index, end)?;
+                index += i;
+
+                let s = PacketLiteral {
+                    _version: packet_version,
+                    val: n,
+                };
+
+                result.push(Packet::Literal(s));
+
+                continue;
+            }
+
+            let length_type = self.read(index, end, 1)? == 1;
+            index += 1;
+
+            let mut subpackets = if length_type {
+                let n_packets = self.read(index, end, 11)? as usize;
+                index += 11;
+                let (i, packets) = self.parse_packets(index, end, Some(n_packets))?;
+                index += i;
+                packets
+            } else {
+                let n_bits = self.read(index, end, 15)? as usize;
+                index += 15;
+                if index + n_bits > end {
+                    return Err(self.error_at(end, "more bits of the sub-packets"));
+                }
+                let (i, packets) = self.parse_packets(index, index + n_bits, None)?;
+                index += i;
+                packets
+            };
+
+            let new_packet = match packet_type_id {
+                0..=3 => {
+                    if subpackets.is_empty() {
+                        return Err(self.error_at(packet_start, "an operator with sub-packets"));
+                    }
+
+                    let t = match packet_type_id {
+                        0 => PacketArrayType::Sum,
+                        1 => PacketArrayType::Product,
+                        2 => PacketArrayType::Minimum,
+                        _ => PacketArrayType::Maximum,
+                    };
+
+                    let s = PacketArraylike {
+                        _version: packet_version,
+                        t,
+                        children: subpackets,
+                    };
+
+                    Packet::Arraylike(s)
+                }
+                _ => {
+                    if subpackets.len() != 2 {
+                        return Err(
+                            self.error_at(packet_start, "a comparison with two sub-packets")
+                        );
+                    }
+
+                    let t = match packet_type_id {
+                        5 => PacketBinaryType::Greater,
+                        6 => PacketBinaryType::Less,
+                        _ => PacketBinaryType::Equal,
+                    };
+
+                    let right = subpackets.pop().unwrap();
+                    let left = subpackets.pop().unwrap();
+
+                    let s = PacketBinary {
+                        _version: packet_version,
+                        t,
+                        left: Box::new(left),
+                        right: Box::new(right),
+                    };
+
+                    Packet::Binary(s)
+                }
+            };
+
+            result.push(new_packet);
+        }
+
+        if let Some(m) = max_packets {
+            if result.len() < m {
+                return Err(self.error_at(end, "another packet"));
+            }
+        }
+
+        Ok((index - start, result))
+    }
+
+    fn parse_literal(&self, start: usize, end: usize) -> Result<(usize, u64), ParseError> {
+        let mut result = 0;
+        let mut keep_reading = true;
+        let mut index = start;
+
+        while keep_reading {
+            keep_reading = self.read(index, end, 1)? == 1;
+            result <<= 4;
+            result += self.read(index + 1, end, 4)?;
+            index += 5;
+        }
+
+        Ok((index - start, result))
+    }
+}
+
+trait PacketTrait {
+    fn version_sum(&self) -> u64;
+    fn eval(&self) -> u64;
+}
+
+enum Packet {
+    Literal(PacketLiteral),
+    Arraylike(PacketArraylike),
+    Binary(PacketBinary),
+}
+
+impl PacketTrait for Packet {
+    fn version_sum(&self) -> u64 {
+        match self {
+            Packet::Literal(p) => p.version_sum(),
+            Packet::Arraylike(p) => p.version_sum(),
+            Packet::Binary(p) => p.version_sum(),
+        }
+    }
+
+    fn eval(&self) -> u64 {
+        match self {
+            Packet::Literal(p) => p.eval(),
+            Packet::Arraylike(p) => p.eval(),
+            Packet::Binary(p) => p.eval(),
+        }
+    }
+}
+
+struct PacketLiteral {
+    _version: u64,
+    val: u64,
+}
+
+impl PacketTrait for PacketLiteral {
+    fn version_sum(&self) -> u64 {
+        self._version
+    }
+
+    fn eval(&self) -> u64 {
+        self.val
+    }
+}
+
+enum PacketArrayType {
+    Sum,
+    Product,
+    Minimum,
+    Maximum,
+}
+
+struct PacketArraylike {
+    _version: u64,
+    t: PacketArrayType,
+    children: Vec<Packet>,
+}
+
+impl PacketTrait for PacketArraylike {
+    fn version_sum(&self) -> u64 {
+        self._version + self.children.iter().map(|p| p.version_sum()).sum::<u64>()
+    }
+
+    fn eval(&self) -> u64 {
+        match self.t {
+            PacketArrayType::Sum => self.children.iter().map(|p| p.eval()).sum(),
+            PacketArrayType::Product => self.children.iter().map(|p| p.eval()).product(),
+            PacketArrayType::Minimum => self.children.iter().map(|p| p.eval()).min().unwrap(),
+            PacketArrayType::Maximum => self.children.iter().map(|p| p.eval()).max().unwrap(),
+        }
+    }
+}
+
+enum PacketBinaryType {
+    Greater,
+    Less,
+    Equal,
+}
+
+struct PacketBinary {
+    _version: u64,
+    t: PacketBinaryType,
+    left: Box<Packet>,
+    right: Box<Packet>,
+}
+
+impl PacketTrait for PacketBinary {
+    fn version_sum(&self) -> u64 {
+        self._version + self.left.version_sum() + self.right.version_sum()
+    }
+
+    fn eval(&self) -> u64 {
+        let left_val = self.left.eval();
+        let right_val = self.right.eval();
+
+        let ret_one = match self.t {
+            PacketBinaryType::Greater => left_val > right_val,
+            PacketBinaryType::Less => left_val < right_val,
+            PacketBinaryType::Equal => left_val == right_val,
+        };
+
+        if ret_one {
+            1
+        } else {
+            0
+        }
+    }
+}
+
+fn push_bits(bits_stream: &mut Vec<bool>, c: char) -> Option<()> {
+    let bits = match c {
+        '0' => [false, false, false, false],
+        '1' => [false, false, false, true],
+        '2' => [false, false, true, false],
+        '3' => [false, false, true, true],
+        '4' => [false, true, false, false],
+        '5' => [false, true, false, true],
+        '6' => [false, true, true, false],
+        '7' => [false, true, true, true],
+        '8' => [true, false, false, false],
+        '9' => [true, false, false, true],
+        'A' | 'a' => [true, false, true, false],
+        'B' | 'b' => [true, false, true, true],
+        'C' | 'c' => [true, true, false, false],
+        'D' | 'd' => [true, true, false, true],
+        'E' | 'e' => [true, true, true, false],
+        'F' | 'f' => [true, true, true, true],
+        _ => return None,
+    };
+
+    bits_stream.extend_from_slice(&bits);
+    Some(())
+}
+
+fn parse_slice(bits: &[bool]) -> u64 {
+    let mut result = 0;
+    for b in bits {
+        result <<= 1;
+        if *b {
+            result += 1;
+        }
+    }
+    result
+}
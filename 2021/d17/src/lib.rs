@@ -1,17 +1,35 @@
-fn main() {
-    use std::env;
-    let args: Vec<String> = env::args().collect();
-
-    if args.len() != 2 {
-        println!("Usage: d17 <input filename>");
-    } else {
-        let input = read_file(&args[1]);
-        run(&input);
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2021
+    }
+
+    fn day(&self) -> u8 {
+        17
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
     }
+
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
+    }
+}
+
+fn compute_solution_part_one(input: &str) -> Result<i64, ParseError> {
+    Ok(run(input)?.0)
+}
+
+fn compute_solution_part_two(input: &str) -> Result<usize, ParseError> {
+    Ok(run(input)?.1)
 }
 
-fn run(input: &str) {
-    let target_area = Area::from_str(input);
+fn run(input: &str) -> Result<(i64, usize), ParseError> {
+    let target_area = Area::from_str(input)?;
     let vxs = compute_vxs(&target_area);
     let vys = compute_vys(&target_area);
 
@@ -44,8 +62,7 @@ fn run(input: &str) {
         }
     }
 
-    println!("Solution 1 = {}", max_height);
-    println!("Solution 2 = {}", count);
+    Ok((max_height, count))
 }
 
 fn compute_vxs(t: &Area) -> Vec<i64> {
@@ -135,32 +152,17 @@ struct Area {
 }
 
 impl Area {
-    fn from_str(input: &str) -> Self {
-        fn parse(s: &str) -> (i64, i64) {
-            let mut iter = s.chars();
-            iter.next();
-            iter.next();
-
-            let mut iter = iter.as_str().split("..");
-            let first = iter.next().unwrap().parse().unwrap();
-            let second = iter.next().unwrap().parse().unwrap();
-
-            (first, second)
-        }
-
-        let mut input_iter = input.chars();
-
-        for _ in 0..13 {
-            input_iter.next();
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        fn parse(s: Span) -> Result<(i64, i64), ParseError> {
+            let (first, second) = s.split_once("..")?;
+            Ok((first.parse("a coordinate")?, second.parse("a coordinate")?))
         }
 
-        let a: &str = input_iter.as_str();
+        let a = Span::new(input).strip_prefix("target area: x=")?;
+        let (x_str, y_str) = a.split_once(", y=")?;
 
-        let x_str = a.split(',').next().unwrap().trim();
-        let y_str = a.split(',').nth(1).unwrap().trim();
-
-        let (mut x0, mut x1) = parse(x_str);
-        let (mut y0, mut y1) = parse(y_str);
+        let (mut x0, mut x1) = parse(x_str)?;
+        let (mut y0, mut y1) = parse(y_str)?;
 
         if x0 > x1 {
             std::mem::swap(&mut x0, &mut x1);
@@ -170,7 +172,7 @@ impl Area {
             std::mem::swap(&mut y0, &mut y1);
         }
 
-        Self { x0, x1, y0, y1 }
+        Ok(Self { x0, x1, y0, y1 })
     }
 
     fn height(&self) -> i64 {
@@ -208,25 +210,8 @@ impl Probe {
     }
 
     fn can_still_reach_target_area(&self, t: &Area) -> bool {
-        if self.vx >= 0 && self.x > t.x1 {
-            false
-        } else if self.vx <= 0 && self.x < t.x0 {
-            false
-        } else if self.vy <= 0 && self.y < t.y0 {
-            false
-        } else {
-            true
-        }
+        let passed_x = (self.vx >= 0 && self.x > t.x1) || (self.vx <= 0 && self.x < t.x0);
+        let passed_y = self.vy <= 0 && self.y < t.y0;
+        !passed_x && !passed_y
     }
 }
-
-fn read_file(filename: &str) -> String {
-    use std::fs::File;
-    use std::io::prelude::*;
-
-    let mut f = File::open(filename).expect("file not found");
-    let mut contents = String::new();
-    f.read_to_string(&mut contents)
-        .expect("something went wrong reading the file");
-    contents.trim().to_owned()
-}
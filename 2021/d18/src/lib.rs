@@ -1,35 +1,55 @@
-fn main() {
-    use std::env;
-    let args: Vec<String> = env::args().collect();
-
-    if args.len() != 2 {
-        println!("Usage: d18 <input filename>");
-    } else {
-        let input = read_file(&args[1]);
-        println!("solution 1 = {}", compute_solution_part_one(&input));
-        println!("solution 2 = {}", compute_solution_part_two(&input));
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2021
+    }
+
+    fn day(&self) -> u8 {
+        18
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Pair>, ParseError> {
+    let input = Span::new(input);
+    let numbers = input
+        .lines()
+        .map(Pair::from_str)
+        .collect::<Result<Vec<_>, _>>()?;
+    if numbers.is_empty() {
+        return Err(input.error("a snailfish number"));
     }
+    Ok(numbers)
 }
 
-fn compute_solution_part_one(input: &str) -> u64 {
-    let mut s = Pair::from_str(input.lines().next().unwrap());
+fn compute_solution_part_one(input: &str) -> Result<u64, ParseError> {
+    let mut numbers = parse_input(input)?.into_iter();
+    let mut s = numbers.next().unwrap();
 
-    for line in input.lines().skip(1) {
-        let n = Pair::from_str(line);
+    for n in numbers {
         s = s.add(n);
     }
 
-    s.magnitude()
+    Ok(s.magnitude())
 }
 
-fn compute_solution_part_two(input: &str) -> u64 {
+fn compute_solution_part_two(input: &str) -> Result<u64, ParseError> {
+    let numbers = parse_input(input)?;
     let mut max = None;
 
-    for a in input.lines() {
-        for b in input.lines() {
-            let na = Pair::from_str(a);
-            let nb = Pair::from_str(b);
-            let c = na.add(nb);
+    for a in numbers.iter() {
+        for b in numbers.iter() {
+            let c = a.clone().add(b.clone());
 
             let mag = c.magnitude();
             if max.is_none() || max.unwrap() < mag {
@@ -38,10 +58,10 @@ fn compute_solution_part_two(input: &str) -> u64 {
         }
     }
 
-    max.unwrap()
+    Ok(max.unwrap())
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 enum PairOrNumber {
     N(u64),
     P(Pair),
@@ -84,35 +104,49 @@ impl PairOrNumber {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 struct Pair {
     left: Box<PairOrNumber>,
     right: Box<PairOrNumber>,
 }
 
 impl Pair {
-    fn from_str(line: &str) -> Self {
-        let mut left = None;
-        let mut right = None;
-        for (s, is_subpair) in nested_list_split(line) {
-            assert!(!(left.is_some() && right.is_some()));
-            let side = if left.is_none() {
-                &mut left
-            } else {
-                &mut right
-            };
-
-            if is_subpair {
-                *side = Some(PairOrNumber::P(Self::from_str(s)));
-            } else {
-                *side = Some(PairOrNumber::N(s.parse().unwrap()));
-            }
+    fn from_str(line: Span) -> Result<Self, ParseError> {
+        let (pair, rest) = Self::parse_pair(line)?;
+        if !rest.is_empty() {
+            return Err(rest.error("the end of the line"));
+        }
+        Ok(pair)
+    }
+
+    // Parses a pair at the start of `s` and returns it together with the rest of `s`.
+    fn parse_pair(s: Span) -> Result<(Self, Span), ParseError> {
+        let s = expect_char(s, '[')?;
+        let (left, s) = Self::parse_element(s)?;
+        let s = expect_char(s, ',')?;
+        let (right, s) = Self::parse_element(s)?;
+        let s = expect_char(s, ']')?;
+
+        let pair = Self {
+            left: Box::new(left),
+            right: Box::new(right),
+        };
+        Ok((pair, s))
+    }
+
+    fn parse_element(s: Span) -> Result<(PairOrNumber, Span), ParseError> {
+        if s.as_str().starts_with('[') {
+            let (pair, rest) = Self::parse_pair(s)?;
+            return Ok((PairOrNumber::P(pair), rest));
         }
 
-        Self {
-            left: Box::new(left.unwrap()),
-            right: Box::new(right.unwrap()),
+        let n_digits = s.as_str().chars().take_while(char::is_ascii_digit).count();
+        if n_digits == 0 {
+            let found = s.slice(0, s.as_str().chars().next().map_or(0, char::len_utf8));
+            return Err(found.error("a number or `[`"));
         }
+        let n = s.slice(0, n_digits).parse("a number")?;
+        Ok((PairOrNumber::N(n), s.slice(n_digits, s.as_str().len())))
     }
 
     fn magnitude(&self) -> u64 {
@@ -156,18 +190,17 @@ impl Pair {
     fn explode(&mut self) -> bool {
         let level = 0;
         if let PairOrNumber::P(p) = &mut *self.left {
-            let (was_exploding, destroy_child, _retleft, mut retright) = p.explode_inner(level + 1);
+            let (was_exploding, destroy_child, _retleft, retright) = p.explode_inner(level + 1);
             if let Some(n) = retright {
                 let mut p = &mut *self.right;
                 while p.is_pair() {
                     p = &mut *p.unwrap_pair_mut().left;
                 }
                 *p.unwrap_number_mut() += n;
-                retright = None;
             }
 
             if destroy_child {
-                self.left = Box::new(PairOrNumber::N(0));
+                *self.left = PairOrNumber::N(0);
             }
 
             if was_exploding {
@@ -176,18 +209,17 @@ impl Pair {
         }
 
         if let PairOrNumber::P(p) = &mut *self.right {
-            let (was_exploding, destroy_child, mut retleft, _retright) = p.explode_inner(level + 1);
+            let (was_exploding, destroy_child, retleft, _retright) = p.explode_inner(level + 1);
             if let Some(n) = retleft {
                 let mut p = &mut *self.left;
                 while p.is_pair() {
                     p = &mut *p.unwrap_pair_mut().right;
                 }
                 *p.unwrap_number_mut() += n;
-                retleft = None;
             }
 
             if destroy_child {
-                self.right = Box::new(PairOrNumber::N(0));
+                *self.right = PairOrNumber::N(0);
             }
 
             if was_exploding {
@@ -220,7 +252,7 @@ impl Pair {
             }
 
             if destroy_child {
-                self.left = Box::new(PairOrNumber::N(0));
+                *self.left = PairOrNumber::N(0);
             }
 
             if was_exploding {
@@ -240,7 +272,7 @@ impl Pair {
             }
 
             if destroy_child {
-                self.right = Box::new(PairOrNumber::N(0));
+                *self.right = PairOrNumber::N(0);
             }
 
             if was_exploding {
@@ -264,7 +296,7 @@ impl Pair {
         match &mut *self.left {
             PairOrNumber::N(n) => {
                 if *n >= 10 {
-                    self.left = Box::new(PairOrNumber::P(Self::from_split(*n)));
+                    *self.left = PairOrNumber::P(Self::from_split(*n));
                     return true;
                 }
             }
@@ -278,7 +310,7 @@ impl Pair {
         match &mut *self.right {
             PairOrNumber::N(n) => {
                 if *n >= 10 {
-                    self.right = Box::new(PairOrNumber::P(Self::from_split(*n)));
+                    *self.right = PairOrNumber::P(Self::from_split(*n));
                     return true;
                 }
             }
@@ -293,69 +325,11 @@ impl Pair {
     }
 }
 
-fn nested_list_split(s: &str) -> NestedListIter {
-    let mut s_chars = s.chars();
-    let a = s_chars.next().unwrap();
-    assert_eq!(a, '[');
-
-    let b = s_chars.next_back().unwrap();
-    assert_eq!(b, ']');
-
-    NestedListIter {
-        rest: s_chars.as_str(),
-    }
-}
-
-struct NestedListIter<'a> {
-    rest: &'a str,
-}
-
-impl<'a> Iterator for NestedListIter<'a> {
-    type Item = (&'a str, bool);
-
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.rest.is_empty() {
-            return None;
-        }
-
-        let mut bracket_counter = 0;
-        let mut contains_bracket = false;
-        for (i, c) in self.rest.char_indices() {
-            match c {
-                '[' => {
-                    bracket_counter += 1;
-                    contains_bracket = true;
-                }
-                ']' => {
-                    assert!(bracket_counter > 0, "invalid input");
-                    bracket_counter -= 1;
-                }
-                ',' => {
-                    assert_ne!(i, 0, "invalid input");
-                    if bracket_counter == 0 {
-                        let result_inner = &self.rest[..i];
-                        self.rest = &self.rest[(i + 1)..];
-                        return Some((result_inner.trim(), contains_bracket));
-                    }
-                }
-                _ => (),
-            }
-        }
-
-        let result_inner = self.rest;
-        self.rest = "";
-
-        Some((result_inner.trim(), contains_bracket))
+// Consumes the character `c` at the start of `s`.
+fn expect_char(s: Span, c: char) -> Result<Span, ParseError> {
+    match s.chars().next() {
+        Some((_, found)) if found == c => Ok(s.slice(c.len_utf8(), s.as_str().len())),
+        Some((span, _)) => Err(span.error(format!("`{}`", c))),
+        None => Err(s.error(format!("`{}`", c))),
     }
 }
-
-fn read_file(filename: &str) -> String {
-    use std::fs::File;
-    use std::io::prelude::*;
-
-    let mut f = File::open(filename).expect("file not found");
-    let mut contents = String::new();
-    f.read_to_string(&mut contents)
-        .expect("something went wrong reading the file");
-    contents.trim().to_owned()
-}
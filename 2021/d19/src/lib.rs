@@ -1,20 +1,42 @@
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
 use std::collections::{BTreeMap, BTreeSet};
 
-fn main() {
-    use std::env;
-    let args: Vec<String> = env::args().collect();
+pub struct Solution;
 
-    if args.len() != 2 {
-        println!("Usage: d19 <input filename>");
-    } else {
-        let input = read_file(&args[1]);
-        run(&input);
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2021
+    }
+
+    fn day(&self) -> u8 {
+        19
+    }
+
+    fn parse(&self, input: &Input) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input.trimmed())?);
+        Ok(true)
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
     }
 }
 
-fn run(input: &str) {
+fn compute_solution_part_one(input: &str) -> Result<usize, ParseError> {
+    Ok(run(input)?.0)
+}
+
+fn compute_solution_part_two(input: &str) -> Result<i64, ParseError> {
+    Ok(run(input)?.1)
+}
+
+fn run(input: &str) -> Result<(usize, i64), ParseError> {
     let orientations = compute_orientations();
-    let mut scanners = parse_input(input);
+    let mut scanners = parse_input(input)?;
 
     let fingerprints: Vec<_> = scanners.iter().map(|s| s.fingerprint()).collect();
 
@@ -100,8 +122,6 @@ fn run(input: &str) {
         }
     }
 
-    println!("Solution 1 = {}", beacons.len());
-
     let mut max_dist = 0;
 
     for i in 0..scanners.len() {
@@ -114,28 +134,30 @@ fn run(input: &str) {
         }
     }
 
-    println!("Solution 2 = {}", max_dist);
+    Ok((beacons.len(), max_dist))
 }
 
-fn parse_input(input: &str) -> Vec<Scanner> {
+fn parse_input(input: &str) -> Result<Vec<Scanner>, ParseError> {
     let mut result = Vec::new();
 
-    for group in input.trim().split("---") {
-        let group = group.trim();
-        if group.is_empty() {
-            continue;
-        }
-        if group.starts_with('s') {
-            continue;
-        }
+    for group in Span::new(input).blocks() {
+        let mut lines = group.lines();
+        let header = lines.expect("a scanner header")?;
+        header
+            .strip_prefix("--- scanner ")?
+            .strip_suffix(" ---")?
+            .parse::<usize>("a scanner number")?;
 
-        result.push(Scanner::from_str(group));
+        result.push(Scanner::from_lines(lines)?);
     }
 
-    result
+    if result.is_empty() {
+        return Err(Span::new(input).error("a scanner"));
+    }
+    Ok(result)
 }
 
-fn compute_position(to_compute: usize, already_known: usize, scanners: &mut Vec<Scanner>) {
+fn compute_position(to_compute: usize, already_known: usize, scanners: &mut [Scanner]) {
     let known_set: BTreeSet<_> = scanners[already_known].beacons.iter().cloned().collect();
 
     for i in 0..scanners[to_compute].beacons.len() {
@@ -213,10 +235,8 @@ fn compute_orientations() -> [[Vector3; 3]; 24] {
         Vector3::unit(Axis::Z),
     ];
     result_set.remove(&result[0]);
-    let mut i = 1;
-    for r in result_set.iter() {
-        result[i] = *r;
-        i += 1;
+    for (i, r) in result_set.iter().enumerate() {
+        result[i + 1] = *r;
     }
     result
 }
@@ -228,14 +248,14 @@ struct Scanner {
 }
 
 impl Scanner {
-    fn from_str(s: &str) -> Self {
+    fn from_lines<'a>(lines: impl Iterator<Item = Span<'a>>) -> Result<Self, ParseError> {
         let position = None;
         let mut beacons = Vec::new();
-        for line in s.lines() {
-            beacons.push(Vector3::from_str(line));
+        for line in lines {
+            beacons.push(Vector3::from_str(line)?);
         }
 
-        Self { position, beacons }
+        Ok(Self { position, beacons })
     }
 
     fn transform_to_canonical(&mut self, orientation: &[Vector3; 3]) {
@@ -316,12 +336,15 @@ impl Vector3 {
         Self { x, y, z }
     }
 
-    fn from_str(s: &str) -> Self {
-        let mut s_iter = s.trim().split(',');
-        let x = s_iter.next().unwrap().trim().parse().unwrap();
-        let y = s_iter.next().unwrap().trim().parse().unwrap();
-        let z = s_iter.next().unwrap().trim().parse().unwrap();
-        Self::new(x, y, z)
+    fn from_str(s: Span) -> Result<Self, ParseError> {
+        let mut s_iter = s.trim().split(",");
+        let mut coordinate =
+            |name: &str| -> Result<i64, ParseError> { s_iter.expect(name)?.trim().parse(name) };
+        let x = coordinate("an x coordinate")?;
+        let y = coordinate("a y coordinate")?;
+        let z = coordinate("a z coordinate")?;
+        s_iter.end()?;
+        Ok(Self::new(x, y, z))
     }
 
     fn rotated90(&self, axis: Axis) -> Self {
@@ -363,14 +386,3 @@ impl std::ops::Sub<Vector3> for Vector3 {
         Self::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
     }
 }
-
-fn read_file(filename: &str) -> String {
-    use std::fs::File;
-    use std::io::prelude::*;
-
-    let mut f = File::open(filename).expect("file not found");
-    let mut contents = String::new();
-    f.read_to_string(&mut contents)
-        .expect("something went wrong reading the file");
-    contents.trim().to_owned()
-}
@@ -1,32 +1,73 @@
+use aoc_common::{Answer, Input, ParseError, Picture, PictureExport, Puzzle, Span};
 use std::collections::{BTreeMap, BTreeSet};
+use std::io;
 
-fn main() {
-    use std::env;
-    let args: Vec<String> = env::args().collect();
+pub struct Solution;
 
-    if args.len() != 2 {
-        println!("Usage: d20 <input filename>");
-    } else {
-        let input = read_file(&args[1]);
-        println!("solution 1 = {}", compute_solution_part_one(&input));
-        println!("solution 2 = {}", compute_solution_part_two(&input));
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2021
+    }
+
+    fn day(&self) -> u8 {
+        20
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
+    }
+
+    fn export_image(&self, input: &Input, export: &mut PictureExport) -> io::Result<bool> {
+        export_image(input.trimmed(), export)
     }
 }
 
-fn compute_solution_part_one(input: &str) -> usize {
-    let mut image = Image::from_str(input);
+fn compute_solution_part_one(input: &str) -> Result<usize, ParseError> {
+    let mut image = Image::from_str(input)?;
     for _ in 0..2 {
         image.step();
     }
-    image.count_lit_pixels()
+    Ok(image.count_lit_pixels())
 }
 
-fn compute_solution_part_two(input: &str) -> usize {
-    let mut image = Image::from_str(input);
+fn compute_solution_part_two(input: &str) -> Result<usize, ParseError> {
+    let mut image = Image::from_str(input)?;
     for _ in 0..50 {
         image.step();
     }
-    image.count_lit_pixels()
+    Ok(image.count_lit_pixels())
+}
+
+// Draws the image after the 50 steps of part two, one frame per step
+fn export_image(input: &str, export: &mut PictureExport) -> io::Result<bool> {
+    let steps = 50;
+    let mut image =
+        Image::from_str(input).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    // The image grows by at most one pixel per step on every side
+    let top = image.pixels.keys().map(|&(i, _)| i).min().unwrap() - steps;
+    let bottom = image.pixels.keys().map(|&(i, _)| i).max().unwrap() + steps;
+    let left = image.pixels.keys().map(|&(_, j)| j).min().unwrap() - steps;
+    let right = image.pixels.keys().map(|&(_, j)| j).max().unwrap() + steps;
+    let width = (right - left + 1) as usize;
+    let height = (bottom - top + 1) as usize;
+    let picture = |image: &Image| {
+        Picture::bitmap(width, height, |row, col| {
+            image.get(top + row as i64, left + col as i64)
+        })
+    };
+
+    export.frame(|| picture(&image))?;
+    for _ in 0..steps {
+        image.step();
+        export.frame(|| picture(&image))?;
+    }
+    export.finish(&picture(&image))?;
+    Ok(true)
 }
 
 #[derive(Debug)]
@@ -37,40 +78,40 @@ struct Image {
 }
 
 impl Image {
-    fn from_str(input: &str) -> Self {
-        fn char_to_bool(c: char) -> bool {
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        fn char_to_bool((span, c): (Span, char)) -> Result<bool, ParseError> {
             match c {
-                '#' => true,
-                '.' => false,
-                _ => panic!("invalid input"),
+                '#' => Ok(true),
+                '.' => Ok(false),
+                _ => Err(span.error("`#` or `.`")),
             }
         }
 
-        let input = input.trim();
-        let enhancement_rule: Vec<bool> = input
-            .lines()
-            .next()
-            .unwrap()
-            .trim()
+        let mut blocks = Span::new(input).blocks();
+        let rule = blocks.expect("the image enhancement algorithm")?.trim();
+        let enhancement_rule = rule
             .chars()
             .map(char_to_bool)
-            .collect();
-        assert_eq!(512, enhancement_rule.len());
+            .collect::<Result<Vec<bool>, _>>()?;
+        if enhancement_rule.len() != 512 {
+            return Err(rule.error("an image enhancement algorithm of 512 pixels"));
+        }
 
         let mut pixels = BTreeMap::new();
 
-        for (i, line) in input.lines().skip(2).enumerate() {
+        for (i, line) in blocks.expect("the input image")?.lines().enumerate() {
             let line = line.trim();
             for (j, c) in line.chars().enumerate() {
-                pixels.insert((i as i64, j as i64), char_to_bool(c));
+                pixels.insert((i as i64, j as i64), char_to_bool(c)?);
             }
         }
+        blocks.end()?;
 
-        Self {
+        Ok(Self {
             enhancement_rule,
             default: false,
             pixels,
-        }
+        })
     }
 
     fn get(&self, i: i64, j: i64) -> bool {
@@ -200,14 +241,3 @@ impl Iterator for SquareIter {
         Some((self.i + di, self.j + dj))
     }
 }
-
-fn read_file(filename: &str) -> String {
-    use std::fs::File;
-    use std::io::prelude::*;
-
-    let mut f = File::open(filename).expect("file not found");
-    let mut contents = String::new();
-    f.read_to_string(&mut contents)
-        .expect("something went wrong reading the file");
-    contents.trim().to_owned()
-}
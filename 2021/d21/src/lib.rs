@@ -1,20 +1,33 @@
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
 use std::collections::BTreeMap;
 
-fn main() {
-    use std::env;
-    let args: Vec<String> = env::args().collect();
+pub struct Solution;
 
-    if args.len() != 2 {
-        println!("Usage: d21 <input filename>");
-    } else {
-        let input = read_file(&args[1]);
-        println!("solution 1 = {}", compute_solution_part_one(&input));
-        println!("solution 2 = {}", compute_solution_part_two(&input));
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2021
+    }
+
+    fn day(&self) -> u8 {
+        21
+    }
+
+    fn parse(&self, input: &Input) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input.trimmed())?);
+        Ok(true)
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
     }
 }
 
-fn compute_solution_part_one(input: &str) -> usize {
-    let mut players = parse_input(input);
+fn compute_solution_part_one(input: &str) -> Result<usize, ParseError> {
+    let mut players = parse_input(input)?;
     let mut player_index = 0;
     let mut die = DeterministicDie::new();
     let loser_index;
@@ -34,12 +47,12 @@ fn compute_solution_part_one(input: &str) -> usize {
         }
     }
 
-    players[loser_index].points * die.n_rolls
+    Ok(players[loser_index].points * die.n_rolls)
 }
 
-fn compute_solution_part_two(input: &str) -> usize {
+fn compute_solution_part_two(input: &str) -> Result<usize, ParseError> {
     let mut game_info: BTreeMap<[Player; 2], usize> = {
-        let players = parse_input(input);
+        let players = parse_input(input)?;
         let mut game_info = BTreeMap::new();
         game_info.insert(players, 1);
         game_info
@@ -87,40 +100,32 @@ fn compute_solution_part_two(input: &str) -> usize {
     }
 
     if wins_one > wins_two {
-        wins_one
+        Ok(wins_one)
     } else {
-        wins_two
+        Ok(wins_two)
+    }
+}
+
+fn parse_starting_position(line: Span, player: usize) -> Result<usize, ParseError> {
+    let prefix = format!("Player {} starting position: ", player);
+    let field = line.trim().strip_prefix(&prefix)?;
+    match field.parse("a starting position")? {
+        n @ 1..=10 => Ok(n),
+        _ => Err(field.error("a starting position from 1 to 10")),
     }
 }
 
-fn parse_input(input: &str) -> [Player; 2] {
-    let input = input.trim();
-
-    let field_one = input
-        .lines()
-        .next()
-        .unwrap()
-        .split(':')
-        .nth(1)
-        .unwrap()
-        .trim()
-        .parse()
-        .unwrap();
-    let field_two = input
-        .lines()
-        .nth(1)
-        .unwrap()
-        .split(':')
-        .nth(1)
-        .unwrap()
-        .trim()
-        .parse()
-        .unwrap();
+fn parse_input(input: &str) -> Result<[Player; 2], ParseError> {
+    let mut lines = Span::new(input).trim().lines();
+
+    let field_one = parse_starting_position(lines.expect("the position of player 1")?, 1)?;
+    let field_two = parse_starting_position(lines.expect("the position of player 2")?, 2)?;
+    lines.end()?;
 
     let player_one = Player::new(field_one, 0);
     let player_two = Player::new(field_two, 0);
 
-    [player_one, player_two]
+    Ok([player_one, player_two])
 }
 
 #[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Copy, Clone)]
@@ -175,14 +180,3 @@ impl DeterministicDie {
         result
     }
 }
-
-fn read_file(filename: &str) -> String {
-    use std::fs::File;
-    use std::io::prelude::*;
-
-    let mut f = File::open(filename).expect("file not found");
-    let mut contents = String::new();
-    f.read_to_string(&mut contents)
-        .expect("something went wrong reading the file");
-    contents.trim().to_owned()
-}
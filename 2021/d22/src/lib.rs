@@ -1,18 +1,32 @@
-fn main() {
-    use std::env;
-    let args: Vec<String> = env::args().collect();
-
-    if args.len() != 2 {
-        println!("Usage: d22 <input filename>");
-    } else {
-        let input = read_file(&args[1]);
-        println!("solution 1 = {}", compute_solution_part_one(&input));
-        println!("solution 2 = {}", compute_solution_part_two(&input));
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2021
+    }
+
+    fn day(&self) -> u8 {
+        22
+    }
+
+    fn parse(&self, input: &Input) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input.trimmed())?);
+        Ok(true)
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
     }
 }
 
-fn compute_solution_part_one(input: &str) -> i64 {
-    let commands = parse_input(input);
+fn compute_solution_part_one(input: &str) -> Result<i64, ParseError> {
+    let commands = parse_input(input)?;
     let mut group = CuboidGroup::new();
 
     for c in &commands {
@@ -26,11 +40,11 @@ fn compute_solution_part_one(input: &str) -> i64 {
         }
     }
 
-    group.volume()
+    Ok(group.volume())
 }
 
-fn compute_solution_part_two(input: &str) -> i64 {
-    let commands = parse_input(input);
+fn compute_solution_part_two(input: &str) -> Result<i64, ParseError> {
+    let commands = parse_input(input)?;
     let mut group = CuboidGroup::new();
 
     for c in &commands {
@@ -40,19 +54,21 @@ fn compute_solution_part_two(input: &str) -> i64 {
         }
     }
 
-    group.volume()
+    Ok(group.volume())
 }
 
-fn parse_input(input: &str) -> Vec<Command> {
+fn parse_input(input: &str) -> Result<Vec<Command>, ParseError> {
     let mut result = Vec::new();
-    let input = input.trim();
+    let input = Span::new(input).trim();
     for line in input.lines() {
-        result.push(Command::from_str(line));
+        result.push(Command::from_str(line)?);
     }
 
-    result
+    Ok(result)
 }
 
+type Interval = [i64; 2];
+
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
 struct Cuboid {
     x0: i64,
@@ -75,34 +91,39 @@ impl Cuboid {
         }
     }
 
-    fn from_str(s: &str) -> Self {
-        fn parse_range(s: &str) -> (i64, i64) {
-            let s = s.trim();
-            let s = s.split('=').nth(1).unwrap().trim();
-            let mut val_iter = s.split("..");
-            let v0 = val_iter.next().unwrap().parse().unwrap();
-            let v1 = val_iter.next().unwrap().parse().unwrap();
-            (v0, v1)
+    fn from_str(s: Span) -> Result<Self, ParseError> {
+        fn parse_range(s: Span, axis: &str) -> Result<(i64, i64), ParseError> {
+            let s = s.trim().strip_prefix(axis)?.strip_prefix("=")?.trim();
+            let (v0, v1) = s.split_once("..")?;
+            let v0 = v0.parse("a lower bound")?;
+            let v1 = v1.parse("an upper bound")?;
+            if v0 > v1 {
+                return Err(s.error("a lower bound not above the upper bound"));
+            }
+            Ok((v0, v1))
         }
 
         let s = s.trim();
         let mut vals = [0; 6];
         let mut index = 0;
-        for sub in s.split(',') {
-            let subvals = parse_range(sub);
+        let mut sub_iter = s.split(",");
+        for axis in ["x", "y", "z"] {
+            let sub = sub_iter.expect(&format!("a range for {}", axis))?;
+            let subvals = parse_range(sub, axis)?;
             vals[index] = subvals.0;
             vals[index + 1] = subvals.1;
             index += 2;
         }
+        sub_iter.end()?;
 
-        Self {
+        Ok(Self {
             x0: vals[0],
             x1: vals[1],
             y0: vals[2],
             y1: vals[3],
             z0: vals[4],
             z1: vals[5],
-        }
+        })
     }
 
     fn volume(&self) -> i64 {
@@ -164,7 +185,7 @@ impl Cuboid {
         v1: i64,
         t0: i64,
         t1: i64,
-    ) -> ([Option<[i64; 2]>; 2], [i64; 2], [Option<[i64; 2]>; 2]) {
+    ) -> ([Option<Interval>; 2], Interval, [Option<Interval>; 2]) {
         assert!(v1 >= t0);
         assert!(v0 <= t1);
 
@@ -247,22 +268,23 @@ struct Command {
 }
 
 impl Command {
-    fn from_str(s: &str) -> Self {
+    fn from_str(s: Span) -> Result<Self, ParseError> {
         let s = s.trim();
         let mut s_iter = s.split_whitespace();
-        let command_type_str = s_iter.next().unwrap();
-        let cuboid = Cuboid::from_str(s_iter.next().unwrap());
+        let command_type_str = s_iter.expect("`on` or `off`")?;
+        let cuboid = Cuboid::from_str(s_iter.expect("a cuboid")?)?;
+        s_iter.end()?;
 
-        let command_type = match command_type_str {
+        let command_type = match command_type_str.as_str() {
             "on" => CommandType::On,
             "off" => CommandType::Off,
-            _ => panic!("invalid input"),
+            _ => return Err(command_type_str.error("`on` or `off`")),
         };
 
-        Self {
+        Ok(Self {
             cuboid,
             command_type,
-        }
+        })
     }
 }
 
@@ -310,13 +332,23 @@ impl CuboidGroup {
     }
 }
 
-fn read_file(filename: &str) -> String {
-    use std::fs::File;
-    use std::io::prelude::*;
-
-    let mut f = File::open(filename).expect("file not found");
-    let mut contents = String::new();
-    f.read_to_string(&mut contents)
-        .expect("something went wrong reading the file");
-    contents.trim().to_owned()
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_errors() {
+        let e = parse_input("on x=10..12,y=10..12,z=10..12\noff x=9..11,y=9..11").unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "line 2, column 20: expected a range for z, found nothing"
+        );
+        let e = parse_input("toggle x=1..2,y=1..2,z=1..2").unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "line 1, column 1: expected `on` or `off`, found `toggle`"
+        );
+        let e = parse_input("on x=3..1,y=1..2,z=1..2").unwrap_err();
+        assert_eq!(e.expected, "a lower bound not above the upper bound");
+    }
 }
@@ -1,23 +1,40 @@
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
+use aoc_grid::Grid;
 use std::collections::{BTreeMap, BTreeSet};
 
-fn main() {
-    use std::env;
-    let args: Vec<String> = env::args().collect();
+pub struct Solution;
 
-    if args.len() != 2 {
-        println!("Usage: d23 <input filename>");
-    } else {
-        let input = read_file(&args[1]);
-        println!("solution 1 = {}", compute_solution_part_one(&input));
-        println!("solution 2 = {}", compute_solution_part_two(&input));
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2021
+    }
+
+    fn day(&self) -> u8 {
+        23
+    }
+
+    fn parse(&self, input: &Input) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input.trimmed())?);
+        Ok(true)
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
     }
 }
 
-fn compute_solution_part_one(input: &str) -> usize {
+fn compute_solution_part_one(input: &str) -> Result<usize, ParseError> {
     compute_energy(input)
 }
 
-fn compute_solution_part_two(input: &str) -> usize {
+fn compute_solution_part_two(input: &str) -> Result<usize, ParseError> {
+    // Report errors against the input as given, not the unfolded diagram
+    parse_input(input)?;
+
     let mut s = "".to_owned();
 
     for line in input.lines().take(3) {
@@ -25,7 +42,7 @@ fn compute_solution_part_two(input: &str) -> usize {
         s += "\n";
     }
 
-    s += "  #D#C#B#A#\n  #D#B#A#C#";
+    s += "  #D#C#B#A#\n  #D#B#A#C#\n";
 
     for line in input.lines().skip(3) {
         s += line;
@@ -35,11 +52,11 @@ fn compute_solution_part_two(input: &str) -> usize {
     compute_energy(&s)
 }
 
-fn compute_energy(input: &str) -> usize {
+fn compute_energy(input: &str) -> Result<usize, ParseError> {
     use std::cmp::Reverse;
     use std::collections::BinaryHeap;
 
-    let (initial_burrow, layout) = parse_input(input);
+    let (initial_burrow, layout) = parse_input(input)?;
 
     let mut cost = BTreeMap::new();
     cost.insert(initial_burrow.clone(), 0);
@@ -60,7 +77,7 @@ fn compute_energy(input: &str) -> usize {
         for (k, v) in next_bs.iter() {
             let mc = k.min_cost(&layout);
             if mc == 0 {
-                return *v;
+                return Ok(*v);
             }
 
             let prev_cost = cost.get(k);
@@ -95,7 +112,7 @@ fn move_cost(c: FieldContent) -> usize {
 
 struct BurrowLayout {
     connections: Vec<(usize, usize)>,
-    min_distances: Matrix<usize>,
+    min_distances: Grid<usize>,
     index_amber: usize,
     index_bronze: usize,
     index_copper: usize,
@@ -185,7 +202,7 @@ impl Burrow {
 
             let delta = move_cost(s);
 
-            result += delta * layout.min_distances.get(i, *m);
+            result += delta * layout.min_distances[(i, *m)];
             *m += 1;
             maybe_increase_index(m, s, r[1]);
         }
@@ -233,9 +250,7 @@ impl Burrow {
 
         let mut todo = vec![(self.clone(), init_energy, index)];
 
-        while !todo.is_empty() {
-            let (b, e, i) = todo.pop().unwrap();
-
+        while let Some((b, e, i)) = todo.pop() {
             for n in neighbors(i, layout) {
                 if b.state[n] == FieldContent::Empty {
                     let mut new_b = b.clone();
@@ -282,19 +297,30 @@ impl Burrow {
     }
 }
 
-fn parse_input(input: &str) -> (Burrow, BurrowLayout) {
-    let mut state = Vec::new();
-    let hallway_line = input.lines().nth(1).unwrap();
-
-    for c in hallway_line.chars() {
-        match c {
-            'A' => state.push(FieldContent::Amber),
-            'B' => state.push(FieldContent::Bronze),
-            'C' => state.push(FieldContent::Copper),
-            'D' => state.push(FieldContent::Desert),
-            '.' => state.push(FieldContent::Empty),
-            _ => (),
-        }
+fn parse_field(c: char) -> Option<FieldContent> {
+    match c {
+        'A' => Some(FieldContent::Amber),
+        'B' => Some(FieldContent::Bronze),
+        'C' => Some(FieldContent::Copper),
+        'D' => Some(FieldContent::Desert),
+        '.' => Some(FieldContent::Empty),
+        _ => None,
+    }
+}
+
+fn parse_input(input: &str) -> Result<(Burrow, BurrowLayout), ParseError> {
+    let input = Span::new(input);
+    let mut lines = input.lines();
+    lines.expect("the top wall of the burrow")?;
+    let hallway_line = lines.expect("the hallway")?;
+
+    let mut state: Vec<FieldContent> = hallway_line
+        .as_str()
+        .chars()
+        .filter_map(parse_field)
+        .collect();
+    if state.len() != 11 {
+        return Err(hallway_line.error("a hallway of 11 fields"));
     }
 
     let mut state_amber = Vec::new();
@@ -302,37 +328,40 @@ fn parse_input(input: &str) -> (Burrow, BurrowLayout) {
     let mut state_copper = Vec::new();
     let mut state_desert = Vec::new();
 
-    for line in input.lines().skip(2) {
-        let mut i = 0;
-        for c in line.chars() {
-            let s = match i {
-                0 => &mut state_amber,
-                1 => &mut state_bronze,
-                2 => &mut state_copper,
-                3 => &mut state_desert,
-                _ => panic!("parse error"),
-            };
-
-            let orglen = s.len();
-
-            match c {
-                'A' => s.push(FieldContent::Amber),
-                'B' => s.push(FieldContent::Bronze),
-                'C' => s.push(FieldContent::Copper),
-                'D' => s.push(FieldContent::Desert),
-                '.' => s.push(FieldContent::Empty),
-                _ => (),
-            }
-
-            if orglen != s.len() {
-                i = (i + 1) % 4;
-            }
+    for line in lines {
+        let fields: Vec<FieldContent> = line.as_str().chars().filter_map(parse_field).collect();
+        match fields.len() {
+            0 => continue,
+            4 => (),
+            _ => return Err(line.error("a row of 4 room fields")),
         }
+        state_amber.push(fields[0]);
+        state_bronze.push(fields[1]);
+        state_copper.push(fields[2]);
+        state_desert.push(fields[3]);
     }
 
-    assert_eq!(state_amber.len(), state_bronze.len());
-    assert_eq!(state_amber.len(), state_copper.len());
-    assert_eq!(state_amber.len(), state_desert.len());
+    if state_amber.is_empty() {
+        return Err(input.end().error("a row of 4 room fields"));
+    }
+    for kind in [
+        FieldContent::Amber,
+        FieldContent::Bronze,
+        FieldContent::Copper,
+        FieldContent::Desert,
+    ] {
+        let count = state
+            .iter()
+            .chain(&state_amber)
+            .chain(&state_bronze)
+            .chain(&state_copper)
+            .chain(&state_desert)
+            .filter(|&&f| f == kind)
+            .count();
+        if count != state_amber.len() {
+            return Err(input.error(format!("{} amphipods of every type", state_amber.len())));
+        }
+    }
 
     let room_size = state_amber.len();
     let index_amber = state.len();
@@ -369,7 +398,7 @@ fn parse_input(input: &str) -> (Burrow, BurrowLayout) {
 
     let mut layout = BurrowLayout {
         connections,
-        min_distances: Matrix::constant(0, 0, 0),
+        min_distances: Grid::filled(0, 0, 0),
         index_amber,
         index_bronze,
         index_copper,
@@ -386,10 +415,10 @@ fn parse_input(input: &str) -> (Burrow, BurrowLayout) {
 
     let burrow = Burrow { state };
 
-    (burrow, layout)
+    Ok((burrow, layout))
 }
 
-fn compute_min_distances(layout: &BurrowLayout) -> Matrix<usize> {
+fn compute_min_distances(layout: &BurrowLayout) -> Grid<usize> {
     let mut n = *layout.connections.iter().map(|(a, _)| a).max().unwrap();
     let m = *layout.connections.iter().map(|(_, a)| a).max().unwrap();
 
@@ -397,15 +426,14 @@ fn compute_min_distances(layout: &BurrowLayout) -> Matrix<usize> {
         n = m;
     }
 
-    let mut result = Matrix::constant(0, n + 1, n + 1);
+    let mut result = Grid::filled(n + 1, n + 1, 0);
 
     for i in 0..(n + 1) {
         let mut todo = vec![(i, 0)];
         let mut distances = BTreeMap::new();
         distances.insert(i, 0);
 
-        while !todo.is_empty() {
-            let (j, d) = todo.pop().unwrap();
+        while let Some((j, d)) = todo.pop() {
             let new_dist = d + 1;
             for k in neighbors(j, layout) {
                 let old_dist = distances.entry(k).or_insert(new_dist + 1);
@@ -417,49 +445,14 @@ fn compute_min_distances(layout: &BurrowLayout) -> Matrix<usize> {
         }
 
         for (k, v) in distances.iter() {
-            result.set(i, *k, *v);
+            result[(i, *k)] = *v;
         }
     }
 
     result
 }
 
-#[derive(Debug)]
-struct Matrix<T> {
-    width: usize,
-    height: usize,
-    data: Vec<T>,
-}
-
-impl<T> Matrix<T> {
-    fn constant(c: T, width: usize, height: usize) -> Self
-    where
-        T: Clone,
-    {
-        Self {
-            width,
-            height,
-            data: vec![c; width * height],
-        }
-    }
-
-    fn get(&self, i: usize, j: usize) -> T
-    where
-        T: Copy,
-    {
-        assert!(i < self.height);
-        assert!(j < self.width);
-        self.data[i * self.width + j]
-    }
-
-    fn set(&mut self, i: usize, j: usize, new_val: T) {
-        assert!(i < self.height);
-        assert!(j < self.width);
-        self.data[i * self.width + j] = new_val;
-    }
-}
-
-fn neighbors(index: usize, layout: &BurrowLayout) -> ConnectionsIter {
+fn neighbors(index: usize, layout: &BurrowLayout) -> ConnectionsIter<'_> {
     ConnectionsIter {
         burrow_index: index,
         index: 0,
@@ -519,14 +512,3 @@ impl Ord for EnergyEntry {
         self.energy.cmp(&other.energy)
     }
 }
-
-fn read_file(filename: &str) -> String {
-    use std::fs::File;
-    use std::io::prelude::*;
-
-    let mut f = File::open(filename).expect("file not found");
-    let mut contents = String::new();
-    f.read_to_string(&mut contents)
-        .expect("something went wrong reading the file");
-    contents.trim().to_owned()
-}
@@ -1,38 +1,59 @@
 // The code in this file works under the assumption that x and y get set to zero after every inp,
 // which is mapped to w.
 
+use aoc_common::{Answer, Input, ParseError, Puzzle, Span};
 use std::collections::{BTreeSet, VecDeque};
 
-fn main() {
-    use std::env;
-    let args: Vec<String> = env::args().collect();
+pub struct Solution;
 
-    if args.len() != 2 {
-        println!("Usage: d24 <input filename>");
-    } else {
-        let input = read_file(&args[1]);
-        run(&input);
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2021
     }
+
+    fn day(&self) -> u8 {
+        24
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
+
+    fn part_two(&self, input: &Input) -> Option<Result<Answer, ParseError>> {
+        Some(compute_solution_part_two(input.trimmed()).map(Answer::from))
+    }
+}
+
+fn compute_solution_part_one(input: &str) -> Result<String, ParseError> {
+    Ok(run(input)?.0)
+}
+
+fn compute_solution_part_two(input: &str) -> Result<String, ParseError> {
+    Ok(run(input)?.1)
 }
 
-fn run(input: &str) {
-    let mut inputs: Vec<Vec<Instruction>> = input
-        .split("inp")
-        .filter(|s| !s.trim().is_empty())
-        .map(|s| {
-            let mut s = s.to_owned();
-            s = "inp".to_owned() + &s;
-            s
-        })
-        .map(|s| {
-            s.lines()
-                .filter(|l| !l.is_empty())
-                .map(|l| Instruction::from_str(l))
-                .collect()
-        })
-        .collect();
-
-    assert_eq!(inputs.len(), 14);
+// The instructions grouped by input digit, every group starts with `inp`
+fn parse_input(input: &str) -> Result<Vec<Vec<Instruction>>, ParseError> {
+    let input = Span::new(input);
+    let mut inputs: Vec<Vec<Instruction>> = Vec::new();
+
+    for line in input.lines().filter(|l| !l.is_empty()) {
+        let instruction = Instruction::from_str(line)?;
+        match (instruction, inputs.last_mut()) {
+            (Instruction::Inp(_), _) => inputs.push(vec![instruction]),
+            (_, Some(instructions)) => instructions.push(instruction),
+            (_, None) => return Err(line.error("an `inp` instruction")),
+        }
+    }
+
+    if inputs.len() != 14 {
+        return Err(input.end().error("14 `inp` instructions"));
+    }
+    Ok(inputs)
+}
+
+fn run(input: &str) -> Result<(String, String), ParseError> {
+    let mut inputs = parse_input(input)?;
     inputs.reverse();
 
     let zmax = 100000;
@@ -44,7 +65,7 @@ fn run(input: &str) {
         for z in 0..zmax {
             for k in 1..10 {
                 let inputs = VecDeque::from([k]);
-                let mut alu = ALU::new(inputs, 0, 0, 0, z);
+                let mut alu = Alu::new(inputs, 0, 0, 0, z);
 
                 for instruction in instructions {
                     alu.perform(*instruction);
@@ -65,12 +86,11 @@ fn run(input: &str) {
     z_targets.reverse();
     inputs.reverse();
 
-    fn print_solution(
+    fn find_model_number(
         inputs: &[Vec<Instruction>],
         z_targets: &[BTreeSet<i64>],
         do_max: bool,
-        prefix: &str,
-    ) {
+    ) -> String {
         let mut z = 0;
         let mut result_vec = Vec::new();
 
@@ -92,7 +112,7 @@ fn run(input: &str) {
                 }
 
                 let inputs = VecDeque::from([k]);
-                let mut alu = ALU::new(inputs, 0, 0, 0, z);
+                let mut alu = Alu::new(inputs, 0, 0, 0, z);
 
                 for instruction in instructions {
                     alu.perform(*instruction);
@@ -113,14 +133,16 @@ fn run(input: &str) {
             result += &k.to_string();
         }
 
-        println!("{}{}", prefix, result);
+        result
     }
 
-    print_solution(&inputs, &z_targets, true, "Solution 1 = ");
-    print_solution(&inputs, &z_targets, false, "Solution 2 = ");
+    Ok((
+        find_model_number(&inputs, &z_targets, true),
+        find_model_number(&inputs, &z_targets, false),
+    ))
 }
 
-struct ALU {
+struct Alu {
     inputs: VecDeque<i64>,
     w: i64,
     x: i64,
@@ -129,7 +151,7 @@ struct ALU {
     verbose: bool,
 }
 
-impl ALU {
+impl Alu {
     fn new(inputs: VecDeque<i64>, w: i64, x: i64, y: i64, z: i64) -> Self {
         Self {
             inputs,
@@ -232,13 +254,13 @@ enum Variable {
 }
 
 impl Variable {
-    fn from_str(s: &str) -> Self {
-        match s.trim() {
-            "w" | "W" => Self::W,
-            "x" | "X" => Self::X,
-            "y" | "Y" => Self::Y,
-            "z" | "Z" => Self::Z,
-            _ => panic!("unknown variable"),
+    fn from_str(s: Span) -> Result<Self, ParseError> {
+        match s.trim().as_str() {
+            "w" | "W" => Ok(Self::W),
+            "x" | "X" => Ok(Self::X),
+            "y" | "Y" => Ok(Self::Y),
+            "z" | "Z" => Ok(Self::Z),
+            _ => Err(s.error("a variable `w`, `x`, `y` or `z`")),
         }
     }
 }
@@ -250,12 +272,15 @@ enum VariableOrValue {
 }
 
 impl VariableOrValue {
-    fn from_str(s: &str) -> Self {
-        let v: Option<i64> = s.trim().parse().ok();
+    fn from_str(s: Span) -> Result<Self, ParseError> {
+        let v: Option<i64> = s.trim().as_str().parse().ok();
 
         match v {
-            Some(n) => Self::Value(n),
-            None => Self::Variable(Variable::from_str(s)),
+            Some(n) => Ok(Self::Value(n)),
+            None => match Variable::from_str(s) {
+                Ok(variable) => Ok(Self::Variable(variable)),
+                Err(_) => Err(s.error("a variable or a number")),
+            },
         }
     }
 }
@@ -271,31 +296,45 @@ enum Instruction {
 }
 
 impl Instruction {
-    fn from_str(s: &str) -> Self {
-        let mut iter = s.trim().split_whitespace();
-        let a = iter.next().unwrap().trim();
-        let b = iter.next().unwrap().trim();
-        let c = iter.next();
-
-        match a {
-            "inp" => Self::Inp(Variable::from_str(b)),
-            "add" => Self::Add(Variable::from_str(b), VariableOrValue::from_str(c.unwrap())),
-            "mul" => Self::Mul(Variable::from_str(b), VariableOrValue::from_str(c.unwrap())),
-            "div" => Self::Div(Variable::from_str(b), VariableOrValue::from_str(c.unwrap())),
-            "mod" => Self::Mod(Variable::from_str(b), VariableOrValue::from_str(c.unwrap())),
-            "eql" => Self::Eql(Variable::from_str(b), VariableOrValue::from_str(c.unwrap())),
-            _ => panic!("unknown instruction"),
+    fn from_str(s: Span) -> Result<Self, ParseError> {
+        let mut iter = s.split_whitespace();
+        let a = iter.expect("an instruction")?;
+        let b = Variable::from_str(iter.expect("a variable")?)?;
+        if a.as_str() == "inp" {
+            iter.end()?;
+            return Ok(Self::Inp(b));
+        }
+        let c = VariableOrValue::from_str(iter.expect("a variable or a number")?)?;
+        iter.end()?;
+
+        match a.as_str() {
+            "add" => Ok(Self::Add(b, c)),
+            "mul" => Ok(Self::Mul(b, c)),
+            "div" => Ok(Self::Div(b, c)),
+            "mod" => Ok(Self::Mod(b, c)),
+            "eql" => Ok(Self::Eql(b, c)),
+            _ => Err(a.error("`inp`, `add`, `mul`, `div`, `mod` or `eql`")),
         }
     }
 }
 
-fn read_file(filename: &str) -> String {
-    use std::fs::File;
-    use std::io::prelude::*;
-
-    let mut f = File::open(filename).expect("file not found");
-    let mut contents = String::new();
-    f.read_to_string(&mut contents)
-        .expect("something went wrong reading the file");
-    contents.trim().to_owned()
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_errors() {
+        let e = parse_input("inp w\nadd x 2\nmul y q").err().unwrap();
+        assert_eq!(
+            e.to_string(),
+            "line 3, column 7: expected a variable or a number, found `q`"
+        );
+        let e = parse_input("add x 1").err().unwrap();
+        assert_eq!(
+            e.to_string(),
+            "line 1, column 1: expected an `inp` instruction, found `add x 1`"
+        );
+        let e = parse_input("inp v").err().unwrap();
+        assert_eq!((e.line, e.column, e.token.as_str()), (1, 5, "v"));
+    }
 }
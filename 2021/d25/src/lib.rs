@@ -0,0 +1,114 @@
+use aoc_common::{Answer, Input, ParseError, Puzzle};
+use aoc_grid::Grid;
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    fn year(&self) -> u16 {
+        2021
+    }
+
+    fn day(&self) -> u8 {
+        25
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError> {
+        compute_solution_part_one(input.trimmed()).map(Answer::from)
+    }
+
+    fn has_part_two(&self) -> bool {
+        false
+    }
+}
+
+fn compute_solution_part_one(input: &str) -> Result<usize, ParseError> {
+    let mut cucumbers = SeaCucumbers::from_str(input)?;
+    let mut n_steps = 0;
+    while cucumbers.step() {
+        n_steps += 1;
+    }
+    Ok(n_steps + 1)
+}
+
+struct SeaCucumbers {
+    data: Grid<Option<Cucumber>>,
+}
+
+impl SeaCucumbers {
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        let data = Grid::parse(input, "`>`, `v` or `.`", |c| match c {
+            '>' => Some(Some(Cucumber::East)),
+            'v' => Some(Some(Cucumber::South)),
+            '.' => Some(None),
+            _ => None,
+        })?;
+
+        Ok(Self { data })
+    }
+
+    fn step(&mut self) -> bool {
+        let east = self.step_east();
+        let south = self.step_south();
+        east || south
+    }
+
+    fn step_east(&mut self) -> bool {
+        let mut change = false;
+
+        for (i, j) in self.data.positions() {
+            if self.data[(i, j)] != Some(Cucumber::East) {
+                continue;
+            }
+
+            if self.data.get_wrapping(i, j + 1).is_none() {
+                change = true;
+                self.data[(i, j)] = Some(Cucumber::EastToBeMoved);
+            }
+        }
+
+        for (i, j) in self.data.positions() {
+            if self.data[(i, j)] != Some(Cucumber::EastToBeMoved) {
+                continue;
+            }
+
+            self.data[(i, j)] = None;
+            *self.data.get_wrapping_mut(i, j + 1) = Some(Cucumber::East);
+        }
+
+        change
+    }
+
+    fn step_south(&mut self) -> bool {
+        let mut change = false;
+
+        for (i, j) in self.data.positions() {
+            if self.data[(i, j)] != Some(Cucumber::South) {
+                continue;
+            }
+
+            if self.data.get_wrapping(i + 1, j).is_none() {
+                change = true;
+                self.data[(i, j)] = Some(Cucumber::SouthToBeMoved);
+            }
+        }
+
+        for (i, j) in self.data.positions() {
+            if self.data[(i, j)] != Some(Cucumber::SouthToBeMoved) {
+                continue;
+            }
+
+            self.data[(i, j)] = None;
+            *self.data.get_wrapping_mut(i + 1, j) = Some(Cucumber::South);
+        }
+
+        change
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+enum Cucumber {
+    East,
+    EastToBeMoved,
+    South,
+    SouthToBeMoved,
+}
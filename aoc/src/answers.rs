@@ -0,0 +1,95 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Known-correct answers, keyed by (year, day, part).
+///
+/// The file has one answer per line in the form `<year> <day> <part> <answer>`. Everything after
+/// the part is the answer, so it may contain spaces; newlines in multi-line answers are written
+/// as `\n`. Empty lines and lines starting with `#` are ignored.
+pub struct Answers {
+    expected: BTreeMap<(u16, u8, u8), String>,
+}
+
+impl Answers {
+    pub fn from_path(path: &Path) -> Result<Self, String> {
+        let contents = std::fs::read_to_string(path)
+            .map_err(|e| format!("could not read {}: {}", path.display(), e))?;
+        Self::parse(&contents).map_err(|e| format!("{}:{}", path.display(), e))
+    }
+
+    fn parse(contents: &str) -> Result<Self, String> {
+        let mut expected = BTreeMap::new();
+        for (i, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let mut fields = line.splitn(4, char::is_whitespace);
+            let mut next_field = |name: &str| {
+                fields
+                    .next()
+                    .filter(|f| !f.is_empty())
+                    .ok_or_else(|| format!("{}: missing {}", i + 1, name))
+            };
+            let year = next_field("year")?;
+            let day = next_field("day")?;
+            let part = next_field("part")?;
+            let answer = next_field("answer")?.trim();
+
+            let parse_error =
+                |name: &str, value: &str| format!("{}: invalid {} {}", i + 1, name, value);
+            let year = year.parse().map_err(|_| parse_error("year", year))?;
+            let day = day.parse().map_err(|_| parse_error("day", day))?;
+            let part = match part {
+                "1" => 1,
+                "2" => 2,
+                _ => return Err(parse_error("part", part)),
+            };
+
+            if expected
+                .insert((year, day, part), answer.to_owned())
+                .is_some()
+            {
+                return Err(format!(
+                    "{}: duplicate answer for {} day {} part {}",
+                    i + 1,
+                    year,
+                    day,
+                    part
+                ));
+            }
+        }
+        Ok(Self { expected })
+    }
+
+    pub fn get(&self, year: u16, day: u8, part: u8) -> Option<&str> {
+        self.expected.get(&(year, day, part)).map(|s| s.as_str())
+    }
+}
+
+pub fn escape(answer: &str) -> String {
+    answer.trim_end().replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let answers = Answers::parse("# comment\n\n2020 13 1 2845\n2019 8 2 A B\\nC\n").unwrap();
+        assert_eq!(answers.get(2020, 13, 1), Some("2845"));
+        assert_eq!(answers.get(2019, 8, 2), Some("A B\\nC"));
+        assert_eq!(answers.get(2020, 13, 2), None);
+
+        assert!(Answers::parse("2020 13 3 1").is_err());
+        assert!(Answers::parse("2020 13 1").is_err());
+        assert!(Answers::parse("2020 13 1 1\n2020 13 1 2").is_err());
+    }
+
+    #[test]
+    fn escaping() {
+        assert_eq!(escape("42"), "42");
+        assert_eq!(escape("#.\n.#\n"), "#.\\n.#");
+    }
+}
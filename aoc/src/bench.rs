@@ -0,0 +1,224 @@
+use crate::check::format_duration;
+use crate::find_input;
+use crate::options::Options;
+use aoc_common::{Input, ParseError, Puzzle};
+use std::hint::black_box;
+use std::io::Write;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+pub struct Timing {
+    year: u16,
+    day: u8,
+    stage: &'static str,
+    runs: usize,
+    min: Duration,
+    median: Duration,
+    max: Duration,
+}
+
+impl Timing {
+    fn from_samples(puzzle: &dyn Puzzle, stage: &'static str, mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+        let n = samples.len();
+        let median = if n % 2 == 1 {
+            samples[n / 2]
+        } else {
+            (samples[n / 2 - 1] + samples[n / 2]) / 2
+        };
+        Self {
+            year: puzzle.year(),
+            day: puzzle.day(),
+            stage,
+            runs: n,
+            min: samples[0],
+            median,
+            max: samples[n - 1],
+        }
+    }
+}
+
+fn millis(d: Duration) -> f64 {
+    d.as_secs_f64() * 1000.0
+}
+
+/// Times `f` over `runs` runs. Returns `None` if the first run reports that there is nothing to
+/// time.
+fn sample<F>(runs: usize, mut f: F) -> Result<Option<Vec<Duration>>, ParseError>
+where
+    F: FnMut() -> Result<bool, ParseError>,
+{
+    let mut samples = Vec::with_capacity(runs);
+    for _ in 0..runs {
+        let start = Instant::now();
+        let timed = f()?;
+        samples.push(start.elapsed());
+        if !timed {
+            return Ok(None);
+        }
+    }
+    Ok(Some(samples))
+}
+
+fn bench_puzzle(
+    puzzle: &dyn Puzzle,
+    input: &Input,
+    options: &Options,
+) -> Result<Vec<Timing>, ParseError> {
+    let mut timings = Vec::new();
+    let runs = options.runs;
+
+    if let Some(samples) = sample(runs, || puzzle.parse(input))? {
+        timings.push(Timing::from_samples(puzzle, "parse", samples));
+    }
+    if options.runs_part(1) {
+        if let Some(samples) = sample(runs, || black_box(puzzle.part_one(input)).map(|_| true))? {
+            timings.push(Timing::from_samples(puzzle, "part1", samples));
+        }
+    }
+    if options.runs_part(2) {
+        let part_two = || {
+            black_box(puzzle.part_two(input))
+                .transpose()
+                .map(|a| a.is_some())
+        };
+        if let Some(samples) = sample(runs, part_two)? {
+            timings.push(Timing::from_samples(puzzle, "part2", samples));
+        }
+    }
+    Ok(timings)
+}
+
+/// Times the parsing and both parts of every selected day separately. Parts parse their input
+/// themselves, so their times include the parsing time. Days whose input cannot be read or
+/// fails to parse are reported and skipped; the result says whether there were any.
+pub fn bench(puzzles: &[&dyn Puzzle], options: &Options) -> (Vec<Timing>, bool) {
+    let mut timings = Vec::new();
+    let mut failed = Vec::new();
+    for &puzzle in puzzles {
+        let label = format!("{} day {:02}", puzzle.year(), puzzle.day());
+        let input = match find_input(puzzle, options) {
+            Ok(Some(input)) => input,
+            Ok(None) => continue,
+            Err(e) => {
+                eprintln!("{}", e);
+                failed.push(label);
+                continue;
+            }
+        };
+        let puzzle_timings = match bench_puzzle(puzzle, &input, options) {
+            Ok(puzzle_timings) => puzzle_timings,
+            Err(e) => {
+                eprintln!("{}: invalid input at {}", label, e);
+                failed.push(label);
+                continue;
+            }
+        };
+        for timing in puzzle_timings {
+            println!(
+                "{} day {:02} {}: min {}, median {}, max {} ({} runs)",
+                timing.year,
+                timing.day,
+                timing.stage,
+                format_duration(timing.min),
+                format_duration(timing.median),
+                format_duration(timing.max),
+                timing.runs
+            );
+            timings.push(timing);
+        }
+    }
+    if !failed.is_empty() {
+        eprintln!("failed: {}", failed.join(", "));
+    }
+    (timings, failed.is_empty())
+}
+
+fn write_csv<W: Write>(mut w: W, timings: &[Timing]) -> std::io::Result<()> {
+    writeln!(w, "year,day,stage,runs,min_ms,median_ms,max_ms")?;
+    for t in timings {
+        writeln!(
+            w,
+            "{},{},{},{},{:.6},{:.6},{:.6}",
+            t.year,
+            t.day,
+            t.stage,
+            t.runs,
+            millis(t.min),
+            millis(t.median),
+            millis(t.max)
+        )?;
+    }
+    Ok(())
+}
+
+fn write_json<W: Write>(mut w: W, timings: &[Timing]) -> std::io::Result<()> {
+    writeln!(w, "[")?;
+    for (i, t) in timings.iter().enumerate() {
+        let separator = if i + 1 < timings.len() { "," } else { "" };
+        writeln!(
+            w,
+            "  {{\"year\": {}, \"day\": {}, \"stage\": \"{}\", \"runs\": {}, \"min_ms\": {:.6}, \
+             \"median_ms\": {:.6}, \"max_ms\": {:.6}}}{}",
+            t.year,
+            t.day,
+            t.stage,
+            t.runs,
+            millis(t.min),
+            millis(t.median),
+            millis(t.max),
+            separator
+        )?;
+    }
+    writeln!(w, "]")
+}
+
+/// Writes the timings as CSV or JSON, depending on the extension of `path`.
+pub fn write_timings(path: &Path, timings: &[Timing]) -> Result<(), String> {
+    let file = std::fs::File::create(path)
+        .map_err(|e| format!("could not create {}: {}", path.display(), e))?;
+    let w = std::io::BufWriter::new(file);
+    let result = match path.extension().and_then(|e| e.to_str()) {
+        Some("json") => write_json(w, timings),
+        _ => write_csv(w, timings),
+    };
+    result.map_err(|e| format!("could not write {}: {}", path.display(), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timing(stage: &'static str, ms: u64) -> Timing {
+        Timing {
+            year: 2020,
+            day: 15,
+            stage,
+            runs: 3,
+            min: Duration::from_millis(ms),
+            median: Duration::from_millis(2 * ms),
+            max: Duration::from_millis(3 * ms),
+        }
+    }
+
+    #[test]
+    fn csv_and_json() {
+        let timings = vec![timing("parse", 1), timing("part1", 2)];
+
+        let mut csv = Vec::new();
+        write_csv(&mut csv, &timings).unwrap();
+        assert_eq!(
+            String::from_utf8(csv).unwrap(),
+            "year,day,stage,runs,min_ms,median_ms,max_ms\n\
+             2020,15,parse,3,1.000000,2.000000,3.000000\n\
+             2020,15,part1,3,2.000000,4.000000,6.000000\n"
+        );
+
+        let mut json = Vec::new();
+        write_json(&mut json, &timings).unwrap();
+        let json = String::from_utf8(json).unwrap();
+        assert!(json.starts_with("[\n  {\"year\": 2020, \"day\": 15, \"stage\": \"parse\""));
+        assert!(json.contains("\"max_ms\": 3.000000},\n"));
+        assert!(json.ends_with("\"max_ms\": 6.000000}\n]\n"));
+    }
+}
@@ -0,0 +1,140 @@
+use crate::answers::{escape, Answers};
+use crate::find_input;
+use crate::options::Options;
+use aoc_common::{Answer, Input, ParseError, Puzzle};
+use std::panic::{self, AssertUnwindSafe};
+use std::time::{Duration, Instant};
+
+enum Outcome {
+    Pass,
+    Mismatch { expected: String, actual: String },
+    Fail(String),
+    Unrecorded(String),
+}
+
+#[derive(Default)]
+struct Summary {
+    passed: usize,
+    mismatched: usize,
+    failed: usize,
+    unrecorded: usize,
+    skipped: usize,
+}
+
+fn panic_message(payload: Box<dyn std::any::Any + Send>) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        (*s).to_owned()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "unknown panic".to_owned()
+    }
+}
+
+pub fn solve(
+    puzzle: &dyn Puzzle,
+    part: u8,
+    input: &Input,
+) -> (Result<Option<Result<Answer, ParseError>>, String>, Duration) {
+    let start = Instant::now();
+    let result = panic::catch_unwind(AssertUnwindSafe(|| match part {
+        1 => Some(puzzle.part_one(input)),
+        _ => puzzle.part_two(input),
+    }));
+    (result.map_err(panic_message), start.elapsed())
+}
+
+fn check_part(
+    puzzle: &dyn Puzzle,
+    part: u8,
+    input: &Input,
+    answers: &Answers,
+) -> Option<(Outcome, Duration)> {
+    let (result, elapsed) = solve(puzzle, part, input);
+    let outcome = match result {
+        Ok(None) => return None,
+        Ok(Some(Ok(answer))) => {
+            let actual = escape(&answer.to_string());
+            match answers.get(puzzle.year(), puzzle.day(), part) {
+                None => Outcome::Unrecorded(actual),
+                Some(expected) if expected == actual => Outcome::Pass,
+                Some(expected) => Outcome::Mismatch {
+                    expected: expected.to_owned(),
+                    actual,
+                },
+            }
+        }
+        Ok(Some(Err(e))) => Outcome::Fail(format!("invalid input at {}", e)),
+        Err(message) => Outcome::Fail(format!("panicked: {}", message)),
+    };
+    Some((outcome, elapsed))
+}
+
+pub fn format_duration(d: Duration) -> String {
+    format!("{:.3} ms", d.as_secs_f64() * 1000.0)
+}
+
+/// Runs every selected part against its stored input and compares the result with the answers
+/// file. Returns whether all checked parts passed.
+pub fn check(puzzles: &[&dyn Puzzle], options: &Options, answers: &Answers) -> bool {
+    let mut summary = Summary::default();
+
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    for &puzzle in puzzles {
+        let label = format!("{} day {:02}", puzzle.year(), puzzle.day());
+        let input = match find_input(puzzle, options) {
+            Ok(Some(input)) => input,
+            Ok(None) => {
+                summary.skipped += 1;
+                continue;
+            }
+            Err(e) => {
+                println!("{}: fail, {}", label, e);
+                summary.failed += 1;
+                continue;
+            }
+        };
+
+        for part in [1, 2].into_iter().filter(|&p| options.runs_part(p)) {
+            let (outcome, elapsed) = match check_part(puzzle, part, &input, answers) {
+                Some(result) => result,
+                None => continue,
+            };
+            let status = match outcome {
+                Outcome::Pass => {
+                    summary.passed += 1;
+                    "pass".to_owned()
+                }
+                Outcome::Mismatch { expected, actual } => {
+                    summary.mismatched += 1;
+                    format!("mismatch, expected {} but got {}", expected, actual)
+                }
+                Outcome::Fail(message) => {
+                    summary.failed += 1;
+                    format!("fail, {}", message)
+                }
+                Outcome::Unrecorded(actual) => {
+                    summary.unrecorded += 1;
+                    format!("no recorded answer, got {}", actual)
+                }
+            };
+            println!(
+                "{} part {}: {} ({})",
+                label,
+                part,
+                status,
+                format_duration(elapsed)
+            );
+        }
+    }
+
+    panic::set_hook(default_hook);
+
+    println!(
+        "{} passed, {} mismatched, {} failed, {} without recorded answer, {} skipped",
+        summary.passed, summary.mismatched, summary.failed, summary.unrecorded, summary.skipped
+    );
+    summary.mismatched == 0 && summary.failed == 0
+}
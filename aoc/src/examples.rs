@@ -0,0 +1,246 @@
+use crate::answers::escape;
+use crate::check::{format_duration, solve};
+use aoc_common::{Answer, Input, ParseError, Puzzle};
+use std::panic;
+use std::path::{Path, PathBuf};
+
+/// An example input from a puzzle description, together with the answers the description gives
+/// for it.
+///
+/// Example files live in `<year>/dNN/examples/*.txt` next to the crate of their day, or below the
+/// directory given with `--examples-root` if the sources are elsewhere. They start
+/// with a header of `part <1|2>: <answer>` lines, written like the answers file with newlines as
+/// `\n`, followed by an empty line and the example input. Many examples only illustrate one part,
+/// so parts without an answer in the header are not run.
+pub struct Example {
+    name: String,
+    answers: [Option<String>; 2],
+    input: Input,
+}
+
+impl Example {
+    fn parse(name: String, contents: &str) -> Result<Self, String> {
+        // The header ends at the first empty line, which may end in `\r\n` like all others
+        let mut offset = 0;
+        let empty_line = contents.split_inclusive('\n').find_map(|line| {
+            let start = offset;
+            offset += line.len();
+            line.trim_end_matches(['\r', '\n'])
+                .is_empty()
+                .then_some((start, offset))
+        });
+        let (start, end) = empty_line
+            .ok_or_else(|| "missing empty line between the answers and the input".to_owned())?;
+        let (header, input) = (&contents[..start], &contents[end..]);
+
+        let mut answers = [None, None];
+        for (i, line) in header.lines().enumerate() {
+            let (part, answer) = line
+                .strip_prefix("part ")
+                .and_then(|rest| rest.split_once(':'))
+                .ok_or_else(|| format!("{}: expected `part <1|2>: <answer>`", i + 1))?;
+            let slot = match part {
+                "1" => &mut answers[0],
+                "2" => &mut answers[1],
+                _ => return Err(format!("{}: invalid part {}", i + 1, part)),
+            };
+            if slot.replace(answer.trim().to_owned()).is_some() {
+                return Err(format!("{}: duplicate answer for part {}", i + 1, part));
+            }
+        }
+        if answers.iter().all(Option::is_none) {
+            return Err("no answers given".to_owned());
+        }
+
+        Ok(Self {
+            name,
+            answers,
+            input: Input::from_string(input),
+        })
+    }
+
+    fn answer(&self, part: u8) -> Option<&str> {
+        self.answers[part as usize - 1].as_deref()
+    }
+}
+
+pub fn examples_dir(root: &Path, puzzle: &dyn Puzzle) -> PathBuf {
+    root.join(puzzle.year().to_string())
+        .join(format!("d{:02}", puzzle.day()))
+        .join("examples")
+}
+
+/// Loads all examples of `puzzle` in the order of their file names. A day without an examples
+/// directory has no examples.
+pub fn load(root: &Path, puzzle: &dyn Puzzle) -> Result<Vec<Example>, String> {
+    let dir = examples_dir(root, puzzle);
+    if !dir.is_dir() {
+        return Ok(Vec::new());
+    }
+    let entries =
+        std::fs::read_dir(&dir).map_err(|e| format!("could not read {}: {}", dir.display(), e))?;
+    let mut paths = Vec::new();
+    for entry in entries {
+        let path = entry
+            .map_err(|e| format!("could not read {}: {}", dir.display(), e))?
+            .path();
+        if path.extension().is_some_and(|ext| ext == "txt") {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+
+    paths
+        .into_iter()
+        .map(|path| {
+            let contents = std::fs::read_to_string(&path)
+                .map_err(|e| format!("could not read {}: {}", path.display(), e))?;
+            let name = path.file_name().unwrap().to_string_lossy().into_owned();
+            Example::parse(name, &contents).map_err(|e| format!("{}:{}", path.display(), e))
+        })
+        .collect()
+}
+
+#[derive(Default)]
+struct Summary {
+    passed: usize,
+    mismatched: usize,
+    failed: usize,
+    uncovered: Vec<String>,
+}
+
+impl Summary {
+    fn record(
+        &mut self,
+        result: Result<Option<Result<Answer, ParseError>>, String>,
+        expected: &str,
+    ) -> String {
+        match result {
+            Ok(Some(Ok(answer))) => {
+                let actual = escape(&answer.to_string());
+                if actual == expected {
+                    self.passed += 1;
+                    "pass".to_owned()
+                } else {
+                    self.mismatched += 1;
+                    format!("mismatch, expected {} but got {}", expected, actual)
+                }
+            }
+            Ok(Some(Err(e))) => {
+                self.failed += 1;
+                format!("fail, invalid input at {}", e)
+            }
+            Ok(None) => {
+                self.failed += 1;
+                "fail, the day has no such part".to_owned()
+            }
+            Err(message) => {
+                self.failed += 1;
+                format!("fail, panicked: {}", message)
+            }
+        }
+    }
+}
+
+/// Runs every example of the selected parts and compares the results with the answers in the
+/// example files, then lists the parts that no example covers. Returns whether all examples
+/// passed; missing coverage alone does not fail.
+pub fn run_examples(puzzles: &[&dyn Puzzle], parts: &[u8], root: &Path) -> bool {
+    let mut summary = Summary::default();
+
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    for &puzzle in puzzles {
+        let label = format!("{} day {:02}", puzzle.year(), puzzle.day());
+        let examples = match load(root, puzzle) {
+            Ok(examples) => examples,
+            Err(e) => {
+                println!("{}: fail, {}", label, e);
+                summary.failed += 1;
+                continue;
+            }
+        };
+
+        for &part in parts {
+            let mut covered = false;
+            for example in &examples {
+                let expected = match example.answer(part) {
+                    Some(expected) => expected,
+                    None => continue,
+                };
+                covered = true;
+                let (result, elapsed) = solve(puzzle, part, &example.input);
+                let status = summary.record(result, expected);
+                println!(
+                    "{} example {} part {}: {} ({})",
+                    label,
+                    example.name,
+                    part,
+                    status,
+                    format_duration(elapsed)
+                );
+            }
+            if covered {
+                continue;
+            }
+            if examples.is_empty() {
+                summary.uncovered.push(label.clone());
+            } else if part == 1 || puzzle.has_part_two() {
+                summary.uncovered.push(format!("{} part {}", label, part));
+            }
+        }
+    }
+
+    panic::set_hook(default_hook);
+
+    summary.uncovered.dedup();
+    println!(
+        "{} passed, {} mismatched, {} failed",
+        summary.passed, summary.mismatched, summary.failed
+    );
+    if !summary.uncovered.is_empty() {
+        println!(
+            "{} without examples: {}",
+            summary.uncovered.len(),
+            summary.uncovered.join(", ")
+        );
+    }
+    summary.mismatched == 0 && summary.failed == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+
+    #[test]
+    fn parse() {
+        let example =
+            Example::parse("a.txt".to_owned(), "part 1: 7\npart 2: A\\nB\n\n1\n2\n").unwrap();
+        assert_eq!(example.answer(1), Some("7"));
+        assert_eq!(example.answer(2), Some("A\\nB"));
+        assert_eq!(example.input.untrimmed(), "1\n2\n");
+
+        let example = Example::parse("b.txt".to_owned(), "part 2: 5\n\n  #\n").unwrap();
+        assert_eq!(example.answer(1), None);
+        assert_eq!(example.input.untrimmed(), "  #\n");
+
+        let example =
+            Example::parse("c.txt".to_owned(), "part 1: 7\r\npart 2: 8\r\n\r\n1\r\n").unwrap();
+        assert_eq!(example.answer(1), Some("7"));
+        assert_eq!(example.answer(2), Some("8"));
+        assert_eq!(example.input.untrimmed(), "1\r\n");
+
+        assert!(Example::parse("c.txt".to_owned(), "part 1: 7\n1\n2\n").is_err());
+        assert!(Example::parse("d.txt".to_owned(), "part 3: 7\n\n1\n").is_err());
+        assert!(Example::parse("e.txt".to_owned(), "part 1: 7\npart 1: 8\n\n1\n").is_err());
+        assert!(Example::parse("f.txt".to_owned(), "\n\n1\n").is_err());
+    }
+
+    #[test]
+    fn examples() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+        assert!(run_examples(&registry::puzzles(), &[1, 2], &root));
+    }
+}
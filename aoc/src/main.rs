@@ -0,0 +1,185 @@
+mod answers;
+mod bench;
+mod check;
+mod examples;
+mod options;
+mod output;
+mod registry;
+
+use answers::Answers;
+use aoc_common::{Input, InputError, PictureExport, Puzzle};
+use options::{Mode, Options, USAGE};
+use output::{print_answer, print_status};
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+fn input_path(puzzle: &dyn Puzzle, options: &Options) -> PathBuf {
+    match &options.input {
+        Some(input) => input.clone(),
+        None => options
+            .inputs
+            .join(puzzle.year().to_string())
+            .join(format!("d{:02}.txt", puzzle.day())),
+    }
+}
+
+/// Loads the input of `puzzle`. Days without an explicit input filename and without a stored
+/// input are reported as skipped and yield `None`.
+fn find_input(puzzle: &dyn Puzzle, options: &Options) -> Result<Option<Input>, InputError> {
+    let path = input_path(puzzle, options);
+    if options.input.is_none() && !path.exists() {
+        let message = format!(
+            "{} day {:02}: skipped, no input at {}",
+            puzzle.year(),
+            puzzle.day(),
+            path.display()
+        );
+        print_status(options.output, &message);
+        return Ok(None);
+    }
+    if path == Path::new("-") {
+        Input::from_stdin().map(Some)
+    } else {
+        Input::from_path(&path).map(Some)
+    }
+}
+
+fn export_image(puzzle: &dyn Puzzle, input: &Input, path: &Path, options: &Options) -> bool {
+    let label = format!("{} day {:02}", puzzle.year(), puzzle.day());
+    let mut export = PictureExport::new(path.to_owned(), options.frames);
+    match puzzle.export_image(input, &mut export) {
+        Ok(true) => {
+            let mut message = format!("{}: image written to {}", label, path.display());
+            if options.frames {
+                message.push_str(&format!(" ({} frames)", export.frame_count()));
+            }
+            print_status(options.output, &message);
+            true
+        }
+        Ok(false) => {
+            eprintln!("{}: no image to export", label);
+            false
+        }
+        Err(e) => {
+            eprintln!("{}: could not export image, {}", label, e);
+            false
+        }
+    }
+}
+
+fn run(puzzle: &dyn Puzzle, options: &Options) -> Result<bool, InputError> {
+    let input = match find_input(puzzle, options)? {
+        Some(input) => input,
+        None => return Ok(true),
+    };
+
+    let mut succeeded = true;
+    for part in [1, 2].into_iter().filter(|&p| options.runs_part(p)) {
+        let start = Instant::now();
+        let result = match part {
+            1 => Some(puzzle.part_one(&input)),
+            _ => puzzle.part_two(&input),
+        };
+        match result {
+            Some(Ok(answer)) => {
+                print_answer(options.output, puzzle, part, &answer, start.elapsed())
+            }
+            Some(Err(e)) => {
+                eprintln!(
+                    "{} day {:02} part {}: invalid input at {}\n{}",
+                    puzzle.year(),
+                    puzzle.day(),
+                    part,
+                    e,
+                    e.excerpt()
+                );
+                succeeded = false;
+            }
+            None => (),
+        }
+    }
+    if let Some(path) = &options.export_image {
+        succeeded &= export_image(puzzle, &input, path, options);
+    }
+    Ok(succeeded)
+}
+
+fn main() {
+    let options = match Options::from_args(std::env::args().skip(1)) {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("{}", e);
+            eprintln!("{}", USAGE);
+            std::process::exit(2);
+        }
+    };
+
+    let puzzles: Vec<_> = registry::puzzles()
+        .into_iter()
+        .filter(|p| options.year.is_none_or(|y| p.year() == y))
+        .filter(|p| options.day.is_none_or(|d| p.day() == d))
+        .collect();
+
+    if puzzles.is_empty() {
+        eprintln!("no solution matches the given year and day");
+        std::process::exit(1);
+    }
+    if options.input.is_some() && puzzles.len() != 1 {
+        eprintln!("an input filename requires --year and --day to select a single solution");
+        std::process::exit(2);
+    }
+    if options.export_image.is_some() && puzzles.len() != 1 {
+        eprintln!("--export-image requires --year and --day to select a single solution");
+        std::process::exit(2);
+    }
+
+    let succeeded = match options.mode {
+        Mode::Run => {
+            let mut succeeded = true;
+            for puzzle in puzzles {
+                match run(puzzle, &options) {
+                    Ok(true) => (),
+                    Ok(false) => succeeded = false,
+                    Err(e) => {
+                        eprintln!("{}", e);
+                        succeeded = false;
+                    }
+                }
+            }
+            succeeded
+        }
+        Mode::Check => {
+            let answers = match Answers::from_path(&options.answers_path()) {
+                Ok(answers) => answers,
+                Err(e) => {
+                    eprintln!("{}", e);
+                    std::process::exit(1);
+                }
+            };
+            check::check(&puzzles, &options, &answers)
+        }
+        Mode::Examples => {
+            let parts: Vec<_> = [1, 2]
+                .into_iter()
+                .filter(|&p| options.runs_part(p))
+                .collect();
+            examples::run_examples(&puzzles, &parts, &options.examples_root)
+        }
+        Mode::Bench => {
+            let (timings, succeeded) = bench::bench(&puzzles, &options);
+            match &options.bench_output {
+                Some(path) => match bench::write_timings(path, &timings) {
+                    Ok(()) => succeeded,
+                    Err(e) => {
+                        eprintln!("{}", e);
+                        false
+                    }
+                },
+                None => succeeded,
+            }
+        }
+    };
+    if !succeeded {
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,137 @@
+use crate::output::Format;
+use std::path::{Path, PathBuf};
+
+pub const USAGE: &str = "Usage: aoc [--year <year>] [--day <day>] [--part <1|2>] \
+[--inputs <directory>] [--output <text|json>] [--check [--answers <file>]] \
+[--examples [--examples-root <directory>]] \
+[--bench [--runs <n>] [--bench-output <file.csv|file.json>]] \
+[--export-image <file.pbm|file.pgm|file.ppm> [--frames]] [<input filename> | -]";
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    Run,
+    Check,
+    Examples,
+    Bench,
+}
+
+pub struct Options {
+    pub mode: Mode,
+    pub year: Option<u16>,
+    pub day: Option<u8>,
+    pub part: Option<u8>,
+    pub inputs: PathBuf,
+    pub input: Option<PathBuf>,
+    pub answers: Option<PathBuf>,
+    pub examples_root: PathBuf,
+    pub runs: usize,
+    pub bench_output: Option<PathBuf>,
+    pub export_image: Option<PathBuf>,
+    pub frames: bool,
+    pub output: Format,
+}
+
+impl Options {
+    pub fn from_args<I: Iterator<Item = String>>(mut args: I) -> Result<Self, String> {
+        let mut options = Self {
+            mode: Mode::Run,
+            year: None,
+            day: None,
+            part: None,
+            inputs: PathBuf::from("inputs"),
+            input: None,
+            answers: None,
+            // The source tree this binary was built from
+            examples_root: Path::new(env!("CARGO_MANIFEST_DIR")).join(".."),
+            runs: 10,
+            bench_output: None,
+            export_image: None,
+            frames: false,
+            output: Format::Text,
+        };
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--year" => options.year = Some(parse_value(&arg, args.next())?),
+                "--day" => options.day = Some(parse_value(&arg, args.next())?),
+                "--part" => {
+                    let part = parse_value(&arg, args.next())?;
+                    if part != 1 && part != 2 {
+                        return Err(format!("invalid part {}, expected 1 or 2", part));
+                    }
+                    options.part = Some(part);
+                }
+                "--inputs" => options.inputs = PathBuf::from(expect_value(&arg, args.next())?),
+                "--output" => options.output = parse_value(&arg, args.next())?,
+                "--check" => options.mode = Mode::Check,
+                "--examples" => options.mode = Mode::Examples,
+                "--examples-root" => {
+                    options.examples_root = PathBuf::from(expect_value(&arg, args.next())?)
+                }
+                "--bench" => options.mode = Mode::Bench,
+                "--runs" => {
+                    options.runs = parse_value(&arg, args.next())?;
+                    if options.runs == 0 {
+                        return Err("--runs needs at least one run".to_owned());
+                    }
+                }
+                "--bench-output" => {
+                    options.bench_output = Some(PathBuf::from(expect_value(&arg, args.next())?))
+                }
+                "--export-image" => {
+                    options.export_image = Some(PathBuf::from(expect_value(&arg, args.next())?))
+                }
+                "--frames" => options.frames = true,
+                "--answers" => {
+                    options.answers = Some(PathBuf::from(expect_value(&arg, args.next())?))
+                }
+                _ if arg.starts_with("--") => return Err(format!("unknown option {}", arg)),
+                _ => {
+                    if options.input.is_some() {
+                        return Err("more than one input filename given".to_owned());
+                    }
+                    options.input = Some(PathBuf::from(arg));
+                }
+            }
+        }
+
+        if options.frames && options.export_image.is_none() {
+            return Err("--frames needs --export-image".to_owned());
+        }
+        if options.output == Format::Json && options.mode != Mode::Run {
+            return Err(
+                "--output json cannot be combined with --check, --examples or --bench".to_owned(),
+            );
+        }
+        if options.export_image.is_some() && options.mode != Mode::Run {
+            return Err(
+                "--export-image cannot be combined with --check, --examples or --bench".to_owned(),
+            );
+        }
+
+        Ok(options)
+    }
+
+    /// The answers file defaults to `answers.txt` next to the stored inputs.
+    pub fn answers_path(&self) -> PathBuf {
+        match &self.answers {
+            Some(path) => path.clone(),
+            None => self.inputs.join("answers.txt"),
+        }
+    }
+
+    pub fn runs_part(&self, part: u8) -> bool {
+        self.part.is_none() || self.part == Some(part)
+    }
+}
+
+fn expect_value(option: &str, value: Option<String>) -> Result<String, String> {
+    value.ok_or_else(|| format!("missing value for {}", option))
+}
+
+fn parse_value<T: std::str::FromStr>(option: &str, value: Option<String>) -> Result<T, String> {
+    let value = expect_value(option, value)?;
+    value
+        .parse()
+        .map_err(|_| format!("invalid value {} for {}", value, option))
+}
@@ -0,0 +1,145 @@
+use aoc_common::{Answer, Puzzle};
+use std::time::Duration;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+impl std::str::FromStr for Format {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, ()> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(()),
+        }
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut result = String::with_capacity(s.len() + 2);
+    result.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            c if (c as u32) < 0x20 => result.push_str(&format!("\\u{:04x}", c as u32)),
+            c => result.push(c),
+        }
+    }
+    result.push('"');
+    result
+}
+
+/// Numbers stay numbers, text (including multi-line art, without its trailing newline) becomes a
+/// string.
+fn json_answer(answer: &Answer) -> String {
+    match answer {
+        Answer::Signed(v) => v.to_string(),
+        Answer::Unsigned(v) => v.to_string(),
+        Answer::Text(s) => json_string(s.trim_end_matches('\n')),
+    }
+}
+
+fn format_answer(
+    format: Format,
+    puzzle: &dyn Puzzle,
+    part: u8,
+    answer: &Answer,
+    elapsed: Duration,
+) -> String {
+    match format {
+        Format::Text => {
+            let label = format!("{} day {:02} part {}", puzzle.year(), puzzle.day(), part);
+            match answer {
+                Answer::Text(s) if s.contains('\n') => format!("{} =\n{}", label, s.trim_end()),
+                _ => format!("{} = {}", label, answer),
+            }
+        }
+        Format::Json => format!(
+            "{{\"year\": {}, \"day\": {}, \"part\": {}, \"answer\": {}, \"elapsed_ms\": {:.6}}}",
+            puzzle.year(),
+            puzzle.day(),
+            part,
+            json_answer(answer),
+            elapsed.as_secs_f64() * 1000.0
+        ),
+    }
+}
+
+/// Prints one answer. JSON output has one object per line.
+pub fn print_answer(
+    format: Format,
+    puzzle: &dyn Puzzle,
+    part: u8,
+    answer: &Answer,
+    elapsed: Duration,
+) {
+    println!("{}", format_answer(format, puzzle, part, answer, elapsed));
+}
+
+/// Prints a message that is not an answer. With JSON output it goes to stderr, so that stdout
+/// only contains answers.
+pub fn print_status(format: Format, message: &str) {
+    match format {
+        Format::Text => println!("{}", message),
+        Format::Json => eprintln!("{}", message),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{Input, ParseError};
+
+    struct Dummy;
+
+    impl Puzzle for Dummy {
+        fn year(&self) -> u16 {
+            2019
+        }
+
+        fn day(&self) -> u8 {
+            8
+        }
+
+        fn part_one(&self, _input: &Input) -> Result<Answer, ParseError> {
+            Ok(Answer::Unsigned(0))
+        }
+    }
+
+    #[test]
+    fn json() {
+        assert_eq!(json_string("a\"b\\c\nd\u{1}"), "\"a\\\"b\\\\c\\nd\\u0001\"");
+
+        let elapsed = Duration::from_micros(1500);
+        assert_eq!(
+            format_answer(Format::Json, &Dummy, 1, &Answer::Signed(-3), elapsed),
+            "{\"year\": 2019, \"day\": 8, \"part\": 1, \"answer\": -3, \"elapsed_ms\": 1.500000}"
+        );
+        assert_eq!(
+            format_answer(Format::Json, &Dummy, 2, &"■□\n□■\n".into(), elapsed),
+            "{\"year\": 2019, \"day\": 8, \"part\": 2, \"answer\": \"■□\\n□■\", \
+             \"elapsed_ms\": 1.500000}"
+        );
+    }
+
+    #[test]
+    fn text() {
+        let elapsed = Duration::from_millis(1);
+        assert_eq!(
+            format_answer(Format::Text, &Dummy, 1, &Answer::Unsigned(42), elapsed),
+            "2019 day 08 part 1 = 42"
+        );
+        assert_eq!(
+            format_answer(Format::Text, &Dummy, 2, &"#.\n.#\n".into(), elapsed),
+            "2019 day 08 part 2 =\n#.\n.#"
+        );
+    }
+}
@@ -0,0 +1,97 @@
+use aoc_common::Puzzle;
+
+pub fn puzzles() -> Vec<&'static dyn Puzzle> {
+    vec![
+        &aoc_2017_d01::Solution,
+        &aoc_2017_d02::Solution,
+        &aoc_2017_d03::Solution,
+        &aoc_2017_d04::Solution,
+        &aoc_2017_d05::Solution,
+        &aoc_2017_d06::Solution,
+        &aoc_2017_d07::Solution,
+        &aoc_2017_d08::Solution,
+        &aoc_2017_d09::Solution,
+        &aoc_2017_d10::Solution,
+        &aoc_2017_d11::Solution,
+        &aoc_2017_d12::Solution,
+        &aoc_2017_d13::Solution,
+        &aoc_2017_d14::Solution,
+        &aoc_2017_d15::Solution,
+        &aoc_2017_d16::Solution,
+        &aoc_2017_d17::Solution,
+        &aoc_2017_d18::Solution,
+        &aoc_2017_d19::Solution,
+        &aoc_2017_d20::Solution,
+        &aoc_2017_d21::Solution,
+        &aoc_2017_d22::Solution,
+        &aoc_2017_d23::Solution,
+        &aoc_2017_d24::Solution,
+        &aoc_2017_d25::Solution,
+        &aoc_2019_d01::Solution,
+        &aoc_2019_d02::Solution,
+        &aoc_2019_d03::Solution,
+        &aoc_2019_d04::Solution,
+        &aoc_2019_d05::Solution,
+        &aoc_2019_d06::Solution,
+        &aoc_2019_d07::Solution,
+        &aoc_2019_d08::Solution,
+        &aoc_2019_d09::Solution,
+        &aoc_2019_d10::Solution,
+        &aoc_2019_d11::Solution,
+        &aoc_2019_d12::Solution,
+        &aoc_2019_d13::Solution,
+        &aoc_2019_d14::Solution,
+        &aoc_2019_d15::Solution,
+        &aoc_2019_d16::Solution,
+        &aoc_2020_d01::Solution,
+        &aoc_2020_d02::Solution,
+        &aoc_2020_d03::Solution,
+        &aoc_2020_d04::Solution,
+        &aoc_2020_d05::Solution,
+        &aoc_2020_d06::Solution,
+        &aoc_2020_d07::Solution,
+        &aoc_2020_d08::Solution,
+        &aoc_2020_d09::Solution,
+        &aoc_2020_d10::Solution,
+        &aoc_2020_d11::Solution,
+        &aoc_2020_d12::Solution,
+        &aoc_2020_d13::Solution,
+        &aoc_2020_d14::Solution,
+        &aoc_2020_d15::Solution,
+        &aoc_2020_d16::Solution,
+        &aoc_2020_d17::Solution,
+        &aoc_2020_d18::Solution,
+        &aoc_2020_d19::Solution,
+        &aoc_2020_d20::Solution,
+        &aoc_2020_d21::Solution,
+        &aoc_2020_d22::Solution,
+        &aoc_2020_d23::Solution,
+        &aoc_2020_d24::Solution,
+        &aoc_2020_d25::Solution,
+        &aoc_2021_d01::Solution,
+        &aoc_2021_d02::Solution,
+        &aoc_2021_d03::Solution,
+        &aoc_2021_d04::Solution,
+        &aoc_2021_d05::Solution,
+        &aoc_2021_d06::Solution,
+        &aoc_2021_d07::Solution,
+        &aoc_2021_d08::Solution,
+        &aoc_2021_d09::Solution,
+        &aoc_2021_d10::Solution,
+        &aoc_2021_d11::Solution,
+        &aoc_2021_d12::Solution,
+        &aoc_2021_d13::Solution,
+        &aoc_2021_d14::Solution,
+        &aoc_2021_d15::Solution,
+        &aoc_2021_d16::Solution,
+        &aoc_2021_d17::Solution,
+        &aoc_2021_d18::Solution,
+        &aoc_2021_d19::Solution,
+        &aoc_2021_d20::Solution,
+        &aoc_2021_d21::Solution,
+        &aoc_2021_d22::Solution,
+        &aoc_2021_d23::Solution,
+        &aoc_2021_d24::Solution,
+        &aoc_2021_d25::Solution,
+    ]
+}
@@ -0,0 +1,175 @@
+use std::fmt;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+#[derive(Debug)]
+pub enum InputError {
+    Io {
+        path: Option<PathBuf>,
+        source: io::Error,
+    },
+    InvalidUtf8 {
+        path: Option<PathBuf>,
+        position: usize,
+    },
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let describe = |path: &Option<PathBuf>| match path {
+            Some(p) => p.display().to_string(),
+            None => "stdin".to_owned(),
+        };
+        match self {
+            InputError::Io { path, source } => {
+                write!(f, "could not read {}: {}", describe(path), source)
+            }
+            InputError::InvalidUtf8 { path, position } => write!(
+                f,
+                "{} is not valid UTF-8 (first invalid byte at offset {})",
+                describe(path),
+                position
+            ),
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InputError::Io { source, .. } => Some(source),
+            InputError::InvalidUtf8 { .. } => None,
+        }
+    }
+}
+
+/// Puzzle input exactly as it was read. The views decide how much whitespace is significant,
+/// nothing is trimmed on loading.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Input {
+    contents: String,
+}
+
+impl Input {
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self, InputError> {
+        let path = path.as_ref();
+        let bytes = std::fs::read(path).map_err(|source| InputError::Io {
+            path: Some(path.to_owned()),
+            source,
+        })?;
+        Self::from_bytes(bytes, Some(path.to_owned()))
+    }
+
+    pub fn from_stdin() -> Result<Self, InputError> {
+        let mut bytes = Vec::new();
+        io::stdin()
+            .read_to_end(&mut bytes)
+            .map_err(|source| InputError::Io { path: None, source })?;
+        Self::from_bytes(bytes, None)
+    }
+
+    pub fn from_string<S: Into<String>>(contents: S) -> Self {
+        Self {
+            contents: contents.into(),
+        }
+    }
+
+    fn from_bytes(bytes: Vec<u8>, path: Option<PathBuf>) -> Result<Self, InputError> {
+        String::from_utf8(bytes)
+            .map(Self::from_string)
+            .map_err(|e| InputError::InvalidUtf8 {
+                path,
+                position: e.utf8_error().valid_up_to(),
+            })
+    }
+
+    pub fn untrimmed(&self) -> &str {
+        &self.contents
+    }
+
+    pub fn trimmed(&self) -> &str {
+        self.contents.trim()
+    }
+
+    /// Lines of the trimmed input, without line terminators.
+    pub fn lines(&self) -> std::str::Lines<'_> {
+        self.trimmed().lines()
+    }
+
+    pub fn blocks(&self) -> Blocks<'_> {
+        blocks(&self.contents)
+    }
+}
+
+/// Splits `s` into blocks separated by one or more blank lines. Blank lines may contain
+/// whitespace, and both `\n` and `\r\n` line endings are accepted. Lines inside a block keep
+/// their leading whitespace, only the block's final line terminator is dropped.
+pub fn blocks(s: &str) -> Blocks<'_> {
+    Blocks { rest: s }
+}
+
+pub struct Blocks<'a> {
+    pub(crate) rest: &'a str,
+}
+
+impl<'a> Iterator for Blocks<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        // skip leading blank lines
+        loop {
+            if self.rest.is_empty() {
+                return None;
+            }
+            let line_end = self.rest.find('\n').map_or(self.rest.len(), |i| i + 1);
+            if !self.rest[..line_end].trim().is_empty() {
+                break;
+            }
+            self.rest = &self.rest[line_end..];
+        }
+
+        let mut end = 0;
+        while end < self.rest.len() {
+            let line_end = self.rest[end..]
+                .find('\n')
+                .map_or(self.rest.len(), |i| end + i + 1);
+            if self.rest[end..line_end].trim().is_empty() {
+                break;
+            }
+            end = line_end;
+        }
+
+        let block = self.rest[..end].trim_end_matches(['\n', '\r']);
+        self.rest = &self.rest[end..];
+        Some(block)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn blocks_are_separated_by_blank_lines() {
+        let s = "\na b\nc\n\n\n  \nd\r\n\r\ne\n f\n";
+        let result: Vec<_> = blocks(s).collect();
+        assert_eq!(result, vec!["a b\nc", "d", "e\n f"]);
+        assert_eq!(blocks("").count(), 0);
+        assert_eq!(blocks("\n \n").count(), 0);
+    }
+
+    #[test]
+    fn views() {
+        let input = Input::from_string("  | \n  +-A\n\n");
+        assert_eq!(input.untrimmed(), "  | \n  +-A\n\n");
+        assert_eq!(input.trimmed(), "| \n  +-A");
+        assert_eq!(input.lines().collect::<Vec<_>>(), vec!["| ", "  +-A"]);
+        assert_eq!(input.blocks().collect::<Vec<_>>(), vec!["  | \n  +-A"]);
+    }
+
+    #[test]
+    fn missing_file() {
+        let e = Input::from_path("does/not/exist.txt").unwrap_err();
+        assert!(matches!(e, InputError::Io { path: Some(_), .. }));
+    }
+}
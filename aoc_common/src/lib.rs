@@ -0,0 +1,99 @@
+mod input;
+mod parse;
+mod picture;
+
+pub use input::{blocks, Blocks, Input, InputError};
+pub use parse::{ParseError, Span, Tokens};
+pub use picture::{Picture, PictureExport};
+
+use std::fmt;
+use std::io;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Answer {
+    Signed(i64),
+    Unsigned(u64),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Signed(v) => write!(f, "{}", v),
+            Answer::Unsigned(v) => write!(f, "{}", v),
+            Answer::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+macro_rules! impl_from_signed {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(v: $t) -> Self {
+                    Answer::Signed(v as i64)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_from_unsigned {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(v: $t) -> Self {
+                    Answer::Unsigned(v as u64)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_signed!(i32, i64);
+impl_from_unsigned!(u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_owned())
+    }
+}
+
+/// A single day of Advent of Code. Every day crate exposes a `Solution` implementing this
+/// trait, which is what the `aoc` runner dispatches to. Parts fail with a `ParseError` if the
+/// input is malformed.
+pub trait Puzzle {
+    fn year(&self) -> u16;
+
+    fn day(&self) -> u8;
+
+    /// Runs only the parsing of the input, so that benchmarks can time it apart from the parts.
+    /// Days whose parts do not share a separate parsing step keep the default and return
+    /// `Ok(false)`.
+    fn parse(&self, _input: &Input) -> Result<bool, ParseError> {
+        Ok(false)
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer, ParseError>;
+
+    /// Days without a second part (usually day 25) keep the default and override `has_part_two`.
+    fn part_two(&self, _input: &Input) -> Option<Result<Answer, ParseError>> {
+        None
+    }
+
+    fn has_part_two(&self) -> bool {
+        true
+    }
+
+    /// Draws the state of the puzzle as a picture, and the steps leading to it as frames if the
+    /// export asks for them. Days with nothing to draw keep the default and return `Ok(false)`.
+    fn export_image(&self, _input: &Input, _export: &mut PictureExport) -> io::Result<bool> {
+        Ok(false)
+    }
+}
@@ -0,0 +1,329 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// Invalid puzzle input: what was expected at a position of the input, and what was found
+/// there instead.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line of the offending token.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    pub token: String,
+    pub expected: String,
+    line_text: String,
+}
+
+impl ParseError {
+    /// The offending line with the token underlined.
+    pub fn excerpt(&self) -> String {
+        let number = self.line.to_string();
+        let token_width = self
+            .token
+            .lines()
+            .next()
+            .map_or(0, |first| first.chars().count());
+        format!(
+            "{} | {}\n{} | {}{}",
+            number,
+            self.line_text,
+            " ".repeat(number.len()),
+            " ".repeat(self.column - 1),
+            "^".repeat(token_width.max(1))
+        )
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}, found ",
+            self.line, self.column, self.expected
+        )?;
+        if self.token.is_empty() {
+            write!(f, "nothing")
+        } else {
+            write!(f, "`{}`", self.token)
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// A piece of the input that remembers where it is located, so that errors can point at it.
+/// All methods that return parts of a span return spans again.
+#[derive(Clone, Copy, Debug)]
+pub struct Span<'a> {
+    source: &'a str,
+    start: usize,
+    text: &'a str,
+}
+
+impl<'a> Span<'a> {
+    /// A span covering all of `source`, which starts at line 1, column 1.
+    pub fn new(source: &'a str) -> Self {
+        Self {
+            source,
+            start: 0,
+            text: source,
+        }
+    }
+
+    pub fn as_str(&self) -> &'a str {
+        self.text
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    /// `sub` has to be a slice of this span's text.
+    fn sub(&self, sub: &'a str) -> Self {
+        let offset = sub.as_ptr() as usize - self.text.as_ptr() as usize;
+        assert!(offset + sub.len() <= self.text.len());
+        Self {
+            source: self.source,
+            start: self.start + offset,
+            text: sub,
+        }
+    }
+
+    /// The span of the bytes `start..end` of this span.
+    pub fn slice(&self, start: usize, end: usize) -> Self {
+        self.sub(&self.text[start..end])
+    }
+
+    /// An empty span right after the end of this one.
+    pub fn end(&self) -> Self {
+        self.sub(&self.text[self.text.len()..])
+    }
+
+    pub fn error<S: Into<String>>(&self, expected: S) -> ParseError {
+        let before = &self.source[..self.start];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let line_end = self.source[self.start..]
+            .find('\n')
+            .map_or(self.source.len(), |i| self.start + i);
+        ParseError {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            // Errors about whole blocks only show their first line
+            token: self.text.lines().next().unwrap_or("").to_owned(),
+            expected: expected.into(),
+            line_text: self.source[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_owned(),
+        }
+    }
+
+    /// Parses the whole span, e.g. as a number.
+    pub fn parse<T: FromStr>(&self, expected: &str) -> Result<T, ParseError> {
+        self.text.parse().map_err(|_| self.error(expected))
+    }
+
+    pub fn trim(&self) -> Self {
+        self.sub(self.text.trim())
+    }
+
+    /// Lines without their `\n` or `\r\n` terminators, like `str::lines`.
+    pub fn lines(&self) -> Tokens<'a, 'static> {
+        self.tokens(Separator::Lines)
+    }
+
+    /// Blocks separated by blank lines, see [`crate::blocks`].
+    pub fn blocks(&self) -> Tokens<'a, 'static> {
+        self.tokens(Separator::Blocks)
+    }
+
+    pub fn chars(&self) -> impl Iterator<Item = (Span<'a>, char)> + 'a {
+        let span = *self;
+        self.text
+            .char_indices()
+            .map(move |(i, c)| (span.slice(i, i + c.len_utf8()), c))
+    }
+
+    pub fn split<'b>(&self, separator: &'b str) -> Tokens<'a, 'b> {
+        self.tokens(Separator::Str(separator))
+    }
+
+    pub fn split_whitespace(&self) -> Tokens<'a, 'static> {
+        self.tokens(Separator::Whitespace)
+    }
+
+    fn tokens<'b>(&self, separator: Separator<'b>) -> Tokens<'a, 'b> {
+        Tokens {
+            span: *self,
+            rest: Some(self.text),
+            separator,
+        }
+    }
+
+    /// Splits at the first occurrence of `separator`.
+    pub fn split_once(&self, separator: &str) -> Result<(Self, Self), ParseError> {
+        match self.text.find(separator) {
+            Some(i) => Ok((
+                self.slice(0, i),
+                self.slice(i + separator.len(), self.text.len()),
+            )),
+            None => Err(self.error(format!("`{}`", separator))),
+        }
+    }
+
+    pub fn strip_prefix(&self, prefix: &str) -> Result<Self, ParseError> {
+        match self.text.strip_prefix(prefix) {
+            Some(rest) => Ok(self.sub(rest)),
+            None => Err(self.error(format!("`{}`", prefix))),
+        }
+    }
+
+    pub fn strip_suffix(&self, suffix: &str) -> Result<Self, ParseError> {
+        match self.text.strip_suffix(suffix) {
+            Some(rest) => Ok(self.sub(rest)),
+            None => Err(self.error(format!("`{}`", suffix))),
+        }
+    }
+}
+
+// What `Tokens` splits at
+#[derive(Clone, Copy)]
+enum Separator<'b> {
+    Str(&'b str),
+    // Skips empty parts, like `str::split_whitespace`
+    Whitespace,
+    Lines,
+    Blocks,
+}
+
+/// Parts of a span split at a separator, at whitespace, into lines or into blocks. Besides
+/// iterating, a fixed number of parts can be taken with [`Tokens::expect`] and [`Tokens::end`].
+pub struct Tokens<'a, 'b> {
+    span: Span<'a>,
+    rest: Option<&'a str>,
+    separator: Separator<'b>,
+}
+
+impl<'a, 'b> Tokens<'a, 'b> {
+    /// The next part, or an error describing what was expected instead of the end of the span.
+    pub fn expect(&mut self, expected: &str) -> Result<Span<'a>, ParseError> {
+        self.next().ok_or_else(|| self.span.end().error(expected))
+    }
+
+    /// Checks that there are no parts left.
+    pub fn end(&mut self) -> Result<(), ParseError> {
+        match self.next() {
+            None => Ok(()),
+            Some(token) => Err(token.error("nothing more")),
+        }
+    }
+}
+
+impl<'a, 'b> Iterator for Tokens<'a, 'b> {
+    type Item = Span<'a>;
+
+    fn next(&mut self) -> Option<Span<'a>> {
+        let rest = self.rest?;
+        match self.separator {
+            Separator::Str(separator) => match rest.find(separator) {
+                Some(i) => {
+                    self.rest = Some(&rest[i + separator.len()..]);
+                    Some(self.span.sub(&rest[..i]))
+                }
+                None => {
+                    self.rest = None;
+                    Some(self.span.sub(rest))
+                }
+            },
+            Separator::Lines => {
+                if rest.is_empty() {
+                    self.rest = None;
+                    return None;
+                }
+                let (line, next) = match rest.find('\n') {
+                    Some(i) => (&rest[..i], &rest[i + 1..]),
+                    None => (rest, ""),
+                };
+                self.rest = Some(next);
+                Some(self.span.sub(line.strip_suffix('\r').unwrap_or(line)))
+            }
+            Separator::Blocks => {
+                let mut blocks = crate::blocks(rest);
+                let block = blocks.next();
+                self.rest = Some(blocks.rest);
+                block.map(|block| self.span.sub(block))
+            }
+            Separator::Whitespace => {
+                let rest = rest.trim_start();
+                if rest.is_empty() {
+                    self.rest = None;
+                    return None;
+                }
+                let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+                self.rest = Some(&rest[end..]);
+                Some(self.span.sub(&rest[..end]))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn positions() {
+        let input = Span::new("1 2\nfoo: 3,x\n");
+        let lines: Vec<_> = input.lines().collect();
+        assert_eq!(lines.len(), 2);
+
+        let (name, values) = lines[1].split_once(": ").unwrap();
+        assert_eq!(name.as_str(), "foo");
+        let values: Vec<_> = values.split(",").collect();
+        assert_eq!(values[0].parse::<u32>("a number"), Ok(3));
+
+        let e = values[1].parse::<u32>("a number").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 8));
+        assert_eq!(
+            e.to_string(),
+            "line 2, column 8: expected a number, found `x`"
+        );
+        assert_eq!(e.excerpt(), "2 | foo: 3,x\n  |        ^");
+
+        let mut tokens = lines[0].split_whitespace();
+        assert_eq!(tokens.expect("a").unwrap().as_str(), "1");
+        assert_eq!(tokens.expect("b").unwrap().as_str(), "2");
+        let e = tokens.expect("a third number").unwrap_err();
+        assert_eq!((e.line, e.column), (1, 4));
+        assert!(e.to_string().ends_with("found nothing"));
+
+        let e = lines[0].split_whitespace().end().unwrap_err();
+        assert_eq!(e.token, "1");
+        assert!(lines[1].strip_prefix("bar").is_err());
+        assert_eq!(lines[1].strip_suffix(",x").unwrap().as_str(), "foo: 3");
+    }
+
+    #[test]
+    fn lines_and_blocks() {
+        let input = Span::new("a\r\n\nb\n \r\nc\nd\n");
+        let lines: Vec<_> = input.lines().map(|s| s.as_str()).collect();
+        assert_eq!(lines, vec!["a", "", "b", " ", "c", "d"]);
+        let mut blocks = input.blocks();
+        assert_eq!(blocks.expect("a").unwrap().as_str(), "a");
+        assert_eq!(blocks.expect("b").unwrap().as_str(), "b");
+        let last = blocks.expect("c and d").unwrap();
+        assert_eq!(last.lines().nth(1).unwrap().error("").line, 6);
+        let e = blocks.expect("a fourth block").unwrap_err();
+        assert!(e.to_string().ends_with("found nothing"));
+    }
+
+    #[test]
+    fn split_keeps_empty_parts() {
+        let parts: Vec<_> = Span::new(",a,,b,").split(",").map(|s| s.as_str()).collect();
+        assert_eq!(parts, vec!["", "a", "", "b", ""]);
+        let parts: Vec<_> = Span::new("  a  b ")
+            .split_whitespace()
+            .map(|s| s.as_str())
+            .collect();
+        assert_eq!(parts, vec!["a", "b"]);
+    }
+}
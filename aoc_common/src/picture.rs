@@ -0,0 +1,273 @@
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+enum Pixels {
+    Bitmap(Vec<bool>),
+    Gray(Vec<u8>),
+    Color(Vec<[u8; 3]>),
+}
+
+/// A raster image that can be written in one of the binary Netpbm formats: PBM for bitmaps,
+/// PGM for gray scale and PPM for color pictures.
+pub struct Picture {
+    width: usize,
+    height: usize,
+    pixels: Pixels,
+}
+
+fn pixels<T, F: FnMut(usize, usize) -> T>(width: usize, height: usize, mut f: F) -> Vec<T> {
+    (0..width * height)
+        .map(|index| f(index / width, index % width))
+        .collect()
+}
+
+impl Picture {
+    /// A black and white picture. `f` is called with `(row, col)` and returns `true` for black.
+    pub fn bitmap<F: FnMut(usize, usize) -> bool>(width: usize, height: usize, f: F) -> Self {
+        Self {
+            width,
+            height,
+            pixels: Pixels::Bitmap(pixels(width, height, f)),
+        }
+    }
+
+    /// A gray scale picture from 0 (black) to 255 (white).
+    pub fn gray<F: FnMut(usize, usize) -> u8>(width: usize, height: usize, f: F) -> Self {
+        Self {
+            width,
+            height,
+            pixels: Pixels::Gray(pixels(width, height, f)),
+        }
+    }
+
+    /// A color picture with one `[red, green, blue]` triple per pixel.
+    pub fn color<F: FnMut(usize, usize) -> [u8; 3]>(width: usize, height: usize, f: F) -> Self {
+        Self {
+            width,
+            height,
+            pixels: Pixels::Color(pixels(width, height, f)),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The usual file extension of the format the picture is written in.
+    pub fn extension(&self) -> &'static str {
+        match self.pixels {
+            Pixels::Bitmap(_) => "pbm",
+            Pixels::Gray(_) => "pgm",
+            Pixels::Color(_) => "ppm",
+        }
+    }
+
+    pub fn write<W: Write>(&self, mut w: W) -> io::Result<()> {
+        match &self.pixels {
+            Pixels::Bitmap(pixels) => {
+                writeln!(w, "P4\n{} {}", self.width, self.height)?;
+                for row in pixels.chunks(self.width.max(1)) {
+                    // Every row is padded to whole bytes, the first pixel is the highest bit.
+                    let bytes: Vec<u8> = row
+                        .chunks(8)
+                        .map(|bits| {
+                            bits.iter()
+                                .enumerate()
+                                .fold(0, |byte, (i, &b)| byte | (u8::from(b) << (7 - i)))
+                        })
+                        .collect();
+                    w.write_all(&bytes)?;
+                }
+            }
+            Pixels::Gray(pixels) => {
+                writeln!(w, "P5\n{} {}\n255", self.width, self.height)?;
+                w.write_all(pixels)?;
+            }
+            Pixels::Color(pixels) => {
+                writeln!(w, "P6\n{} {}\n255", self.width, self.height)?;
+                for pixel in pixels {
+                    w.write_all(pixel)?;
+                }
+            }
+        }
+        w.flush()
+    }
+
+    // The gray value of every pixel, from 0 (black) to 255 (white)
+    fn gray_values(&self) -> Vec<u8> {
+        match &self.pixels {
+            Pixels::Bitmap(pixels) => pixels
+                .iter()
+                .map(|&black| if black { 0 } else { 255 })
+                .collect(),
+            Pixels::Gray(pixels) => pixels.clone(),
+            Pixels::Color(pixels) => pixels
+                .iter()
+                .map(|&[r, g, b]| {
+                    ((299 * u32::from(r) + 587 * u32::from(g) + 114 * u32::from(b)) / 1000) as u8
+                })
+                .collect(),
+        }
+    }
+
+    /// The picture in the format of `extension`, which is one of `extension()`'s values.
+    /// Converting to a bitmap makes the darker half of the gray values black.
+    fn converted(&self, extension: &str) -> Option<Self> {
+        let gray = self.gray_values();
+        let pixels = match extension {
+            "pbm" => Pixels::Bitmap(gray.iter().map(|&v| v < 128).collect()),
+            "pgm" => Pixels::Gray(gray),
+            "ppm" => Pixels::Color(gray.iter().map(|&v| [v; 3]).collect()),
+            _ => return None,
+        };
+        Some(Self {
+            width: self.width,
+            height: self.height,
+            pixels,
+        })
+    }
+
+    /// Writes the picture to `path` in the format its extension names, converting it if that is
+    /// not the picture's own format. Other extensions are an `InvalidInput` error.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let with_path =
+            |e: io::Error| io::Error::new(e.kind(), format!("{}: {}", path.display(), e));
+        let extension = path
+            .extension()
+            .map(|extension| extension.to_string_lossy().to_ascii_lowercase())
+            .unwrap_or_default();
+        let converted = if extension == self.extension() {
+            None
+        } else {
+            let converted = self.converted(&extension).ok_or_else(|| {
+                with_path(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "unknown picture format, expected the extension pbm, pgm or ppm",
+                ))
+            })?;
+            Some(converted)
+        };
+        let file = File::create(path).map_err(with_path)?;
+        converted
+            .as_ref()
+            .unwrap_or(self)
+            .write(BufWriter::new(file))
+            .map_err(with_path)
+    }
+}
+
+/// Where a day writes its pictures to. The final picture goes to `path`; if frames were
+/// requested, intermediate states are written next to it with a frame number appended to the
+/// file stem, e.g. `hull-0042.pbm` for `hull.pbm`.
+pub struct PictureExport {
+    path: PathBuf,
+    frames: bool,
+    frame_count: usize,
+}
+
+impl PictureExport {
+    pub fn new(path: PathBuf, frames: bool) -> Self {
+        Self {
+            path,
+            frames,
+            frame_count: 0,
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn wants_frames(&self) -> bool {
+        self.frames
+    }
+
+    pub fn frame_count(&self) -> usize {
+        self.frame_count
+    }
+
+    /// Writes the next frame. `picture` is only called if frames were requested.
+    pub fn frame<F: FnOnce() -> Picture>(&mut self, picture: F) -> io::Result<()> {
+        if !self.frames {
+            return Ok(());
+        }
+        let stem = self.path.file_stem().unwrap_or_default().to_string_lossy();
+        let mut name = format!("{}-{:04}", stem, self.frame_count);
+        if let Some(extension) = self.path.extension() {
+            name.push('.');
+            name.push_str(&extension.to_string_lossy());
+        }
+        picture().save(&self.path.with_file_name(name))?;
+        self.frame_count += 1;
+        Ok(())
+    }
+
+    pub fn finish(&mut self, picture: &Picture) -> io::Result<()> {
+        picture.save(&self.path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn netpbm() {
+        let mut pbm = Vec::new();
+        let picture = Picture::bitmap(9, 2, |row, col| (row + col) % 2 == 0);
+        picture.write(&mut pbm).unwrap();
+        assert_eq!(pbm, b"P4\n9 2\n\xaa\x80\x55\x00");
+        assert_eq!(picture.extension(), "pbm");
+
+        let mut pgm = Vec::new();
+        Picture::gray(2, 1, |_, col| col as u8 * 255)
+            .write(&mut pgm)
+            .unwrap();
+        assert_eq!(pgm, b"P5\n2 1\n255\n\x00\xff");
+
+        let mut ppm = Vec::new();
+        Picture::color(1, 2, |row, _| [row as u8, 2, 3])
+            .write(&mut ppm)
+            .unwrap();
+        assert_eq!(ppm, b"P6\n1 2\n255\n\x00\x02\x03\x01\x02\x03");
+    }
+
+    #[test]
+    fn conversions() {
+        let converted = |picture: &Picture, extension| {
+            let mut result = Vec::new();
+            picture
+                .converted(extension)
+                .unwrap()
+                .write(&mut result)
+                .unwrap();
+            result
+        };
+        let bitmap = Picture::bitmap(2, 1, |_, col| col == 0);
+        assert_eq!(converted(&bitmap, "pgm"), b"P5\n2 1\n255\n\x00\xff");
+        assert_eq!(
+            converted(&bitmap, "ppm"),
+            b"P6\n2 1\n255\n\x00\x00\x00\xff\xff\xff"
+        );
+        let color = Picture::color(2, 1, |_, col| [col as u8 * 200, 0, 0]);
+        assert_eq!(converted(&color, "pgm"), b"P5\n2 1\n255\n\x00\x3b");
+        assert_eq!(converted(&color, "pbm"), b"P4\n2 1\n\xc0");
+        assert!(color.converted("png").is_none());
+    }
+
+    #[test]
+    fn unknown_extension() {
+        let path = std::env::temp_dir().join("aoc_common_unknown_extension.png");
+        let error = Picture::bitmap(1, 1, |_, _| true).save(&path).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+        assert!(error
+            .to_string()
+            .ends_with("expected the extension pbm, pgm or ppm"));
+        assert!(!path.exists());
+    }
+}
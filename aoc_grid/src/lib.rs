@@ -0,0 +1,393 @@
+pub mod ocr;
+
+use aoc_common::{ParseError, Span};
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// Offsets of the 4-neighborhood, in the order right, up, left, down.
+const OFFSETS_4: [(isize, isize); 4] = [(0, 1), (-1, 0), (0, -1), (1, 0)];
+
+/// Offsets of the 8-neighborhood, counter-clockwise starting to the right.
+const OFFSETS_8: [(isize, isize); 8] = [
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// A rectangular grid stored in row-major order. Positions are `(row, col)` with `(0, 0)` in the
+/// top left corner.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    data: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, data: Vec<T>) -> Self {
+        assert_eq!(width * height, data.len());
+        Self {
+            width,
+            height,
+            data,
+        }
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::new(width, height, vec![value; width * height])
+    }
+
+    pub fn from_fn<F: FnMut(usize, usize) -> T>(width: usize, height: usize, mut f: F) -> Self {
+        let data = (0..width * height)
+            .map(|index| f(index / width, index % width))
+            .collect();
+        Self::new(width, height, data)
+    }
+
+    /// Parses a character map with one row per line. `f` converts every character into a
+    /// cell, returning `None` for characters that are not allowed; `expected` describes the
+    /// allowed characters for the error message.
+    pub fn parse<F: FnMut(char) -> Option<T>>(
+        input: &str,
+        expected: &str,
+        mut f: F,
+    ) -> Result<Self, ParseError> {
+        let input = Span::new(input);
+        let mut data = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+
+        for line in input.lines() {
+            let mut found = 0;
+            for (span, c) in line.chars() {
+                data.push(f(c).ok_or_else(|| span.error(expected))?);
+                found += 1;
+            }
+            let expected = *width.get_or_insert(found);
+            if found != expected {
+                return Err(line.error(format!("a row of {} cells", expected)));
+            }
+            height += 1;
+        }
+
+        match width {
+            Some(width) if width > 0 => Ok(Self::new(width, height, data)),
+            _ => Err(input.error("at least one row")),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, row: usize, col: usize) -> bool {
+        row < self.height && col < self.width
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        if self.contains(row, col) {
+            Some(&self.data[row * self.width + col])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        if self.contains(row, col) {
+            Some(&mut self.data[row * self.width + col])
+        } else {
+            None
+        }
+    }
+
+    /// Moves `(row, col)` by the given offset. Returns `None` if that leaves the grid.
+    pub fn offset(
+        &self,
+        row: usize,
+        col: usize,
+        d_row: isize,
+        d_col: isize,
+    ) -> Option<(usize, usize)> {
+        let row = row.checked_add_signed(d_row)?;
+        let col = col.checked_add_signed(d_col)?;
+        if self.contains(row, col) {
+            Some((row, col))
+        } else {
+            None
+        }
+    }
+
+    /// Moves `(row, col)` by the given offset, treating the grid as a torus.
+    pub fn offset_wrapping(
+        &self,
+        row: usize,
+        col: usize,
+        d_row: isize,
+        d_col: isize,
+    ) -> (usize, usize) {
+        let wrap =
+            |v: usize, d: isize, n: usize| ((v as isize + d).rem_euclid(n as isize)) as usize;
+        (wrap(row, d_row, self.height), wrap(col, d_col, self.width))
+    }
+
+    /// Accesses the grid as if it was repeated infinitely in every direction.
+    pub fn get_wrapping(&self, row: usize, col: usize) -> &T {
+        &self[(row % self.height, col % self.width)]
+    }
+
+    pub fn get_wrapping_mut(&mut self, row: usize, col: usize) -> &mut T {
+        let position = (row % self.height, col % self.width);
+        &mut self[position]
+    }
+
+    pub fn neighbors4(&self, row: usize, col: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        OFFSETS_4
+            .iter()
+            .filter_map(move |&(d_row, d_col)| self.offset(row, col, d_row, d_col))
+    }
+
+    pub fn neighbors8(&self, row: usize, col: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        OFFSETS_8
+            .iter()
+            .filter_map(move |&(d_row, d_col)| self.offset(row, col, d_row, d_col))
+    }
+
+    pub fn neighbors4_wrapping(
+        &self,
+        row: usize,
+        col: usize,
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        OFFSETS_4
+            .iter()
+            .map(move |&(d_row, d_col)| self.offset_wrapping(row, col, d_row, d_col))
+    }
+
+    pub fn neighbors8_wrapping(
+        &self,
+        row: usize,
+        col: usize,
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        OFFSETS_8
+            .iter()
+            .map(move |&(d_row, d_col)| self.offset_wrapping(row, col, d_row, d_col))
+    }
+
+    /// All positions in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.width * self.height).map(move |index| (index / width, index % width))
+    }
+
+    /// All cells in row-major order.
+    pub fn values(&self) -> std::slice::Iter<'_, T> {
+        self.data.iter()
+    }
+
+    pub fn values_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.data.iter_mut()
+    }
+
+    /// All cells together with their position, in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.data.iter())
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        assert!(row < self.height);
+        &self.data[row * self.width..(row + 1) * self.width]
+    }
+
+    /// All `height` rows, which are empty if the grid has no columns.
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        (0..self.height).map(move |row| self.row(row))
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(col < self.width);
+        self.data.iter().skip(col).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |col| self.column(col))
+    }
+
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid::new(self.width, self.height, self.data.iter().map(f).collect())
+    }
+
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_fn(self.height, self.width, |row, col| self[(col, row)].clone())
+    }
+
+    pub fn rotate_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_fn(self.height, self.width, |row, col| {
+            self[(self.height - 1 - col, row)].clone()
+        })
+    }
+
+    pub fn rotate_counterclockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_fn(self.height, self.width, |row, col| {
+            self[(col, self.width - 1 - row)].clone()
+        })
+    }
+
+    /// Mirrors the grid at its vertical axis, i.e. reverses every row.
+    pub fn flip_horizontal(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_fn(self.width, self.height, |row, col| {
+            self[(row, self.width - 1 - col)].clone()
+        })
+    }
+
+    /// Mirrors the grid at its horizontal axis, i.e. reverses the order of the rows.
+    pub fn flip_vertical(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_fn(self.width, self.height, |row, col| {
+            self[(self.height - 1 - row, col)].clone()
+        })
+    }
+
+    /// Renders the grid with one character per cell and a newline after every row.
+    pub fn render<F: FnMut(&T) -> char>(&self, mut f: F) -> String {
+        let mut result = String::with_capacity((self.width + 1) * self.height);
+        for row in self.rows() {
+            result.extend(row.iter().map(&mut f));
+            result.push('\n');
+        }
+        result
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        assert!(row < self.height);
+        assert!(col < self.width);
+        &self.data[row * self.width + col]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        assert!(row < self.height);
+        assert!(col < self.width);
+        &mut self.data[row * self.width + col]
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(input: &str) -> Grid<u32> {
+        Grid::parse(input, "a digit", |c| c.to_digit(10)).unwrap()
+    }
+
+    #[test]
+    fn parse() {
+        let grid = digits("123\n456");
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(1, 0)], 4);
+        assert_eq!(grid.get(2, 0), None);
+        assert_eq!(grid.to_string(), "123\n456\n");
+
+        let e = Grid::parse("12\n3", "a digit", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!((e.line, e.column), (2, 1));
+        assert_eq!(e.expected, "a row of 2 cells");
+        let e = Grid::parse("12\n3x", "a digit", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!((e.line, e.column, e.token.as_str()), (2, 2, "x"));
+        assert!(Grid::parse("", "a digit", |c| c.to_digit(10)).is_err());
+    }
+
+    #[test]
+    fn neighbors() {
+        let grid = digits("123\n456\n789");
+        let n4: Vec<_> = grid.neighbors4(0, 0).collect();
+        assert_eq!(n4, vec![(0, 1), (1, 0)]);
+        assert_eq!(grid.neighbors4(1, 1).count(), 4);
+        assert_eq!(grid.neighbors8(1, 1).count(), 8);
+        assert_eq!(grid.neighbors8(2, 2).count(), 3);
+
+        let n4: Vec<_> = grid.neighbors4_wrapping(0, 0).collect();
+        assert_eq!(n4, vec![(0, 1), (2, 0), (0, 2), (1, 0)]);
+        assert_eq!(grid.neighbors8_wrapping(0, 0).count(), 8);
+        assert_eq!(*grid.get_wrapping(4, 7), 5);
+        assert_eq!(grid.offset_wrapping(0, 2, 0, 1), (0, 0));
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = digits("123\n456");
+        assert_eq!(grid.row(1), &[4, 5, 6]);
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), vec![3, 6]);
+        assert_eq!(grid.columns().count(), 3);
+        assert_eq!(grid.iter().nth(4), Some(((1, 1), &5)));
+    }
+
+    #[test]
+    fn without_columns() {
+        let grid: Grid<u32> = Grid::new(0, 2, Vec::new());
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(grid.columns().count(), 0);
+        assert_eq!(grid.to_string(), "\n\n");
+        assert_eq!(grid.render(|_| '#'), "\n\n");
+        assert_eq!(grid.transpose(), Grid::new(2, 0, Vec::new()));
+        assert_eq!(
+            ocr::recognize(&grid.map(|_| true)),
+            Err(ocr::OcrError::Empty)
+        );
+    }
+
+    #[test]
+    fn transformations() {
+        let grid = digits("123\n456");
+        assert_eq!(grid.rotate_clockwise().to_string(), "41\n52\n63\n");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), "36\n25\n14\n");
+        assert_eq!(grid.flip_horizontal().to_string(), "321\n654\n");
+        assert_eq!(grid.flip_vertical().to_string(), "456\n123\n");
+        assert_eq!(grid.transpose().to_string(), "14\n25\n36\n");
+        assert_eq!(
+            grid.render(|&d| if d % 2 == 0 { '#' } else { '.' }),
+            ".#.\n#.#\n"
+        );
+    }
+}
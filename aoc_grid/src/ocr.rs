@@ -0,0 +1,224 @@
+//! Recognition of the block letters that some puzzles draw as their answer.
+//!
+//! The letters are 6 pixels high and usually 4 pixels wide, `Y` is 5 pixels wide. Letters are
+//! separated by blank columns, except that a 5 pixel wide letter may touch its right neighbor.
+
+use crate::Grid;
+use aoc_common::{ParseError, Span};
+use std::fmt;
+
+const HEIGHT: usize = 6;
+
+const FONT: [(char, [&str; HEIGHT]); 18] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', ["###", ".#.", ".#.", ".#.", ".#.", "###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Y', ["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// A group of pixels that does not match any letter of the font.
+#[derive(Debug, PartialEq, Eq)]
+pub struct UnknownGlyph {
+    /// Column of the first pixel of the glyph in the cropped image.
+    pub column: usize,
+    /// The glyph with `#` for lit and `.` for dark pixels, one line per row.
+    pub pixels: String,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum OcrError {
+    Empty,
+    Height(usize),
+    UnknownGlyphs(Vec<UnknownGlyph>),
+}
+
+impl fmt::Display for OcrError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            OcrError::Empty => write!(f, "no lit pixels to recognize"),
+            OcrError::Height(height) => {
+                write!(f, "letters are {} pixels high, expected {}", height, HEIGHT)
+            }
+            OcrError::UnknownGlyphs(glyphs) => {
+                write!(f, "{} unknown glyph(s)", glyphs.len())?;
+                for glyph in glyphs {
+                    write!(f, "\nat column {}:\n{}", glyph.column, glyph.pixels)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for OcrError {}
+
+/// Reads the letters drawn by the lit pixels of `image`. Blank rows and columns around the
+/// letters are ignored.
+pub fn recognize(image: &Grid<bool>) -> Result<String, OcrError> {
+    let lit_rows: Vec<usize> = (0..image.height())
+        .filter(|&row| image.row(row).iter().any(|&p| p))
+        .collect();
+    let (top, bottom) = match (lit_rows.first(), lit_rows.last()) {
+        (Some(&top), Some(&bottom)) => (top, bottom),
+        _ => return Err(OcrError::Empty),
+    };
+    if bottom - top + 1 != HEIGHT {
+        return Err(OcrError::Height(bottom - top + 1));
+    }
+
+    let column = |col: usize| -> [bool; HEIGHT] {
+        let mut pixels = [false; HEIGHT];
+        for (row, pixel) in pixels.iter_mut().enumerate() {
+            *pixel = image[(top + row, col)];
+        }
+        pixels
+    };
+    let columns: Vec<[bool; HEIGHT]> = (0..image.width()).map(column).collect();
+    let first = columns.iter().position(|c| c.contains(&true)).unwrap();
+    let columns = &columns[first..];
+
+    let mut result = String::new();
+    let mut unknown = Vec::new();
+    let mut col = 0;
+    while col < columns.len() {
+        if !columns[col].contains(&true) {
+            col += 1;
+            continue;
+        }
+        match match_letter(&columns[col..]) {
+            Some((letter, width)) => {
+                result.push(letter);
+                col += width;
+            }
+            None => {
+                let width = columns[col..]
+                    .iter()
+                    .position(|c| !c.contains(&true))
+                    .unwrap_or(columns.len() - col);
+                unknown.push(UnknownGlyph {
+                    column: col,
+                    pixels: render(&columns[col..col + width]),
+                });
+                col += width;
+            }
+        }
+    }
+
+    if unknown.is_empty() {
+        Ok(result)
+    } else {
+        Err(OcrError::UnknownGlyphs(unknown))
+    }
+}
+
+/// Like `recognize`, for puzzles whose answer is the image: letters that cannot be recognized
+/// are an error at `at`, usually the end of the input, that draws the unknown glyphs.
+pub fn recognize_at(image: &Grid<bool>, at: Span) -> Result<String, ParseError> {
+    recognize(image).map_err(|e| {
+        at.error(format!(
+            "an image of known letters ({})",
+            e.to_string().trim_end()
+        ))
+    })
+}
+
+/// Finds the letter at the start of `columns`. A letter only matches if it is followed by a
+/// blank column, the end of the image, or if it is 5 pixels wide.
+fn match_letter(columns: &[[bool; HEIGHT]]) -> Option<(char, usize)> {
+    FONT.iter().find_map(|(letter, rows)| {
+        let width = rows[0].len();
+        if columns.len() < width {
+            return None;
+        }
+        let matches = (0..width).all(|col| {
+            (0..HEIGHT).all(|row| (rows[row].as_bytes()[col] == b'#') == columns[col][row])
+        });
+        let separated = width == 5 || columns.get(width).is_none_or(|next| !next.contains(&true));
+        if matches && separated {
+            Some((*letter, width))
+        } else {
+            None
+        }
+    })
+}
+
+fn render(columns: &[[bool; HEIGHT]]) -> String {
+    let mut result = String::new();
+    for row in 0..HEIGHT {
+        result.extend(columns.iter().map(|c| if c[row] { '#' } else { '.' }));
+        result.push('\n');
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn image(rows: &[&str]) -> Grid<bool> {
+        Grid::parse(&rows.join("\n"), "a pixel", |c| Some(c == '#')).unwrap()
+    }
+
+    #[test]
+    fn letters() {
+        let hello = image(&[
+            "..........................",
+            ".#..#.####.#....#.....##..",
+            ".#..#.#....#....#....#..#.",
+            ".####.###..#....#....#..#.",
+            ".#..#.#....#....#....#..#.",
+            ".#..#.#....#....#....#..#.",
+            ".#..#.####.####.####..##..",
+        ]);
+        assert_eq!(recognize(&hello), Ok("HELLO".to_owned()));
+
+        let touching = image(&[
+            "#...#####.###",
+            "#...#...#..#.",
+            ".#.#...#...#.",
+            "..#...#....#.",
+            "..#..#.....#.",
+            "..#..####.###",
+        ]);
+        assert_eq!(recognize(&touching), Ok("YZI".to_owned()));
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(recognize(&image(&["...."])), Err(OcrError::Empty));
+        assert_eq!(recognize(&image(&["#", "#"])), Err(OcrError::Height(2)));
+
+        let unknown = image(&[
+            "####.##.", "#....#..", "###..#..", "#....#..", "#....#..", "####.##.",
+        ]);
+        assert_eq!(
+            recognize(&unknown),
+            Err(OcrError::UnknownGlyphs(vec![UnknownGlyph {
+                column: 5,
+                pixels: "##\n#.\n#.\n#.\n#.\n##\n".to_owned(),
+            }]))
+        );
+        assert_eq!(
+            recognize_at(&unknown, Span::new("input").end()).map_err(|e| e.to_string()),
+            Err(
+                "line 1, column 6: expected an image of known letters (1 unknown glyph(s)\n\
+                 at column 5:\n##\n#.\n#.\n#.\n#.\n##), found nothing"
+                    .to_owned()
+            )
+        );
+    }
+}